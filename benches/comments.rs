@@ -0,0 +1,57 @@
+// benchmark for comment queries with and without the updates that are embedded in comments
+//
+// The bundled comment fixture is extended to a comment-heavy query result (pages with 100
+// comments each, which all embed the full update from the bundled update fixture), and is served
+// with a `MockTransport`, so the benchmark measures parsing of the received pages without any
+// network access. Run with `cargo bench --bench comments`.
+
+use bodhi::mock::{fixture, MockTransport};
+use bodhi::CommentQuery;
+
+mod common;
+use common::{fixture_page, header, measure, report, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const PAGES: u32 = 10;
+const ROWS_PER_PAGE: usize = 100;
+const ITERATIONS: usize = 20;
+
+// page of comments that all embed the full update they are associated with
+fn comment_page() -> String {
+    let update: serde_json::Value = serde_json::from_str(fixture("update.json").unwrap()).unwrap();
+
+    fixture_page("comments.json", "comments", PAGES, ROWS_PER_PAGE, |comment| {
+        comment["update"] = update["update"].clone();
+    })
+}
+
+fn main() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let page = comment_page();
+    println!(
+        "comment query: {} pages with {} comments each ({} KiB per page)",
+        PAGES,
+        ROWS_PER_PAGE,
+        page.len() / 1024
+    );
+    header();
+
+    for embedded in [true, false] {
+        let transport = MockTransport::new().route("/comments/", page.clone());
+        let bodhi = runtime.block_on(transport.client()).unwrap();
+
+        let measurement = measure(ITERATIONS, || {
+            runtime
+                .block_on(bodhi.paginated_request(&CommentQuery::new().embedded_updates(embedded)))
+                .unwrap()
+        });
+
+        report(&format!("comments (embedded_updates: {embedded})"), &measurement);
+    }
+}
@@ -0,0 +1,150 @@
+// shared helpers for measuring run time and memory usage in benchmarks
+//
+// Benchmarks use a global allocator that counts allocated bytes, and run every measured function
+// for a fixed number of iterations. The reported time is the median of all iterations.
+
+#![allow(dead_code)]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use bodhi::mock::fixture;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+// global allocator that keeps track of allocated and currently used bytes
+pub struct CountingAllocator;
+
+fn record_alloc(size: usize) {
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+fn record_dealloc(size: usize) {
+    CURRENT.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new
+    }
+}
+
+// results of measuring a function
+//
+// - allocated: total number of bytes that were allocated while the function was running
+// - peak: maximum number of additional bytes that were in use while the function was running
+// - retained: number of bytes that were still in use by the return value of the function
+pub struct Measurement {
+    pub time: Duration,
+    pub allocated: usize,
+    pub peak: usize,
+    pub retained: usize,
+}
+
+// run the function for the given number of iterations and measure time and memory usage
+//
+// Memory usage is the same for every iteration, so it is only recorded for the last one.
+pub fn measure<T>(iterations: usize, mut fun: impl FnMut() -> T) -> Measurement {
+    let mut times = Vec::with_capacity(iterations);
+    let mut memory = (0, 0, 0);
+
+    for _ in 0..iterations {
+        let base = CURRENT.load(Ordering::Relaxed);
+        let allocated = ALLOCATED.load(Ordering::Relaxed);
+        PEAK.store(base, Ordering::Relaxed);
+
+        let start = Instant::now();
+        let result = fun();
+        times.push(start.elapsed());
+
+        memory = (
+            ALLOCATED.load(Ordering::Relaxed) - allocated,
+            PEAK.load(Ordering::Relaxed).saturating_sub(base),
+            CURRENT.load(Ordering::Relaxed).saturating_sub(base),
+        );
+        drop(result);
+    }
+
+    times.sort_unstable();
+    let (allocated, peak, retained) = memory;
+
+    Measurement {
+        time: times[times.len() / 2],
+        allocated,
+        peak,
+        retained,
+    }
+}
+
+// print a table header for measurement results
+pub fn header() {
+    println!(
+        "{:<40} {:>12} {:>14} {:>14} {:>14}",
+        "benchmark", "time", "allocated", "peak", "retained"
+    );
+}
+
+// print the results of a measurement as a table row
+pub fn report(name: &str, measurement: &Measurement) {
+    println!(
+        "{:<40} {:>9.2} ms {:>11} KiB {:>11} KiB {:>11} KiB",
+        name,
+        measurement.time.as_secs_f64() * 1000.0,
+        measurement.allocated / 1024,
+        measurement.peak / 1024,
+        measurement.retained / 1024,
+    );
+}
+
+// page of query results that repeats the items of a bundled fixture to fill all rows
+//
+// The page claims to be one of `pages` pages, so the same page is returned for every page of a
+// paginated query. Every item is passed to `edit` before it is added to the page.
+pub fn fixture_page(name: &str, key: &str, pages: u32, rows: usize, edit: impl Fn(&mut serde_json::Value)) -> String {
+    let page: serde_json::Value = serde_json::from_str(fixture(name).unwrap()).unwrap();
+
+    let items: Vec<serde_json::Value> = page[key]
+        .as_array()
+        .unwrap()
+        .iter()
+        .cycle()
+        .take(rows)
+        .map(|item| {
+            let mut item = item.clone();
+            edit(&mut item);
+            item
+        })
+        .collect();
+
+    serde_json::json!({
+        key: items,
+        "page": 1,
+        "pages": pages,
+        "rows_per_page": rows,
+        "total": pages as usize * rows,
+    })
+    .to_string()
+}
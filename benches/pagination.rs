@@ -0,0 +1,57 @@
+// benchmark for the memory usage of paginated queries
+//
+// The bundled fixtures for builds, overrides, and updates are extended to pages with 100 items
+// each, and are served with a `MockTransport`. The "retained" column shows how much memory is
+// used by the results of each query, which includes the catch-all maps of unknown fields of every
+// item (see `ExtraFields`). Run with `cargo bench --bench pagination`.
+
+use bodhi::mock::MockTransport;
+use bodhi::{BodhiClient, BuildQuery, OverrideQuery, UpdateQuery};
+use tokio::runtime::Runtime;
+
+mod common;
+use common::{fixture_page, header, measure, report, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const PAGES: u32 = 10;
+const ROWS_PER_PAGE: usize = 100;
+const ITERATIONS: usize = 20;
+
+// client that answers requests to the given path with a page that is built from a fixture
+fn client(runtime: &Runtime, path: &str, name: &str, key: &str) -> BodhiClient {
+    let page = fixture_page(name, key, PAGES, ROWS_PER_PAGE, |_| {});
+    let transport = MockTransport::new().route(path, page);
+    runtime.block_on(transport.client()).unwrap()
+}
+
+fn main() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    println!("paginated queries: {PAGES} pages with {ROWS_PER_PAGE} items each");
+    header();
+
+    let bodhi = client(&runtime, "/builds/", "builds.json", "builds");
+    let measurement = measure(ITERATIONS, || {
+        runtime.block_on(bodhi.paginated_request(&BuildQuery::new())).unwrap()
+    });
+    report("builds", &measurement);
+
+    let bodhi = client(&runtime, "/overrides/", "overrides.json", "overrides");
+    let measurement = measure(ITERATIONS, || {
+        runtime
+            .block_on(bodhi.paginated_request(&OverrideQuery::new()))
+            .unwrap()
+    });
+    report("overrides", &measurement);
+
+    let bodhi = client(&runtime, "/updates/", "updates_f38.json", "updates");
+    let measurement = measure(ITERATIONS, || {
+        runtime.block_on(bodhi.paginated_request(&UpdateQuery::new())).unwrap()
+    });
+    report("updates", &measurement);
+}
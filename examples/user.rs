@@ -2,7 +2,6 @@
 
 use std::env::args;
 
-use bodhi::error::QueryError;
 use bodhi::{BodhiClientBuilder, UserNameQuery};
 
 #[tokio::main]
@@ -19,15 +18,16 @@ async fn main() -> Result<(), String> {
     arguments.next();
 
     for argument in arguments {
-        let user = match bodhi.request(&UserNameQuery::new(&argument)).await {
-            Ok(user) => user,
-            Err(error) => match error {
-                QueryError::NotFound => {
-                    println!("User '{}' not found.", &argument);
-                    println!();
-                    continue;
-                },
-                error => return Err(error.to_string()),
+        let user = match bodhi
+            .request_opt(&UserNameQuery::new(&argument))
+            .await
+            .map_err(|error| error.to_string())?
+        {
+            Some(user) => user,
+            None => {
+                println!("User '{}' not found.", &argument);
+                println!();
+                continue;
             },
         };
 
@@ -0,0 +1,134 @@
+//! # statistics computed from update data
+//!
+//! This module contains functions for computing aggregate statistics from lists of updates (for
+//! example, the results of an [`UpdateQuery`]), which are commonly needed for Fedora metrics
+//! tooling.
+//!
+//! ```
+//! use bodhi::analysis::update_latency_stats;
+//! # use bodhi::mock::MockServer;
+//! use bodhi::{Update, UpdateQuery};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+//! let updates: Vec<Update> = bodhi.paginated_request(&UpdateQuery::new()).await.unwrap();
+//!
+//! let stats = update_latency_stats(&updates);
+//! if let Some(latency) = stats.submitted_to_testing {
+//!     println!(
+//!         "median time to testing: {} hours",
+//!         latency.median.num_hours()
+//!     );
+//! }
+//! # }
+//! ```
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::data::{BodhiDate, Update};
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::query::UpdateQuery;
+
+
+/// data type that contains summary statistics for a set of durations
+///
+/// Percentiles are computed with the "nearest rank" method, i.e. they are always equal to one of
+/// the sampled durations. For an even number of samples, the median is the lower of the two middle
+/// values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LatencyStats {
+    /// number of sampled durations
+    pub count: usize,
+    /// shortest sampled duration
+    pub min: Duration,
+    /// median of the sampled durations (50th percentile)
+    pub median: Duration,
+    /// 90th percentile of the sampled durations
+    pub p90: Duration,
+    /// 95th percentile of the sampled durations
+    pub p95: Duration,
+    /// longest sampled duration
+    pub max: Duration,
+}
+
+impl LatencyStats {
+    /// compute statistics for the given durations
+    ///
+    /// This returns `None` if there are no durations.
+    pub fn from_durations(mut durations: Vec<Duration>) -> Option<Self> {
+        durations.sort_unstable();
+
+        let min = *durations.first()?;
+        let max = *durations.last()?;
+
+        Some(LatencyStats {
+            count: durations.len(),
+            min,
+            median: percentile(&durations, 50),
+            p90: percentile(&durations, 90),
+            p95: percentile(&durations, 95),
+            max,
+        })
+    }
+}
+
+// nearest-rank percentile of a sorted, non-empty list of durations
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    let rank = (percent * sorted.len() + 99) / 100;
+    sorted[rank.saturating_sub(1)]
+}
+
+
+/// data type that contains latency statistics for the different stages of the update workflow
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpdateLatencyStats {
+    /// time between submission of updates and their push to testing
+    ///
+    /// This is `None` if none of the updates have been pushed to testing.
+    pub submitted_to_testing: Option<LatencyStats>,
+    /// time between the push of updates to testing and their push to stable
+    ///
+    /// This is `None` if none of the updates have been pushed to stable after being pushed to
+    /// testing.
+    pub testing_to_stable: Option<LatencyStats>,
+}
+
+
+/// function for computing latency statistics for the given updates
+///
+/// Every update contributes a sample to each stage for which both the start and end dates are
+/// known. Updates that skipped a stage (for example, updates for releases that are not composed by
+/// bodhi, which are never pushed to testing) are not counted for that stage. Samples with negative
+/// durations (which can occur due to inconsistent server data) are ignored.
+pub fn update_latency_stats(updates: &[Update]) -> UpdateLatencyStats {
+    let submitted_to_testing = updates
+        .iter()
+        .filter_map(|update| latency(update.date_submitted.as_ref(), update.date_testing.as_ref()))
+        .collect();
+
+    let testing_to_stable = updates
+        .iter()
+        .filter_map(|update| latency(update.date_testing.as_ref(), update.date_stable.as_ref()))
+        .collect();
+
+    UpdateLatencyStats {
+        submitted_to_testing: LatencyStats::from_durations(submitted_to_testing),
+        testing_to_stable: LatencyStats::from_durations(testing_to_stable),
+    }
+}
+
+// duration between two dates (if both are known and they are in the correct order)
+fn latency(start: Option<&BodhiDate>, end: Option<&BodhiDate>) -> Option<Duration> {
+    let start: DateTime<Utc> = start?.clone().into();
+    let end: DateTime<Utc> = end?.clone().into();
+
+    let duration = end - start;
+    if duration < Duration::zero() {
+        None
+    } else {
+        Some(duration)
+    }
+}
@@ -0,0 +1,317 @@
+//! # registry of implemented bodhi REST API endpoints
+//!
+//! This module contains a machine-readable list of the bodhi REST API endpoints that are
+//! implemented by this crate, including the names of the parameters that are supported for each
+//! endpoint, and the request type that is used for making requests to it.
+//!
+//! ```
+//! use bodhi::api_map::{endpoint, EndpointMethod};
+//!
+//! let endpoint = endpoint(EndpointMethod::GET, "/updates/").unwrap();
+//! assert_eq!(endpoint.request_type, "UpdateQuery");
+//! assert!(endpoint.parameters.contains(&"releases"));
+//! ```
+//!
+//! Parameters of `GET` requests are passed as URL query arguments, and parameters of `POST`
+//! requests are passed as fields of the JSON request body. Parameters that are part of the URL
+//! path are written as `{name}` placeholders in the endpoint path.
+
+use std::fmt::{Display, Formatter};
+
+/// HTTP methods that are used for bodhi REST API requests
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EndpointMethod {
+    /// `GET` requests (read-only queries)
+    GET,
+    /// `POST` requests (requests that modify server state)
+    POST,
+}
+
+impl Display for EndpointMethod {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let value = match self {
+            EndpointMethod::GET => "GET",
+            EndpointMethod::POST => "POST",
+        };
+
+        write!(f, "{value}")
+    }
+}
+
+
+/// data type that describes a bodhi REST API endpoint that is implemented by this crate
+#[derive(Debug)]
+pub struct Endpoint {
+    /// HTTP method of requests to this endpoint
+    pub method: EndpointMethod,
+    /// path of this endpoint (relative to the base URL of the bodhi server)
+    pub path: &'static str,
+    /// name of the request type in this crate that is used for making requests to this endpoint
+    pub request_type: &'static str,
+    /// names of the supported request parameters (excluding the CSRF token)
+    pub parameters: &'static [&'static str],
+}
+
+impl Display for Endpoint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} {} ({})", self.method, self.path, self.request_type)
+    }
+}
+
+
+// parameters of all paginated queries
+macro_rules! paginated {
+    ($($param:literal),* $(,)?) => {
+        &[$($param,)* "page", "rows_per_page"]
+    };
+}
+
+/// list of all bodhi REST API endpoints that are implemented by this crate
+pub const ENDPOINTS: &[Endpoint] = &[
+    // builds
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/builds/{nvr}",
+        request_type: "BuildNVRQuery",
+        parameters: &[],
+    },
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/builds/",
+        request_type: "BuildQuery",
+        parameters: paginated!["nvr", "packages", "releases", "updates"],
+    },
+    // comments
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/comments/{id}",
+        request_type: "CommentIDQuery",
+        parameters: &[],
+    },
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/comments/",
+        request_type: "CommentQuery",
+        parameters: paginated![
+            "anonymous",
+            "ignore_user",
+            "like",
+            "packages",
+            "search",
+            "since",
+            "update_owner",
+            "updates",
+            "user",
+        ],
+    },
+    Endpoint {
+        method: EndpointMethod::POST,
+        path: "/comments/",
+        request_type: "CommentCreator",
+        parameters: &["update", "text", "karma", "bug_feedback", "testcase_feedback"],
+    },
+    // composes
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/composes/",
+        request_type: "ComposeQuery",
+        parameters: &[],
+    },
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/composes/{release_name}/{request}",
+        request_type: "ComposeReleaseRequestQuery",
+        parameters: &[],
+    },
+    // CSRF tokens
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/csrf",
+        request_type: "CSRFQuery",
+        parameters: &[],
+    },
+    // overrides
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/overrides/{nvr}",
+        request_type: "OverrideNVRQuery",
+        parameters: &[],
+    },
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/overrides/",
+        request_type: "OverrideQuery",
+        parameters: paginated!["builds", "expired", "like", "packages", "releases", "search", "user"],
+    },
+    Endpoint {
+        method: EndpointMethod::POST,
+        path: "/overrides/",
+        request_type: "OverrideCreator",
+        parameters: &["nvr", "notes", "expiration_date"],
+    },
+    Endpoint {
+        method: EndpointMethod::POST,
+        path: "/overrides/",
+        request_type: "OverrideEditor",
+        parameters: &["nvr", "notes", "expiration_date", "expired", "edited"],
+    },
+    // packages
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/packages/",
+        request_type: "PackageQuery",
+        parameters: paginated!["like", "name", "search"],
+    },
+    // releases
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/releases/{name}",
+        request_type: "ReleaseNameQuery",
+        parameters: &[],
+    },
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/releases/",
+        request_type: "ReleaseQuery",
+        parameters: paginated!["exclude_archived", "ids", "name", "packages", "updates"],
+    },
+    // updates
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/updates/{id}",
+        request_type: "UpdateIDQuery",
+        parameters: &[],
+    },
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/updates/",
+        request_type: "UpdateQuery",
+        parameters: paginated![
+            "active_releases",
+            "alias",
+            "approved_before",
+            "approved_since",
+            "bugs",
+            "builds",
+            "content_type",
+            "critpath",
+            "cves",
+            "like",
+            "locked",
+            "modified_before",
+            "modified_since",
+            "packages",
+            "pushed",
+            "pushed_before",
+            "pushed_since",
+            "releases",
+            "request",
+            "search",
+            "severity",
+            "status",
+            "submitted_before",
+            "submitted_since",
+            "suggest",
+            "type",
+            "updateid",
+            "user",
+        ],
+    },
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/updates/{id}/get-test-results",
+        request_type: "UpdateTestResultsQuery",
+        parameters: &[],
+    },
+    Endpoint {
+        method: EndpointMethod::POST,
+        path: "/updates/",
+        request_type: "UpdateCreator",
+        parameters: &[
+            "builds",
+            "from_tag",
+            "bugs",
+            "display_name",
+            "close_bugs",
+            "type",
+            "request",
+            "severity",
+            "notes",
+            "autokarma",
+            "stable_karma",
+            "unstable_karma",
+            "suggest",
+            "requirements",
+            "require_bugs",
+            "require_testcases",
+            "autotime",
+            "stable_days",
+        ],
+    },
+    Endpoint {
+        method: EndpointMethod::POST,
+        path: "/updates/",
+        request_type: "UpdateEditor",
+        parameters: &[
+            "builds",
+            "bugs",
+            "display_name",
+            "close_bugs",
+            "type",
+            "request",
+            "severity",
+            "notes",
+            "autokarma",
+            "stable_karma",
+            "unstable_karma",
+            "suggest",
+            "edited",
+            "requirements",
+            "require_bugs",
+            "require_testcases",
+            "autotime",
+            "stable_days",
+        ],
+    },
+    Endpoint {
+        method: EndpointMethod::POST,
+        path: "/updates/{id}/request",
+        request_type: "UpdateStatusRequester",
+        parameters: &["request"],
+    },
+    Endpoint {
+        method: EndpointMethod::POST,
+        path: "/updates/{id}/waive-test-results",
+        request_type: "UpdateTestResultWaiver",
+        parameters: &["comment", "tests"],
+    },
+    // users
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/users/{name}",
+        request_type: "UserNameQuery",
+        parameters: &[],
+    },
+    Endpoint {
+        method: EndpointMethod::GET,
+        path: "/users/",
+        request_type: "UserQuery",
+        parameters: paginated!["groups", "like", "name", "search", "updates"],
+    },
+];
+
+/// look up the first implemented endpoint with the given method and path
+///
+/// The path needs to be specified in the same format as in [`ENDPOINTS`], i.e. with `{name}`
+/// placeholders for parameters that are part of the URL path.
+pub fn endpoint(method: EndpointMethod, path: &str) -> Option<&'static Endpoint> {
+    ENDPOINTS
+        .iter()
+        .find(|endpoint| endpoint.method == method && endpoint.path == path)
+}
+
+/// look up the endpoint that is used by the request type with the given name
+pub fn endpoint_for(request_type: &str) -> Option<&'static Endpoint> {
+    ENDPOINTS.iter().find(|endpoint| endpoint.request_type == request_type)
+}
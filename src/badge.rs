@@ -0,0 +1,108 @@
+//! # compact summaries of updates
+//!
+//! This module contains the [`UpdateBadge`] type, which is a tiny summary of the current state of
+//! an update (alias, status, karma, gating status, and the number of days it has spent in
+//! testing). It can be used for rendering consistent summaries in status bars, chat messages, or
+//! comments on pull requests, and its serialized form is stable enough to be passed between tools.
+//!
+//! ```
+//! use bodhi::badge::UpdateBadge;
+//! # use bodhi::mock::MockServer;
+//! use bodhi::Update;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+//! let update = Update::fetch(&bodhi, "FEDORA-2024-1a2b3c4d5e")
+//!     .await
+//!     .unwrap();
+//!
+//! let badge = UpdateBadge::from(&update);
+//! println!("{badge}");
+//! println!("{badge:#}");
+//! # }
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::{BodhiDate, TestGatingStatus, Update, UpdateStatus};
+use crate::policy::days_in_testing;
+
+
+/// data type that contains a compact summary of the current state of an update
+///
+/// The [`Display`] implementation renders the summary as a single line of plain text. The
+/// alternate form (`{:#}`) renders it as a single line of Markdown instead, with a link to the
+/// update on the bodhi web interface:
+///
+/// - `FEDORA-2024-1a2b3c4d5e: testing, karma +1, gating passed, 3 days in testing`
+/// - ``[`FEDORA-2024-1a2b3c4d5e`](https://...): **testing**, karma +1, gating passed, 3 days in
+///   testing``
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct UpdateBadge {
+    /// alias of the update
+    pub alias: String,
+    /// public URL of the update
+    pub url: String,
+    /// current state of the update
+    pub status: UpdateStatus,
+    /// current total of feedback karma values
+    pub karma: i32,
+    /// current greenwave gating status (if gating is enabled for the update)
+    pub gating: Option<TestGatingStatus>,
+    /// number of full days the update has spent in testing (only for updates in testing)
+    pub days_in_testing: Option<u32>,
+}
+
+impl UpdateBadge {
+    /// constructor for [`UpdateBadge`] that computes the number of days in testing at the given
+    /// date & time
+    ///
+    /// The [`From<&Update>`] implementation uses the current date & time instead.
+    pub fn new(update: &Update, now: &BodhiDate) -> Self {
+        let days_in_testing = if update.status == UpdateStatus::Testing {
+            days_in_testing(update, now)
+        } else {
+            None
+        };
+
+        UpdateBadge {
+            alias: update.alias.clone(),
+            url: update.url.clone(),
+            status: update.status,
+            karma: update.karma.unwrap_or(0),
+            gating: update.test_gating_status,
+            days_in_testing,
+        }
+    }
+}
+
+impl From<&Update> for UpdateBadge {
+    fn from(update: &Update) -> Self {
+        UpdateBadge::new(update, &BodhiDate::now())
+    }
+}
+
+impl Display for UpdateBadge {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "[`{}`]({}): **{}**", self.alias, self.url, self.status)?;
+        } else {
+            write!(f, "{}: {}", self.alias, self.status)?;
+        }
+
+        write!(f, ", karma {:+}", self.karma)?;
+
+        if let Some(gating) = self.gating {
+            write!(f, ", gating {gating}")?;
+        }
+
+        match self.days_in_testing {
+            Some(1) => write!(f, ", 1 day in testing"),
+            Some(days) => write!(f, ", {days} days in testing"),
+            None => Ok(()),
+        }
+    }
+}
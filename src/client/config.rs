@@ -0,0 +1,273 @@
+//! # client configuration from environment variables and configuration files
+//!
+//! This module contains the implementation for reading `BodhiClientBuilder` settings from
+//! environment variables, and (with the `config` feature) from a TOML configuration file.
+
+#[cfg(feature = "config")]
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use super::BuilderError;
+
+// names of the supported environment variables
+const BODHI_URL: &str = "BODHI_URL";
+const BODHI_OPENID_URL: &str = "BODHI_OPENID_URL";
+const BODHI_STAGING: &str = "BODHI_STAGING";
+const BODHI_USERNAME: &str = "BODHI_USERNAME";
+const BODHI_PASSWORD: &str = "BODHI_PASSWORD";
+const BODHI_TIMEOUT: &str = "BODHI_TIMEOUT";
+const BODHI_RETRIES: &str = "BODHI_RETRIES";
+const BODHI_USER_AGENT: &str = "BODHI_USER_AGENT";
+const BODHI_APP_IDENTITY: &str = "BODHI_APP_IDENTITY";
+
+// file name of the configuration file (relative to the user's configuration directory)
+#[cfg(feature = "config")]
+const CONFIG_FILE: &str = "bodhi-rs.toml";
+
+
+// client settings that can be read from environment variables or a configuration file
+//
+// Field names correspond to the keys in the configuration file. All fields are optional, and
+// unset values fall back to the defaults of `BodhiClientBuilder`.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ClientConfig {
+    pub(crate) url: Option<String>,
+    pub(crate) openid_url: Option<String>,
+    pub(crate) staging: Option<bool>,
+    pub(crate) username: Option<String>,
+    pub(crate) password: Option<String>,
+    pub(crate) timeout: Option<u64>,
+    pub(crate) retries: Option<usize>,
+    pub(crate) user_agent: Option<String>,
+    pub(crate) app_identity: Option<String>,
+}
+
+fn invalid(name: &str, value: &str, expected: &str) -> BuilderError {
+    BuilderError::ConfigurationError {
+        error: format!("Invalid value for {name}: '{value}' (expected {expected})"),
+    }
+}
+
+fn parse_bool(name: &str, value: &str) -> Result<bool, BuilderError> {
+    match value.trim().to_lowercase().as_str() {
+        "1" | "true" | "yes" => Ok(true),
+        "0" | "false" | "no" => Ok(false),
+        _ => Err(invalid(name, value, "a boolean value")),
+    }
+}
+
+impl ClientConfig {
+    // read settings from environment variables with the given lookup function
+    //
+    // Empty values are treated the same as unset variables.
+    pub(crate) fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self, BuilderError> {
+        let var = |name: &str| var(name).filter(|value| !value.trim().is_empty());
+
+        let staging = var(BODHI_STAGING)
+            .map(|value| parse_bool(BODHI_STAGING, &value))
+            .transpose()?;
+
+        let timeout = var(BODHI_TIMEOUT)
+            .map(|value| {
+                value
+                    .trim()
+                    .parse()
+                    .map_err(|_| invalid(BODHI_TIMEOUT, &value, "a number of seconds"))
+            })
+            .transpose()?;
+
+        let retries = var(BODHI_RETRIES)
+            .map(|value| {
+                value
+                    .trim()
+                    .parse()
+                    .map_err(|_| invalid(BODHI_RETRIES, &value, "a number"))
+            })
+            .transpose()?;
+
+        Ok(ClientConfig {
+            url: var(BODHI_URL),
+            openid_url: var(BODHI_OPENID_URL),
+            staging,
+            username: var(BODHI_USERNAME),
+            password: var(BODHI_PASSWORD),
+            timeout,
+            retries,
+            user_agent: var(BODHI_USER_AGENT),
+            app_identity: var(BODHI_APP_IDENTITY),
+        })
+    }
+
+    // read settings from the process environment
+    pub(crate) fn from_env() -> Result<Self, BuilderError> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    // read settings from the configuration file at the given path
+    #[cfg(feature = "config")]
+    pub(crate) fn from_file(path: &Path) -> Result<Option<Self>, BuilderError> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => {
+                return Err(BuilderError::ConfigurationError {
+                    error: format!("Failed to read {}: {}", path.display(), error),
+                })
+            },
+        };
+
+        let config: ClientConfig = toml::from_str(&contents).map_err(|error| BuilderError::ConfigurationError {
+            error: format!("Failed to parse {}: {}", path.display(), error),
+        })?;
+
+        Ok(Some(config))
+    }
+
+    // read settings from the default configuration file (if it exists)
+    #[cfg(feature = "config")]
+    pub(crate) fn from_default_file() -> Result<Option<Self>, BuilderError> {
+        match dirs::config_dir() {
+            Some(dir) => Self::from_file(&dir.join(CONFIG_FILE)),
+            None => Ok(None),
+        }
+    }
+
+    // combine two sets of settings, with values from `other` taking precedence
+    #[cfg_attr(not(feature = "config"), allow(dead_code))]
+    pub(crate) fn merge(self, other: Self) -> Self {
+        ClientConfig {
+            url: other.url.or(self.url),
+            openid_url: other.openid_url.or(self.openid_url),
+            staging: other.staging.or(self.staging),
+            username: other.username.or(self.username),
+            password: other.password.or(self.password),
+            timeout: other.timeout.or(self.timeout),
+            retries: other.retries.or(self.retries),
+            user_agent: other.user_agent.or(self.user_agent),
+            app_identity: other.app_identity.or(self.app_identity),
+        }
+    }
+
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.timeout.map(Duration::from_secs)
+    }
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::super::{BodhiClientBuilder, BodhiServiceType};
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        move |name| map.get(name).cloned()
+    }
+
+    #[test]
+    fn env_vars() {
+        let config = ClientConfig::from_vars(vars(&[
+            (BODHI_URL, "https://bodhi.example.com"),
+            (BODHI_STAGING, "Yes"),
+            (BODHI_USERNAME, "janedoe"),
+            (BODHI_TIMEOUT, " 120 "),
+            (BODHI_RETRIES, ""),
+            (BODHI_APP_IDENTITY, "fedora-update-feedback/2.1"),
+        ]))
+        .unwrap();
+
+        assert_eq!(config.url.as_deref(), Some("https://bodhi.example.com"));
+        assert_eq!(config.staging, Some(true));
+        assert_eq!(config.username.as_deref(), Some("janedoe"));
+        assert_eq!(config.timeout(), Some(Duration::from_secs(120)));
+        assert_eq!(config.retries, None);
+        assert_eq!(config.password, None);
+        assert_eq!(config.app_identity.as_deref(), Some("fedora-update-feedback/2.1"));
+    }
+
+    #[test]
+    fn env_vars_invalid() {
+        assert!(matches!(
+            ClientConfig::from_vars(vars(&[(BODHI_STAGING, "maybe")])),
+            Err(BuilderError::ConfigurationError { .. })
+        ));
+        assert!(matches!(
+            ClientConfig::from_vars(vars(&[(BODHI_TIMEOUT, "1m")])),
+            Err(BuilderError::ConfigurationError { .. })
+        ));
+    }
+
+    #[test]
+    fn merge() {
+        let file = ClientConfig {
+            url: Some(String::from("https://bodhi.example.com")),
+            retries: Some(5),
+            ..Default::default()
+        };
+        let env = ClientConfig {
+            retries: Some(2),
+            staging: Some(true),
+            ..Default::default()
+        };
+
+        let merged = file.merge(env);
+        assert_eq!(merged.url.as_deref(), Some("https://bodhi.example.com"));
+        assert_eq!(merged.retries, Some(2));
+        assert_eq!(merged.staging, Some(true));
+    }
+
+    #[test]
+    fn builder() {
+        let config = ClientConfig {
+            url: Some(String::from("https://bodhi.example.com")),
+            staging: Some(true),
+            timeout: Some(30),
+            ..Default::default()
+        };
+
+        let builder = BodhiClientBuilder::from_config(config).unwrap();
+        assert_eq!(builder.url, "https://bodhi.example.com");
+        assert!(matches!(
+            builder.service_type,
+            BodhiServiceType::Custom { openid_url } if openid_url == super::super::session::FEDORA_OPENID_URL
+        ));
+        assert_eq!(builder.timeout, Some(Duration::from_secs(30)));
+        assert!(builder.authentication.is_none());
+
+        let config = ClientConfig {
+            staging: Some(true),
+            username: Some(String::from("janedoe")),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            BodhiClientBuilder::from_config(config),
+            Err(BuilderError::ConfigurationError { .. })
+        ));
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn config_file() {
+        let path = std::env::temp_dir().join(format!("bodhi-rs-config-test-{}.toml", std::process::id()));
+        std::fs::write(&path, "staging = true\nusername = \"janedoe\"\ntimeout = 30\n").unwrap();
+
+        let config = ClientConfig::from_file(&path).unwrap().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(config.staging, Some(true));
+        assert_eq!(config.username.as_deref(), Some("janedoe"));
+        assert_eq!(config.timeout(), Some(Duration::from_secs(30)));
+
+        assert!(ClientConfig::from_file(&path).unwrap().is_none());
+    }
+}
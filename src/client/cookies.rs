@@ -0,0 +1,206 @@
+//! # persistent cookie jar
+//!
+//! This module contains a simple cookie jar implementation based on [`reqwest::cookie::Jar`],
+//! extended with methods for persisting session cookies on disk. This makes it possible to reuse
+//! authenticated sessions instead of logging in again every time a client is constructed.
+
+use std::fs::{create_dir_all, read_to_string, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use cookie_store::{CookieStore, RawCookie};
+use reqwest::header::HeaderValue;
+use url::Url;
+
+// directory and file name of the on-disk cookie cache (relative to the user's home directory)
+//
+// This is the same location that was used by the `fedora` crate, so cookies that were cached by
+// previous versions of this crate are still valid and can be reused.
+const COOKIE_CACHE_DIR: &str = ".fedora";
+const COOKIE_CACHE_FILE: &str = "fedora-rs-cookie-jar.json";
+
+
+// error type that represents failures while loading or storing the on-disk cookie cache
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum CookieCacheError {
+    #[error("No existing cookie cache found.")]
+    DoesNotExist,
+    #[error("Failed to determine location of the cookie cache.")]
+    NoHomeDirectory,
+    #[error("Failed to read or write cookie cache: {error}")]
+    IOError {
+        #[from]
+        error: std::io::Error,
+    },
+    #[error("Failed to (de)serialize cookie cache: {error}")]
+    SerializationError {
+        #[from]
+        error: serde_json::Error,
+    },
+}
+
+fn cookie_cache_dir() -> Result<PathBuf, CookieCacheError> {
+    let home = dirs::home_dir().ok_or(CookieCacheError::NoHomeDirectory)?;
+    Ok(home.join(COOKIE_CACHE_DIR))
+}
+
+// write a file that is only readable and writable by the current user (on unix systems)
+//
+// Files that contain session cookies grant access to the authenticated session, so they should not
+// be readable by other users.
+pub(crate) fn write_private(path: &Path, contents: &str) -> std::io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+        options.mode(0o600);
+        let mut file = options.open(path)?;
+
+        // the mode is only applied to newly created files
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        file.write_all(contents.as_bytes())
+    }
+
+    #[cfg(not(unix))]
+    {
+        let mut file = options.open(path)?;
+        file.write_all(contents.as_bytes())
+    }
+}
+
+// this is based on the private `parse` method of `reqwest::cookie::Cookie`
+fn parse_cookie(value: &HeaderValue) -> Option<RawCookie<'static>> {
+    let string = std::str::from_utf8(value.as_bytes()).ok()?;
+    RawCookie::parse(string).ok().map(RawCookie::into_owned)
+}
+
+
+// implementation of the `reqwest::cookie::CookieStore` trait with support for persistent cookies
+#[derive(Debug, Default)]
+pub(crate) struct CachingJar {
+    store: RwLock<CookieStore>,
+}
+
+impl CachingJar {
+    // read cached cookies from disk (expired cookies are skipped during deserialization)
+    pub(crate) fn read_from_disk() -> Result<Self, CookieCacheError> {
+        let path = cookie_cache_dir()?.join(COOKIE_CACHE_FILE);
+
+        let contents = match read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Err(CookieCacheError::DoesNotExist);
+            },
+            Err(error) => return Err(error.into()),
+        };
+
+        let store: CookieStore = serde_json::from_str(&contents)?;
+
+        Ok(CachingJar::from_store(store))
+    }
+
+    pub(crate) fn from_store(store: CookieStore) -> Self {
+        CachingJar {
+            store: RwLock::new(store),
+        }
+    }
+
+    // snapshot of the current contents of the cookie jar
+    pub(crate) fn to_store(&self) -> CookieStore {
+        self.store.read().expect("Poisoned lock!").clone()
+    }
+
+    // write persistent, non-expired cookies to disk
+    pub(crate) fn write_to_disk(&self) -> Result<(), CookieCacheError> {
+        let dir = cookie_cache_dir()?;
+        create_dir_all(&dir)?;
+
+        let contents = {
+            let store = self.store.read().expect("Poisoned lock!");
+            serde_json::to_string_pretty(&*store)?
+        };
+
+        write_private(&dir.join(COOKIE_CACHE_FILE), &contents)?;
+        Ok(())
+    }
+
+    // check whether there are any non-expired cookies for the given URL
+    pub(crate) fn is_fresh(&self, url: &Url) -> bool {
+        self.store
+            .read()
+            .expect("Poisoned lock!")
+            .iter_unexpired()
+            .any(|cookie| cookie.domain.matches(url))
+    }
+}
+
+impl reqwest::cookie::CookieStore for CachingJar {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
+        let cookies = cookie_headers.filter_map(parse_cookie);
+
+        self.store
+            .write()
+            .expect("Poisoned lock!")
+            .store_response_cookies(cookies, url);
+    }
+
+    fn cookies(&self, url: &Url) -> Option<HeaderValue> {
+        let value = self
+            .store
+            .read()
+            .expect("Poisoned lock!")
+            .get_request_values(url)
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        if value.is_empty() {
+            return None;
+        }
+
+        HeaderValue::from_str(&value).ok()
+    }
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use reqwest::cookie::CookieStore as _;
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let jar = CachingJar::default();
+        let url = Url::parse("https://bodhi.fedoraproject.org/login").unwrap();
+        let other = Url::parse("https://example.com/").unwrap();
+
+        assert!(!jar.is_fresh(&url));
+
+        let header = HeaderValue::from_static("authtkt=deadbeef; Path=/; Max-Age=3600");
+        jar.set_cookies(&mut std::iter::once(&header), &url);
+
+        assert!(jar.is_fresh(&url));
+        assert!(!jar.is_fresh(&other));
+
+        assert_eq!(jar.cookies(&url).unwrap(), "authtkt=deadbeef");
+        assert!(jar.cookies(&other).is_none());
+    }
+
+    #[test]
+    fn expired() {
+        let jar = CachingJar::default();
+        let url = Url::parse("https://bodhi.fedoraproject.org/login").unwrap();
+
+        let header = HeaderValue::from_static("authtkt=deadbeef; Path=/; Max-Age=0");
+        jar.set_cookies(&mut std::iter::once(&header), &url);
+
+        assert!(!jar.is_fresh(&url));
+        assert!(jar.cookies(&url).is_none());
+    }
+}
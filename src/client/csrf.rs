@@ -0,0 +1,140 @@
+//! # caching of CSRF tokens
+//!
+//! Every `POST` request needs a CSRF token, which is requested from the server before the actual
+//! request is sent. Tokens are bound to the session, so a token can be reused for multiple
+//! requests. Tokens are cached for a configurable time (see
+//! [`BodhiClientBuilder::csrf_token_ttl`](crate::BodhiClientBuilder::csrf_token_ttl)). When a
+//! cached token is used during the last quarter of its validity window, a new token is requested
+//! at the same time as the request that uses the cached token is sent, so the next request does
+//! not need to wait for it. Tokens that are rejected by the server are dropped from the cache.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// state of the cached token
+pub(crate) enum CachedToken {
+    // token is valid and not close to its expiry
+    Fresh(String),
+    // token is still valid, but should be refreshed
+    Expiring(String),
+    // no token is cached, or it has expired
+    Missing,
+}
+
+#[derive(Debug)]
+pub(crate) struct CsrfCache {
+    ttl: Duration,
+    timeout: Option<Duration>,
+    token: Mutex<Option<(String, Instant)>>,
+}
+
+impl CsrfCache {
+    pub(crate) fn new(ttl: Duration, timeout: Option<Duration>) -> Self {
+        CsrfCache {
+            ttl,
+            timeout,
+            token: Mutex::new(None),
+        }
+    }
+
+    // timeout for requesting new tokens (if it differs from the timeout for other requests)
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    pub(crate) fn get(&self) -> CachedToken {
+        self.get_at(Instant::now())
+    }
+
+    // state of the cached token at the given point in time
+    fn get_at(&self, now: Instant) -> CachedToken {
+        let token = self.token.lock().expect("Poisoned lock!");
+
+        match &*token {
+            Some((token, fetched)) => {
+                let age = now.saturating_duration_since(*fetched);
+                if age >= self.ttl {
+                    CachedToken::Missing
+                } else if age >= self.ttl - self.ttl / 4 {
+                    CachedToken::Expiring(token.clone())
+                } else {
+                    CachedToken::Fresh(token.clone())
+                }
+            },
+            None => CachedToken::Missing,
+        }
+    }
+
+    pub(crate) fn store(&self, token: String) {
+        self.store_at(token, Instant::now());
+    }
+
+    // store a token that was fetched at the given point in time
+    fn store_at(&self, token: String, fetched: Instant) {
+        // a TTL of zero disables caching
+        if !self.ttl.is_zero() {
+            *self.token.lock().expect("Poisoned lock!") = Some((token, fetched));
+        }
+    }
+
+    // drop the cached token if it matches the token that was rejected by the server
+    pub(crate) fn invalidate(&self, rejected: &str) {
+        let mut token = self.token.lock().expect("Poisoned lock!");
+        if matches!(&*token, Some((cached, _)) if cached == rejected) {
+            *token = None;
+        }
+    }
+}
+
+// check whether a server-side error message was caused by an invalid CSRF token
+pub(crate) fn is_csrf_error(errors: &[HashMap<String, String>]) -> bool {
+    errors
+        .iter()
+        .any(|error| error.get("name").map(String::as_str) == Some("csrf_token"))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_states() {
+        let cache = CsrfCache::new(Duration::from_secs(400), None);
+        let fetched = Instant::now();
+        assert!(matches!(cache.get_at(fetched), CachedToken::Missing));
+
+        cache.store_at(String::from("abc"), fetched);
+        assert!(matches!(cache.get_at(fetched), CachedToken::Fresh(token) if token == "abc"));
+
+        // tokens are refreshed during the last quarter of their validity window
+        let later = fetched + Duration::from_secs(299);
+        assert!(matches!(cache.get_at(later), CachedToken::Fresh(_)));
+        let later = fetched + Duration::from_secs(300);
+        assert!(matches!(cache.get_at(later), CachedToken::Expiring(token) if token == "abc"));
+
+        let later = fetched + Duration::from_secs(400);
+        assert!(matches!(cache.get_at(later), CachedToken::Missing));
+    }
+
+    #[test]
+    fn invalidate() {
+        let cache = CsrfCache::new(Duration::from_secs(60), None);
+
+        cache.store(String::from("abc"));
+        cache.invalidate("def");
+        assert!(matches!(cache.get(), CachedToken::Fresh(_)));
+
+        cache.invalidate("abc");
+        assert!(matches!(cache.get(), CachedToken::Missing));
+    }
+
+    #[test]
+    fn disabled() {
+        let cache = CsrfCache::new(Duration::ZERO, None);
+
+        cache.store(String::from("abc"));
+        assert!(matches!(cache.get(), CachedToken::Missing));
+    }
+}
@@ -0,0 +1,185 @@
+//! # background keep-alive for authenticated sessions
+//!
+//! This module contains the implementation of a background task that keeps the authentication of
+//! a [`BodhiClient`] alive (see [`BodhiClient::keep_alive`]). This is intended for long-running
+//! processes (for example, daemons that authenticate once at startup), which would otherwise only
+//! notice that their session has expired when the next authenticated request fails.
+//!
+//! The task periodically checks the authentication state of the client:
+//!
+//! - OpenID Connect access tokens are refreshed if they would expire before the next check.
+//! - Session cookies are kept alive by requesting a new CSRF token, which also allows the server to
+//!   renew the session. Expired session cookies cannot be renewed without logging in again.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_util::Stream;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use super::oidc::REFRESH_MARGIN;
+use super::BodhiClient;
+use crate::error::QueryError;
+
+
+/// events that are emitted by the keep-alive task of a [`BodhiClient`]
+///
+/// Events are only emitted when the authentication state changes, not for every successful check.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AuthEvent {
+    /// a new OpenID Connect access token was obtained before the current token expired
+    TokenRefreshed,
+    /// the session was checked successfully after a previous check had failed
+    Restored,
+    /// checking or refreshing the session failed (the check is retried after the next interval)
+    Failed {
+        /// error that occurred during the check
+        error: QueryError,
+    },
+    /// session cookies have expired and cannot be renewed without logging in again
+    ///
+    /// This is the last event that is emitted by the keep-alive task.
+    SessionExpired,
+}
+
+
+/// handle for the background task that keeps the authentication of a [`BodhiClient`] alive
+///
+/// This is returned by [`BodhiClient::keep_alive`], and can be used as a [`Stream`] of
+/// [`AuthEvent`]s. The stream ends when the task stops (after an [`AuthEvent::SessionExpired`]
+/// event). The task is cancelled when this handle is dropped, or with [`KeepAlive::cancel`].
+///
+/// ```no_run
+/// # async fn doc(bodhi: bodhi::BodhiClient) {
+/// use std::time::Duration;
+///
+/// use bodhi::AuthEvent;
+/// use futures_util::StreamExt;
+///
+/// let mut keep_alive = bodhi.keep_alive(Duration::from_secs(300)).unwrap();
+///
+/// while let Some(event) = keep_alive.next().await {
+///     match event {
+///         AuthEvent::Failed { error } => eprintln!("Failed to refresh session: {error}"),
+///         AuthEvent::SessionExpired => eprintln!("Session expired."),
+///         _ => {},
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct KeepAlive {
+    events: mpsc::UnboundedReceiver<AuthEvent>,
+    task: JoinHandle<()>,
+}
+
+impl KeepAlive {
+    /// method for stopping the background task
+    ///
+    /// This is equivalent to dropping the [`KeepAlive`] handle.
+    pub fn cancel(self) {
+        drop(self);
+    }
+
+    /// method for checking whether the background task has stopped
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl Drop for KeepAlive {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl Stream for KeepAlive {
+    type Item = AuthEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.events.poll_recv(cx)
+    }
+}
+
+
+// result of a single check of the authentication state
+enum Check {
+    Alive,
+    Refreshed,
+    Expired,
+}
+
+impl BodhiClient {
+    /// method for starting a background task that keeps the authentication of this client alive
+    ///
+    /// The task checks the authentication state of this client once per `interval`, and refreshes
+    /// OpenID Connect access tokens that would expire before the next check, or renews the session
+    /// cookies by requesting a new CSRF token. Changes of the authentication state are reported as
+    /// [`AuthEvent`]s by the returned [`KeepAlive`] handle. The task shares the networking session
+    /// with this client, so refreshed credentials are used for all requests that are sent with
+    /// this client. The task is stopped when the [`KeepAlive`] handle is dropped.
+    ///
+    /// This method must be called from within a `tokio` runtime. If the client is not
+    /// authenticated, a [`QueryError::NotAuthenticated`] error is returned.
+    pub fn keep_alive(&self, interval: Duration) -> Result<KeepAlive, QueryError> {
+        if self.username.is_none() && self.oidc.is_none() {
+            return Err(QueryError::NotAuthenticated);
+        }
+
+        let client = self.with_overrides(self.overrides.clone(), self.retries, self.post_retries);
+        let (sender, events) = mpsc::unbounded_channel();
+
+        let task = tokio::spawn(async move {
+            let mut failed = false;
+
+            loop {
+                tokio::time::sleep(interval).await;
+
+                let event = match client.check_authentication(interval).await {
+                    Ok(Check::Alive) if failed => Some(AuthEvent::Restored),
+                    Ok(Check::Alive) => None,
+                    Ok(Check::Refreshed) => Some(AuthEvent::TokenRefreshed),
+                    Ok(Check::Expired) => Some(AuthEvent::SessionExpired),
+                    Err(error) => {
+                        log::warn!("Failed to refresh authentication: {}", error);
+                        Some(AuthEvent::Failed { error })
+                    },
+                };
+
+                failed = matches!(event, Some(AuthEvent::Failed { .. }));
+                let expired = matches!(event, Some(AuthEvent::SessionExpired));
+
+                if let Some(event) = event {
+                    // stop if the handle was dropped
+                    if sender.send(event).is_err() {
+                        break;
+                    }
+                }
+
+                if expired {
+                    break;
+                }
+            }
+        });
+
+        Ok(KeepAlive { events, task })
+    }
+
+    // check the authentication state, refreshing access tokens that expire before the next check
+    async fn check_authentication(&self, interval: Duration) -> Result<Check, QueryError> {
+        if let Some(oidc) = &self.oidc {
+            let (_, refreshed) = oidc.authorization_within(interval + REFRESH_MARGIN).await?;
+            return Ok(if refreshed { Check::Refreshed } else { Check::Alive });
+        }
+
+        if self.export_session().is_expired() {
+            return Ok(Check::Expired);
+        }
+
+        self.fetch_csrf_token().await?;
+        Ok(Check::Alive)
+    }
+}
@@ -0,0 +1,185 @@
+//! # client health metrics
+//!
+//! With the `metrics` feature, every [`BodhiClient`](crate::BodhiClient) keeps track of the number
+//! of HTTP requests it has sent, the number of failed requests and retries, the time spent waiting
+//! before retrying requests (including delays requested by the server with `Retry-After` headers),
+//! and the distribution of request durations. These values can be rendered in the Prometheus text
+//! exposition format with
+//! [`BodhiClient::render_prometheus`](crate::BodhiClient::render_prometheus).
+
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::request::RequestMethod;
+
+// upper bounds (in seconds) of the buckets of the request duration histogram
+const DURATION_BUCKETS: [f64; 10] = [0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0];
+
+// counters for one request method
+#[derive(Debug, Default)]
+struct MethodCounters {
+    requests: AtomicU64,
+    errors: AtomicU64,
+    retries: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Metrics {
+    get: MethodCounters,
+    post: MethodCounters,
+    // total time spent waiting before retries (in microseconds)
+    retry_wait: AtomicU64,
+    // request duration histogram (non-cumulative bucket counts, the last bucket is "+Inf")
+    duration_buckets: [AtomicU64; DURATION_BUCKETS.len() + 1],
+    // sum of all request durations (in microseconds)
+    duration_sum: AtomicU64,
+}
+
+impl Metrics {
+    fn counters(&self, method: RequestMethod) -> &MethodCounters {
+        match method {
+            RequestMethod::GET => &self.get,
+            RequestMethod::POST => &self.post,
+        }
+    }
+
+    // record one request attempt (including reading the response) and its outcome
+    pub(crate) fn record_request(&self, method: RequestMethod, duration: Duration, success: bool) {
+        let counters = self.counters(method);
+        counters.requests.fetch_add(1, Ordering::Relaxed);
+        if !success {
+            counters.errors.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let seconds = duration.as_secs_f64();
+        let bucket = DURATION_BUCKETS
+            .iter()
+            .position(|bound| seconds <= *bound)
+            .unwrap_or(DURATION_BUCKETS.len());
+        self.duration_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        self.duration_sum.fetch_add(micros(duration), Ordering::Relaxed);
+    }
+
+    // record a retry of a failed request after waiting for the given duration
+    pub(crate) fn record_retry(&self, method: RequestMethod, wait: Duration) {
+        self.counters(method).retries.fetch_add(1, Ordering::Relaxed);
+        self.retry_wait.fetch_add(micros(wait), Ordering::Relaxed);
+    }
+
+    // render all metrics in the Prometheus text exposition format
+    pub(crate) fn render(&self) -> String {
+        let mut output = String::new();
+
+        // writing into a String cannot fail
+        let _ = self.render_into(&mut output);
+
+        output
+    }
+
+    fn render_into(&self, output: &mut String) -> std::fmt::Result {
+        let load = |value: &AtomicU64| value.load(Ordering::Relaxed);
+
+        let counters = [
+            (
+                "bodhi_requests_total",
+                "Number of HTTP requests sent to the bodhi server (including retries).",
+                load(&self.get.requests),
+                load(&self.post.requests),
+            ),
+            (
+                "bodhi_request_errors_total",
+                "Number of HTTP requests that failed (including requests that were retried).",
+                load(&self.get.errors),
+                load(&self.post.errors),
+            ),
+            (
+                "bodhi_request_retries_total",
+                "Number of retries of failed HTTP requests.",
+                load(&self.get.retries),
+                load(&self.post.retries),
+            ),
+        ];
+
+        for (name, help, get, post) in counters {
+            writeln!(output, "# HELP {name} {help}")?;
+            writeln!(output, "# TYPE {name} counter")?;
+            writeln!(output, "{name}{{method=\"GET\"}} {get}")?;
+            writeln!(output, "{name}{{method=\"POST\"}} {post}")?;
+        }
+
+        writeln!(
+            output,
+            "# HELP bodhi_retry_wait_seconds_total Time spent waiting before retrying failed HTTP requests."
+        )?;
+        writeln!(output, "# TYPE bodhi_retry_wait_seconds_total counter")?;
+        writeln!(
+            output,
+            "bodhi_retry_wait_seconds_total {}",
+            seconds(load(&self.retry_wait))
+        )?;
+
+        writeln!(
+            output,
+            "# HELP bodhi_request_duration_seconds Duration of HTTP requests (including reading the response)."
+        )?;
+        writeln!(output, "# TYPE bodhi_request_duration_seconds histogram")?;
+
+        let mut count = 0;
+        for (bound, bucket) in DURATION_BUCKETS.iter().zip(&self.duration_buckets) {
+            count += load(bucket);
+            writeln!(
+                output,
+                "bodhi_request_duration_seconds_bucket{{le=\"{bound}\"}} {count}"
+            )?;
+        }
+        count += load(&self.duration_buckets[DURATION_BUCKETS.len()]);
+        writeln!(output, "bodhi_request_duration_seconds_bucket{{le=\"+Inf\"}} {count}")?;
+        writeln!(
+            output,
+            "bodhi_request_duration_seconds_sum {}",
+            seconds(load(&self.duration_sum))
+        )?;
+        writeln!(output, "bodhi_request_duration_seconds_count {count}")?;
+
+        Ok(())
+    }
+}
+
+fn micros(duration: Duration) -> u64 {
+    u64::try_from(duration.as_micros()).unwrap_or(u64::MAX)
+}
+
+fn seconds(micros: u64) -> f64 {
+    Duration::from_micros(micros).as_secs_f64()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_prometheus() {
+        let metrics = Metrics::default();
+
+        metrics.record_request(RequestMethod::GET, Duration::from_millis(20), false);
+        metrics.record_retry(RequestMethod::GET, Duration::from_millis(1500));
+        metrics.record_request(RequestMethod::GET, Duration::from_millis(300), true);
+        metrics.record_request(RequestMethod::POST, Duration::from_secs(120), true);
+
+        let output = metrics.render();
+
+        assert!(output.contains("bodhi_requests_total{method=\"GET\"} 2\n"));
+        assert!(output.contains("bodhi_requests_total{method=\"POST\"} 1\n"));
+        assert!(output.contains("bodhi_request_errors_total{method=\"GET\"} 1\n"));
+        assert!(output.contains("bodhi_request_retries_total{method=\"GET\"} 1\n"));
+        assert!(output.contains("bodhi_retry_wait_seconds_total 1.5\n"));
+        assert!(output.contains("bodhi_request_duration_seconds_bucket{le=\"0.05\"} 1\n"));
+        assert!(output.contains("bodhi_request_duration_seconds_bucket{le=\"0.5\"} 2\n"));
+        assert!(output.contains("bodhi_request_duration_seconds_bucket{le=\"60\"} 2\n"));
+        assert!(output.contains("bodhi_request_duration_seconds_bucket{le=\"+Inf\"} 3\n"));
+        assert!(output.contains("bodhi_request_duration_seconds_sum 120.32\n"));
+        assert!(output.contains("bodhi_request_duration_seconds_count 3\n"));
+    }
+}
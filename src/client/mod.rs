@@ -0,0 +1,2616 @@
+//! # bodhi API client implementation
+//!
+//! This module contains data structures and implementations for creating a bodhi client session,
+//! and for sending requests to a bodhi server.
+
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures_util::{stream, Stream};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER};
+use reqwest::{Client, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::data::{Comment, Karma, Update, User, FEDORA_BODHI_STG_URL, FEDORA_BODHI_URL};
+use crate::error::{BodhiError, LoginError, PingError, QueryError, ResponseInfo};
+use crate::query::{
+    CSRFQuery,
+    CommentQuery,
+    OverrideNVRQuery,
+    Paginated,
+    RawQuery,
+    UpdateIDQuery,
+    UpdateQuery,
+    UserNameQuery,
+};
+use crate::request::{
+    ConflictCheck,
+    KarmaCheck,
+    PaginatedRequest,
+    Pagination,
+    RequestMethod,
+    RetryCheck,
+    SingleRequest,
+};
+
+mod config;
+use config::ClientConfig;
+
+mod csrf;
+use csrf::{is_csrf_error, CachedToken, CsrfCache};
+
+mod cookies;
+use cookies::CachingJar;
+
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "metrics")]
+use metrics::Metrics;
+
+#[cfg(feature = "keep-alive")]
+mod keepalive;
+#[cfg(feature = "keep-alive")]
+pub use keepalive::{AuthEvent, KeepAlive};
+
+mod oidc;
+use oidc::OidcSession;
+
+mod options;
+pub use options::RequestOptions;
+
+mod request_id;
+pub(crate) use request_id::RequestId;
+
+mod session;
+use session::SessionOptions;
+
+mod state;
+pub use state::SessionState;
+
+mod set;
+pub use set::BodhiClientSet;
+
+mod transport;
+pub use transport::Transport;
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::query::ReleaseNameQuery;
+
+// This constant defines how many items are queried every time for multi-page queries. The
+// server-side maximum is 100, the default is 20, and 50 seems to be a good compromise between
+// the frequency of server timeouts, request failures, and query speed.
+pub(crate) const DEFAULT_ROWS: u32 = 50;
+
+// Specify a longer timeout duration (60 s) for bodhi requests. The `reqwest` default value of 30
+// seconds is a bit too short for long-running queries.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+// Specify a number of retries in case of connection or transient server failures.
+const REQUEST_RETRIES: usize = 3;
+
+// Requests that modify server state are not retried by default, since they might have already
+// been applied on the server even if the request failed.
+const POST_REQUEST_RETRIES: usize = 0;
+
+// Limit the duration to wait before retrying requests if the server is temporarily unavailable,
+// since maintenance windows can be announced with `Retry-After` values of several hours.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+// Limit the size of single server responses (16 MiB) to protect against misbehaving servers or
+// proxies. Even pages with the maximum number of rows are much smaller than this.
+const MAX_RESPONSE_SIZE: usize = 16 * 1024 * 1024;
+
+// Limit the combined size of all responses of paginated requests (256 MiB). This is big enough for
+// querying all builds, comments, or updates of a release, which are the largest existing queries.
+const MAX_PAGINATED_RESPONSE_SIZE: usize = 256 * 1024 * 1024;
+
+// Specify a sane default user agent for bodhi-rs.
+const USER_AGENT: &str = concat!("bodhi-rs v", env!("CARGO_PKG_VERSION"));
+
+// Send request IDs in the header that is most commonly used for this purpose by default.
+const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+// Reuse CSRF tokens for 10 minutes. Tokens are valid for the whole session, but they are refreshed
+// regularly in case the server-side session changes.
+const CSRF_TOKEN_TTL: Duration = Duration::from_secs(600);
+
+
+#[derive(Debug)]
+enum BodhiServiceType {
+    Default,
+    Staging,
+    Custom { openid_url: String },
+}
+
+impl BodhiServiceType {
+    // URL of the OpenID provider that is used for authentication
+    fn openid_url(self) -> Result<Url, url::ParseError> {
+        match self {
+            BodhiServiceType::Default => Url::parse(session::FEDORA_OPENID_URL),
+            BodhiServiceType::Staging => Url::parse(session::FEDORA_OPENID_STG_URL),
+            BodhiServiceType::Custom { openid_url } => {
+                log::warn!("Authenticating with nonstandard OpenID provider URL: {}", openid_url);
+                Url::parse(&openid_url)
+            },
+        }
+    }
+}
+
+
+/// This data type contains all information that is required to build a [`BodhiClient`] instance
+/// with necessary flags. Additionally, depending on whether username and password are supplied as
+/// arguments, building the service instance will try to return a privileged session by
+/// authenticating with the specified OpenID endpoint first.
+///
+/// It's possible to target either the Fedora production or the staging instances of bodhi, or
+/// provide a custom URL, via the `default()`, `staging()`, and `custom()` methods, respectively.
+///
+/// ```
+/// // create service with anonymous session
+/// let bodhi = bodhi::BodhiClientBuilder::default()
+///     .timeout(std::time::Duration::from_secs(42))
+///     .retries(9001)
+///     .build();
+/// ```
+///
+/// ```no_run
+/// // builder for an authenticated session
+/// let builder = bodhi::BodhiClientBuilder::staging()
+///     .timeout(std::time::Duration::from_secs(120))
+///     .retries(2)
+///     .authentication("bodhi-rs", "password1");
+/// let bodhi = builder.build();
+/// ```
+#[derive(Debug)]
+pub struct BodhiClientBuilder<'a> {
+    service_type: BodhiServiceType,
+    authentication: Option<Authentication<'a>>,
+    oidc: Option<OidcAuthentication<'a>>,
+    url: String,
+    timeout: Option<Duration>,
+    user_agent: Option<Cow<'a, str>>,
+    app_identity: Option<Cow<'a, str>>,
+    retries: Option<usize>,
+    post_retries: Option<usize>,
+    compression: Option<bool>,
+    max_response_size: Option<usize>,
+    max_paginated_response_size: Option<usize>,
+    request_id_header: Option<Cow<'a, str>>,
+    csrf_token_ttl: Option<Duration>,
+    csrf_timeout: Option<Duration>,
+    session: Option<SessionState>,
+    transport: Option<Arc<dyn Transport>>,
+}
+
+#[derive(Debug)]
+struct Authentication<'a> {
+    username: Cow<'a, str>,
+    password: Cow<'a, str>,
+}
+
+#[derive(Debug)]
+struct OidcAuthentication<'a> {
+    client_id: Cow<'a, str>,
+    client_secret: Cow<'a, str>,
+    scopes: Vec<&'a str>,
+}
+
+
+/// error type that represents a failure that occurs while initializing a [`BodhiClient`]
+#[derive(Debug, thiserror::Error)]
+pub enum BuilderError {
+    /// error while parsing base URL or login URL
+    ///
+    /// This error should only ever be returned when using custom URLs, never when constructing a
+    /// client for the default Fedora (production) or staging instances of bodhi.
+    #[error("Failed to parse service URL: {error}")]
+    UrlParsingError {
+        /// error that occurred while parsing the URL
+        #[from]
+        error: url::ParseError,
+    },
+    /// error while authenticating with an OpenID endpoint
+    #[error("Failed to log in: {error}")]
+    LoginError {
+        /// error that occurred during the OpenID authentication process
+        #[from]
+        error: LoginError,
+    },
+    /// error while reading settings from environment variables or a configuration file
+    #[error("Invalid configuration: {error}")]
+    ConfigurationError {
+        /// description of the invalid setting
+        error: String,
+    },
+    /// error while starting the runtime for blocking requests (only used by the `compat` module)
+    #[error("Failed to start runtime: {error}")]
+    RuntimeError {
+        /// error that occurred while starting the runtime
+        error: std::io::Error,
+    },
+}
+
+impl<'a> BodhiClientBuilder<'a> {
+    #[allow(clippy::should_implement_trait)]
+    /// constructor for [`BodhiClientBuilder`] for the default / production instance of bodhi
+    pub fn default() -> Self {
+        BodhiClientBuilder {
+            service_type: BodhiServiceType::Default,
+            authentication: None,
+            oidc: None,
+            url: FEDORA_BODHI_URL.to_string(),
+            timeout: None,
+            user_agent: None,
+            app_identity: None,
+            retries: None,
+            post_retries: None,
+            compression: None,
+            max_response_size: None,
+            max_paginated_response_size: None,
+            request_id_header: None,
+            csrf_token_ttl: None,
+            csrf_timeout: None,
+            session: None,
+            transport: None,
+        }
+    }
+
+    /// constructor for [`BodhiClientBuilder`] for the staging instance of bodhi
+    pub fn staging() -> Self {
+        BodhiClientBuilder {
+            service_type: BodhiServiceType::Staging,
+            authentication: None,
+            oidc: None,
+            url: FEDORA_BODHI_STG_URL.to_string(),
+            timeout: None,
+            user_agent: None,
+            app_identity: None,
+            retries: None,
+            post_retries: None,
+            compression: None,
+            max_response_size: None,
+            max_paginated_response_size: None,
+            request_id_header: None,
+            csrf_token_ttl: None,
+            csrf_timeout: None,
+            session: None,
+            transport: None,
+        }
+    }
+
+    /// constructor for [`BodhiClientBuilder`] with custom settings (user-specified base URLs)
+    pub fn custom(url: String, openid_url: String) -> Self {
+        BodhiClientBuilder {
+            service_type: BodhiServiceType::Custom { openid_url },
+            authentication: None,
+            oidc: None,
+            url,
+            timeout: None,
+            user_agent: None,
+            app_identity: None,
+            retries: None,
+            post_retries: None,
+            compression: None,
+            max_response_size: None,
+            max_paginated_response_size: None,
+            request_id_header: None,
+            csrf_token_ttl: None,
+            csrf_timeout: None,
+            session: None,
+            transport: None,
+        }
+    }
+
+    /// constructor for [`BodhiClientBuilder`] with settings from environment variables
+    ///
+    /// The following environment variables are supported (empty values are ignored):
+    ///
+    /// - `BODHI_URL`: base URL of a custom bodhi instance
+    /// - `BODHI_OPENID_URL`: URL of the OpenID provider for a custom bodhi instance (default: the
+    ///   Fedora OpenID provider)
+    /// - `BODHI_STAGING`: target the Fedora staging instance of bodhi (`1` / `true` / `yes` or `0`
+    ///   / `false` / `no`, default: `false`)
+    /// - `BODHI_USERNAME` and `BODHI_PASSWORD`: credentials for an authenticated session (both need
+    ///   to be set)
+    /// - `BODHI_TIMEOUT`: request timeout duration (in seconds)
+    /// - `BODHI_RETRIES`: retry count for failed read-only requests
+    /// - `BODHI_USER_AGENT`: value of the `User-Agent` header
+    /// - `BODHI_APP_IDENTITY`: identity of the application (see
+    ///   [`BodhiClientBuilder::app_identity`])
+    ///
+    /// If `BODHI_URL` is set, it takes precedence over `BODHI_STAGING`. If neither is set, the
+    /// Fedora production instance of bodhi is used.
+    ///
+    /// With the `config` feature enabled, the same settings are also read from the
+    /// `bodhi-rs.toml` file in the user's configuration directory (for example,
+    /// `~/.config/bodhi-rs.toml` on Linux), if it exists. Its keys are the names of the
+    /// environment variables in lowercase and without the `BODHI_` prefix (i.e. `url`,
+    /// `openid_url`, `staging`, `username`, `password`, `timeout`, `retries`, `user_agent`, and
+    /// `app_identity`).
+    ///
+    /// Settings from environment variables take precedence over settings from the configuration
+    /// file. Calling builder methods on the returned value overrides both.
+    ///
+    /// ```no_run
+    /// let builder = bodhi::BodhiClientBuilder::from_env()
+    ///     .expect("Invalid configuration.")
+    ///     .retries(2);
+    /// let bodhi = builder.build();
+    /// ```
+    pub fn from_env() -> Result<Self, BuilderError> {
+        #[cfg(feature = "config")]
+        let config = ClientConfig::from_default_file()?
+            .unwrap_or_default()
+            .merge(ClientConfig::from_env()?);
+        #[cfg(not(feature = "config"))]
+        let config = ClientConfig::from_env()?;
+
+        Self::from_config(config)
+    }
+
+    fn from_config(config: ClientConfig) -> Result<Self, BuilderError> {
+        let timeout = config.timeout();
+
+        let mut builder = match (config.url, config.staging) {
+            (Some(url), _) => {
+                let openid_url = config
+                    .openid_url
+                    .unwrap_or_else(|| session::FEDORA_OPENID_URL.to_string());
+                BodhiClientBuilder::custom(url, openid_url)
+            },
+            (None, Some(true)) => BodhiClientBuilder::staging(),
+            (None, _) => BodhiClientBuilder::default(),
+        };
+
+        builder.authentication = match (config.username, config.password) {
+            (Some(username), Some(password)) => Some(Authentication {
+                username: Cow::Owned(username),
+                password: Cow::Owned(password),
+            }),
+            (None, None) => None,
+            _ => {
+                return Err(BuilderError::ConfigurationError {
+                    error: String::from("Both username and password need to be set for authentication."),
+                })
+            },
+        };
+
+        builder.timeout = timeout;
+        builder.retries = config.retries;
+        builder.user_agent = config.user_agent.map(Cow::Owned);
+        builder.app_identity = config.app_identity.map(Cow::Owned);
+
+        Ok(builder)
+    }
+
+    /// method for overriding the default network request timeout
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// method for overriding the default User-Agent HTTP header that is used for requests
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: &'a str) -> Self {
+        self.user_agent = Some(Cow::Borrowed(user_agent));
+        self
+    }
+
+    /// method for identifying the application that uses this client
+    ///
+    /// The application identity (for example, `fedora-update-feedback/2.1`) is appended to the
+    /// User-Agent HTTP header, instead of replacing it (like [`BodhiClientBuilder::user_agent`]).
+    /// It is also included in log messages for requests, and in errors (see
+    /// [`QueryError::app_identity`]).
+    ///
+    /// Building the client fails with a [`BuilderError::ConfigurationError`] if the application
+    /// identity is not a valid header value.
+    #[must_use]
+    pub fn app_identity(mut self, app_identity: &'a str) -> Self {
+        self.app_identity = Some(Cow::Borrowed(app_identity));
+        self
+    }
+
+    /// method for overriding the name of the HTTP header that request IDs are sent in
+    ///
+    /// Every request is tagged with a randomly generated ID (a version 4 UUID), which is sent to
+    /// the server in this header (default: `X-Request-Id`), so client-side logs and errors can be
+    /// correlated with server-side logs. The ID of a failed request is available via
+    /// [`QueryError::client_request_id`].
+    ///
+    /// Building the client fails with a [`BuilderError::ConfigurationError`] if the header name is
+    /// not valid.
+    #[must_use]
+    pub fn request_id_header(mut self, header: &'a str) -> Self {
+        self.request_id_header = Some(Cow::Borrowed(header));
+        self
+    }
+
+    /// method for overriding the default number of retry attempts for read-only requests
+    ///
+    /// For paginated requests, every page has its own budget of retry attempts.
+    #[must_use]
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = Some(retries);
+        self
+    }
+
+    /// method for opting into retrying failed requests that modify server state
+    ///
+    /// By default, `POST` requests (i.e. creating or editing updates, overrides, or comments)
+    /// are never retried, because they might have already been applied on the server even if the
+    /// request failed. With this option enabled, requests that failed due to connection errors are
+    /// retried for the specified number of times, but only for request types that support checking
+    /// whether the request has already been applied:
+    ///
+    /// - creating buildroot overrides (by checking for an active override for the same build with
+    ///   the same notes and expiration date)
+    /// - creating updates from a list of builds (by checking for an existing update that contains
+    ///   any of the builds)
+    ///
+    /// Before every retry attempt, the server is queried for such a matching item. If one is
+    /// found, the request is not retried, and an [`QueryError::AlreadyApplied`] error is returned
+    /// instead. All other `POST` requests are still never retried.
+    #[must_use]
+    pub fn post_retries(mut self, retries: usize) -> Self {
+        self.post_retries = Some(retries);
+        self
+    }
+
+    /// method for enabling or disabling compressed responses
+    ///
+    /// With the `compression` feature enabled (which is the default), bodhi servers are asked to
+    /// send compressed responses (with `gzip`, `brotli`, or `deflate` encoding), which reduces the
+    /// amount of transferred data significantly, especially for large paginated queries.
+    /// Responses are transparently decompressed. Compression is enabled by default, but it can be
+    /// disabled with this method (for example, for debugging purposes). Without the
+    /// `compression` feature, this setting has no effect.
+    #[must_use]
+    pub fn compression(mut self, compression: bool) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// method for overriding the default maximum size of single server responses
+    ///
+    /// Responses with bodies that are larger than this (default: 16 MiB) are rejected with a
+    /// [`QueryError::ResponseTooLarge`] error instead of being read into memory completely. The
+    /// size is checked after decompression of compressed responses.
+    #[must_use]
+    pub fn max_response_size(mut self, bytes: usize) -> Self {
+        self.max_response_size = Some(bytes);
+        self
+    }
+
+    /// method for overriding the default maximum combined size of all responses of paginated
+    /// requests
+    ///
+    /// Paginated requests that return more data than this (default: 256 MiB) in total are
+    /// aborted with a [`QueryError::ResponseTooLarge`] error. Every single page is additionally
+    /// limited by [`BodhiClientBuilder::max_response_size`].
+    #[must_use]
+    pub fn max_paginated_response_size(mut self, bytes: usize) -> Self {
+        self.max_paginated_response_size = Some(bytes);
+        self
+    }
+
+    /// method for overriding how long CSRF tokens are reused for `POST` requests
+    ///
+    /// Every request that modifies server state needs a CSRF token. Tokens are cached and reused
+    /// for this duration (default: 10 minutes), which avoids an additional request before every
+    /// `POST` request when creating or editing many items. When a cached token is close to its
+    /// expiry, a new token is requested at the same time as the next `POST` request is sent.
+    /// Cached tokens that are rejected by the server are requested again once. Setting this to
+    /// zero disables caching.
+    #[must_use]
+    pub fn csrf_token_ttl(mut self, ttl: Duration) -> Self {
+        self.csrf_token_ttl = Some(ttl);
+        self
+    }
+
+    /// method for overriding the network request timeout for requesting CSRF tokens
+    ///
+    /// CSRF tokens are cheap to compute on the server, so this can be set to a shorter duration
+    /// than the timeout for other requests (see [`BodhiClientBuilder::timeout`]) to fail fast if
+    /// the server is unresponsive. By default, the same timeout is used for all requests.
+    #[must_use]
+    pub fn csrf_timeout(mut self, timeout: Duration) -> Self {
+        self.csrf_timeout = Some(timeout);
+        self
+    }
+
+    /// method for reusing the session of a previously constructed [`BodhiClient`]
+    ///
+    /// The session state can be exported with [`BodhiClient::export_session`]. If the session
+    /// cookies have not expired yet, the new client is authenticated without needing a username
+    /// and password. If username and password are supplied as well, they are only used for
+    /// logging in again if the session has already expired.
+    ///
+    /// Building the client fails with a [`BuilderError::ConfigurationError`] if the session state
+    /// belongs to a different bodhi instance.
+    #[must_use]
+    pub fn with_session(mut self, session: SessionState) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    /// method for sending requests to the bodhi server with a custom [`Transport`]
+    ///
+    /// By default, requests are sent over the network with the HTTP client of the networking
+    /// session. With a custom transport, all requests to the bodhi server (including requests for
+    /// CSRF tokens) are passed to the transport instead, and retries, error handling, and parsing
+    /// of responses work the same as for requests that are sent over the network. Requests for
+    /// logging in with username and password, and for obtaining OpenID Connect access tokens, are
+    /// still sent over the network.
+    ///
+    /// With the `mock` feature, the `bodhi::mock::MockTransport` type provides a transport that
+    /// answers requests with the bundled fixtures, without any network access.
+    #[must_use]
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// method for supplying username and password when using an authenticated bodhi API client
+    #[must_use]
+    pub fn authentication(mut self, username: &'a str, password: &'a str) -> Self {
+        self.authentication = Some(Authentication {
+            username: Cow::Borrowed(username),
+            password: Cow::Borrowed(password),
+        });
+        self
+    }
+
+    /// method for supplying OAuth2 client credentials for authenticating with OpenID Connect
+    ///
+    /// This is an alternative to [`BodhiClientBuilder::authentication`] for service accounts that
+    /// have been registered as OpenID Connect clients with the OpenID provider. Access tokens are
+    /// requested from the token endpoint of the OpenID provider (`/openidc/Token`) with the
+    /// "client credentials" grant, and are sent as bearer tokens with every request. Tokens are
+    /// refreshed automatically before they expire.
+    ///
+    /// If `scopes` is empty, the `openid` and `https://github.com/fedora-infra/bodhi` scopes are
+    /// requested. Clients that authenticate with OpenID Connect do not know the name of the
+    /// user they are authenticated as, so [`BodhiClient::username`] returns `None` for them.
+    ///
+    /// Building the client fails with a [`BuilderError::ConfigurationError`] if username and
+    /// password were supplied as well.
+    ///
+    /// ```no_run
+    /// # async fn doc() {
+    /// let bodhi = bodhi::BodhiClientBuilder::staging()
+    ///     .oidc_auth("bodhi-rs-bot", "secret", &[])
+    ///     .build()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn oidc_auth(mut self, client_id: &'a str, client_secret: &'a str, scopes: &[&'a str]) -> Self {
+        self.oidc = Some(OidcAuthentication {
+            client_id: Cow::Borrowed(client_id),
+            client_secret: Cow::Borrowed(client_secret),
+            scopes: scopes.to_vec(),
+        });
+        self
+    }
+
+    /// method for building a [`BodhiClient`] based on the parameters in this [`BodhiClientBuilder`]
+    ///
+    /// If authentication parameters (username and password) have been supplied as arguments as
+    /// well, calling this method will also attempt to authenticate via OpenID. If OpenID Connect
+    /// client credentials have been supplied instead (see [`BodhiClientBuilder::oidc_auth`]), an
+    /// initial access token is requested, so invalid credentials are detected early.
+    pub async fn build(self) -> Result<BodhiClient, BuilderError> {
+        let url = Url::parse(&self.url)?;
+        let login_url = url.join("/login?method=openid")?;
+
+        let timeout = self.timeout.unwrap_or(REQUEST_TIMEOUT);
+        let retries = self.retries.unwrap_or(REQUEST_RETRIES);
+        let post_retries = self.post_retries.unwrap_or(POST_REQUEST_RETRIES);
+        let user_agent = self.user_agent.unwrap_or(Cow::Borrowed(USER_AGENT));
+        let app_identity = self.app_identity.map(Cow::into_owned);
+
+        let user_agent = match &app_identity {
+            Some(app_identity) => {
+                if HeaderValue::from_str(app_identity).is_err() {
+                    return Err(BuilderError::ConfigurationError {
+                        error: format!("Invalid application identity: {app_identity}"),
+                    });
+                }
+                Cow::Owned(format!("{user_agent} {app_identity}"))
+            },
+            None => user_agent,
+        };
+        let max_response_size = self.max_response_size.unwrap_or(MAX_RESPONSE_SIZE);
+        let max_paginated_response_size = self.max_paginated_response_size.unwrap_or(MAX_PAGINATED_RESPONSE_SIZE);
+
+        let request_id_header = self.request_id_header.unwrap_or(Cow::Borrowed(REQUEST_ID_HEADER));
+        let request_id_header =
+            HeaderName::from_bytes(request_id_header.as_bytes()).map_err(|_| BuilderError::ConfigurationError {
+                error: format!("Invalid request ID header name: {request_id_header}"),
+            })?;
+
+        let options = SessionOptions {
+            user_agent: &user_agent,
+            timeout,
+            compression: self.compression.unwrap_or(true),
+        };
+
+        let session_state = match self.session {
+            Some(state) if state.url() != &url => {
+                return Err(BuilderError::ConfigurationError {
+                    error: format!("Session state belongs to a different bodhi instance: {}", state.url()),
+                });
+            },
+            Some(state) => Some(state),
+            None => None,
+        };
+
+        // the session is only still authenticated if its cookies have not expired yet
+        let (jar, username) = match session_state {
+            Some(state) => {
+                let expired = state.is_expired();
+                let (cookies, username) = state.into_parts();
+                (Some(CachingJar::from_store(cookies)), username.filter(|_| !expired))
+            },
+            None => (None, None),
+        };
+
+        let (session, jar, username, oidc) = match (self.authentication, self.oidc) {
+            (Some(_), Some(_)) => {
+                return Err(BuilderError::ConfigurationError {
+                    error: String::from(
+                        "Username / password and OpenID Connect authentication are mutually exclusive.",
+                    ),
+                });
+            },
+            (Some(auth), None) => {
+                let auth_url = self.service_type.openid_url()?;
+                let (session, jar) =
+                    session::openid_login(login_url, auth_url, &options, &auth.username, &auth.password, jar).await?;
+                (session, jar, Some(auth.username.into_owned()), None)
+            },
+            (None, Some(auth)) => {
+                let token_url = self.service_type.openid_url()?.join(oidc::OIDC_TOKEN_PATH)?;
+                let scopes = if auth.scopes.is_empty() {
+                    oidc::OIDC_DEFAULT_SCOPES
+                } else {
+                    auth.scopes.as_slice()
+                };
+
+                let (session, jar) = session::anonymous(&options, jar);
+                let oidc = OidcSession::new(
+                    token_url,
+                    auth.client_id.into_owned(),
+                    auth.client_secret.into_owned(),
+                    scopes,
+                    session.clone(),
+                );
+                oidc.authorization().await?;
+                (session, jar, None, Some(Arc::new(oidc)))
+            },
+            (None, None) => {
+                let (session, jar) = session::anonymous(&options, jar);
+                (session, jar, username, None)
+            },
+        };
+
+        let transport = match self.transport {
+            Some(transport) => transport,
+            None => Arc::new(session.clone()),
+        };
+
+        Ok(BodhiClient {
+            url,
+            session,
+            transport,
+            jar,
+            username,
+            app_identity,
+            retries,
+            post_retries,
+            max_response_size,
+            max_paginated_response_size,
+            request_id_header,
+            overrides: RequestOverrides::default(),
+            oidc,
+            csrf: Arc::new(CsrfCache::new(
+                self.csrf_token_ttl.unwrap_or(CSRF_TOKEN_TTL),
+                self.csrf_timeout,
+            )),
+            #[cfg(feature = "metrics")]
+            metrics: Arc::new(Metrics::default()),
+        })
+    }
+}
+
+
+/// data type that encapsulates all information that is required for making network requests
+///
+/// A successfully constructed [`BodhiClient`] contains a valid base URL for the given bodhi server
+/// instance, and a networking session that is set up with all necessary headers and cookies.
+#[derive(Debug)]
+pub struct BodhiClient {
+    url: Url,
+    session: Client,
+    transport: Arc<dyn Transport>,
+    jar: Arc<CachingJar>,
+    username: Option<String>,
+    app_identity: Option<String>,
+    retries: usize,
+    post_retries: usize,
+    max_response_size: usize,
+    max_paginated_response_size: usize,
+    request_id_header: HeaderName,
+    overrides: RequestOverrides,
+    oidc: Option<Arc<OidcSession>>,
+    csrf: Arc<CsrfCache>,
+    #[cfg(feature = "metrics")]
+    metrics: Arc<Metrics>,
+}
+
+// internal state of the stream returned by `BodhiClient::paginated_stream`
+struct PageStream<I> {
+    page: u32,
+    pages: Option<u32>,
+    items: Option<I>,
+    // size of all responses and number of retries that were needed for all pages so far
+    size: usize,
+    retries: usize,
+    failed: bool,
+}
+
+// settings that override the settings of the HTTP client for individual requests
+#[derive(Clone, Debug, Default)]
+struct RequestOverrides {
+    timeout: Option<Duration>,
+    headers: HeaderMap,
+}
+
+impl RequestOverrides {
+    // apply the overrides to a request
+    fn apply(&self, mut request: RequestBuilder) -> RequestBuilder {
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        request.headers(self.headers.clone())
+    }
+}
+
+// attach the request ID to the response, so it can be included in errors
+fn tag_response(mut response: Response, request_id: &RequestId) -> Response {
+    response.extensions_mut().insert(request_id.clone());
+    response
+}
+
+// detect responses from proxies or maintenance pages instead of the bodhi server
+fn service_unavailable(response: &Response) -> Option<QueryError> {
+    let status = response.status().as_u16();
+
+    // responses without a content type are assumed to be JSON
+    let json = match response.headers().get(CONTENT_TYPE) {
+        Some(value) => value.to_str().is_ok_and(|value| value.contains("json")),
+        None => true,
+    };
+
+    if status == 502 || status == 503 || !json {
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+
+        Some(QueryError::ServiceUnavailable {
+            retry_after,
+            response: Box::new(ResponseInfo::from_response(response)),
+        })
+    } else {
+        None
+    }
+}
+
+// parse the value of a `Retry-After` header (either a number of seconds or an HTTP date)
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+// check whether a failed `GET` request should be retried
+//
+// This includes errors that occur while reading the response body (for example, connections
+// that are closed in the middle of a response), not only errors while sending the request.
+fn is_transient(error: &QueryError) -> bool {
+    matches!(
+        error,
+        QueryError::RequestError { .. } | QueryError::EmptyResponse { .. } | QueryError::ServiceUnavailable { .. }
+    )
+}
+
+// duration to wait before retrying a failed request
+fn retry_delay(error: &QueryError, default: Duration) -> Duration {
+    match error {
+        QueryError::ServiceUnavailable {
+            retry_after: Some(retry_after),
+            ..
+        } => (*retry_after).min(MAX_RETRY_DELAY),
+        _ => default,
+    }
+}
+
+async fn try_get(
+    session: &Client,
+    transport: &dyn Transport,
+    url: Url,
+    body: Option<String>,
+    request_id: &RequestId,
+    overrides: &RequestOverrides,
+) -> Result<Response, QueryError> {
+    log::debug!("Sending GET request {}: {}", request_id, url);
+
+    let request = overrides
+        .apply(session.get(url))
+        .header(request_id.header(), request_id.as_str());
+    let request = match body {
+        Some(body) => request.body(body).build(),
+        None => request.build(),
+    };
+    let response = match request {
+        Ok(request) => transport.execute(request).await,
+        Err(error) => Err(error),
+    };
+
+    match response {
+        Ok(response) => {
+            let response = tag_response(response, request_id);
+            if let Some(error) = service_unavailable(&response) {
+                log::warn!("Server is temporarily unavailable: HTTP {}", response.status());
+                return Err(error);
+            }
+            // the content length is unknown for compressed and chunked responses
+            match response.content_length() {
+                Some(0) => {
+                    // response is empty
+                    log::warn!("Invalid server response: Expected JSON but received empty body.");
+                    Err(QueryError::EmptyResponse {
+                        response: Box::new(ResponseInfo::from_response(&response)),
+                    })
+                },
+                _ => {
+                    // return the first valid response
+                    Ok(response)
+                },
+            }
+        },
+        Err(error) => {
+            // take a breath, and keep on trying (or not)
+            Err(QueryError::RequestError { error })
+        },
+    }
+}
+
+async fn try_post(
+    session: &Client,
+    transport: &dyn Transport,
+    url: Url,
+    body: Option<String>,
+    request_id: &RequestId,
+    overrides: &RequestOverrides,
+) -> Result<Response, QueryError> {
+    log::debug!("Sending POST request {}: {}", request_id, url);
+
+    let request = overrides
+        .apply(session.post(url))
+        .header(request_id.header(), request_id.as_str());
+    let request = match body {
+        Some(body) => request.body(body).build(),
+        None => request.build(),
+    };
+    let response = match request {
+        Ok(request) => transport.execute(request).await,
+        Err(error) => Err(error),
+    };
+
+    match response {
+        Ok(response) => {
+            let response = tag_response(response, request_id);
+            if let Some(error) = service_unavailable(&response) {
+                log::warn!("Server is temporarily unavailable: HTTP {}", response.status());
+                return Err(error);
+            }
+            // the content length is unknown for compressed and chunked responses
+            match response.content_length() {
+                Some(0) => {
+                    // response is empty
+                    log::warn!("Invalid server response: Expected JSON but received empty body.");
+                    Err(QueryError::EmptyResponse {
+                        response: Box::new(ResponseInfo::from_response(&response)),
+                    })
+                },
+                _ => {
+                    // return the first valid response
+                    Ok(response)
+                },
+            }
+        },
+        Err(error) => {
+            // take a breath, and keep on trying (or not)
+            Err(QueryError::RequestError { error })
+        },
+    }
+}
+
+// read the response body, but abort as soon as it exceeds the size limit
+async fn read_body(mut response: Response, limit: usize) -> Result<String, QueryError> {
+    let too_large = |response: &Response| QueryError::ResponseTooLarge {
+        limit,
+        response: Box::new(ResponseInfo::from_response(response)),
+    };
+
+    // the content length is not known in advance for compressed and chunked responses
+    if response.content_length().is_some_and(|length| length > limit as u64) {
+        log::warn!("Server response exceeds the maximum size of {} bytes.", limit);
+        return Err(too_large(&response));
+    }
+
+    let mut body: Vec<u8> = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > limit {
+            log::warn!("Server response exceeds the maximum size of {} bytes.", limit);
+            return Err(too_large(&response));
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+async fn handle_response<R, P, T>(response: Response, request: &R, limit: usize) -> Result<(P, usize), QueryError>
+where
+    R: SingleRequest<P, T> + ?Sized,
+    T: DeserializeOwned,
+{
+    let status = response.status();
+    let info = ResponseInfo::from_response(&response);
+
+    if status.is_success() {
+        let string = read_body(response, limit).await?;
+
+        // empty bodies can only be detected after decompression
+        if string.is_empty() {
+            log::warn!("Invalid server response: Expected JSON but received empty body.");
+            return Err(QueryError::EmptyResponse {
+                response: Box::new(info),
+            });
+        }
+
+        let page = request
+            .parse(&string)
+            .map_err(|error| error.with_response(info.with_body(&string)))?;
+        Ok((page, string.len()))
+    } else if status == 404 {
+        let result = read_body(response, limit).await.unwrap_or_default();
+        Err(QueryError::NotFound {
+            response: Box::new(info.with_body(&result)),
+        })
+    } else {
+        let result = read_body(response, limit).await?;
+        let info = info.with_body(&result);
+        let error: BodhiError = serde_json::from_str(&result).map_err(|error| QueryError::DeserializationError {
+            error,
+            response: Some(Box::new(info.clone())),
+        })?;
+        Err(QueryError::BodhiError {
+            error,
+            response: Box::new(info),
+        })
+    }
+}
+
+impl BodhiClient {
+    fn session(&self) -> &Client {
+        &self.session
+    }
+
+    /// underlying HTTP client that is used for sending requests
+    ///
+    /// This can be used for sending requests to other services (for example, the koji REST API)
+    /// with the same settings as requests to bodhi: the same `User-Agent` header, timeout, and
+    /// compression settings. Requests also send an `Accept: application/json` header by default.
+    ///
+    /// The cookie jar is shared with the bodhi session, including cookies of authenticated
+    /// sessions. Cookies are only sent to the domains that set them, so bodhi session cookies are
+    /// not leaked to other services, but cookies that are set by other services are stored in the
+    /// same jar, and are included in exported sessions (see [`BodhiClient::export_session`]).
+    /// Other services must not be accessed with this client if that is not desired.
+    ///
+    /// This method is only available with the `raw-client` feature. The returned type is part of
+    /// the public API of the [`reqwest`] crate, so updates of the `reqwest` dependency of this
+    /// crate can be breaking changes for code that uses this method, even in minor releases.
+    #[cfg(feature = "raw-client")]
+    pub fn http_client(&self) -> &Client {
+        &self.session
+    }
+
+    /// method for exporting the state of the networking session of this client
+    ///
+    /// The returned [`SessionState`] can be persisted (for example, with
+    /// [`SessionState::write_to_file`]) and passed to [`BodhiClientBuilder::with_session`] to
+    /// construct a new client that reuses the (authenticated) session.
+    pub fn export_session(&self) -> SessionState {
+        SessionState::new(self.url.clone(), self.jar.to_store(), self.username.clone())
+    }
+
+    /// method for constructing a client that overrides some settings of this client
+    ///
+    /// The returned client shares the networking session (including cookies and authentication),
+    /// the request ID and application identity settings, and the metrics (if enabled) with this
+    /// client, and is cheap to construct. Settings that are overridden in `options` only apply to
+    /// requests that are sent with the returned client. This can be used, for example, for
+    /// allowing long-running queries to take more time without increasing the timeout for all
+    /// other requests.
+    ///
+    /// If any of the specified headers has an invalid name or value, a
+    /// [`BuilderError::ConfigurationError`] is returned.
+    pub fn with_options(&self, options: &RequestOptions) -> Result<BodhiClient, BuilderError> {
+        let mut overrides = self.overrides.clone();
+
+        if let Some(timeout) = options.timeout {
+            overrides.timeout = Some(timeout);
+        }
+
+        for (name, value) in &options.headers {
+            let name = HeaderName::from_bytes(name.as_bytes()).map_err(|_| BuilderError::ConfigurationError {
+                error: format!("Invalid header name: {name}"),
+            })?;
+            let value = HeaderValue::from_str(value).map_err(|_| BuilderError::ConfigurationError {
+                error: format!("Invalid value for header {name}: {value}"),
+            })?;
+            overrides.headers.insert(name, value);
+        }
+
+        Ok(self.with_overrides(
+            overrides,
+            options.retries.unwrap_or(self.retries),
+            options.post_retries.unwrap_or(self.post_retries),
+        ))
+    }
+
+    // construct a client that shares the session of this client, but uses different overrides
+    fn with_overrides(&self, overrides: RequestOverrides, retries: usize, post_retries: usize) -> BodhiClient {
+        BodhiClient {
+            url: self.url.clone(),
+            session: self.session.clone(),
+            transport: Arc::clone(&self.transport),
+            jar: Arc::clone(&self.jar),
+            username: self.username.clone(),
+            app_identity: self.app_identity.clone(),
+            retries,
+            post_retries,
+            max_response_size: self.max_response_size,
+            max_paginated_response_size: self.max_paginated_response_size,
+            request_id_header: self.request_id_header.clone(),
+            overrides,
+            oidc: self.oidc.clone(),
+            csrf: Arc::clone(&self.csrf),
+            #[cfg(feature = "metrics")]
+            metrics: Arc::clone(&self.metrics),
+        }
+    }
+
+    /// name of the user this client is authenticated as (if it is authenticated)
+    ///
+    /// This is the username that was supplied with [`BodhiClientBuilder::authentication`], or
+    /// the username that is associated with a non-expired session that was passed to
+    /// [`BodhiClientBuilder::with_session`].
+    pub fn username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+
+    /// identity of the application that uses this client (if set)
+    ///
+    /// See [`BodhiClientBuilder::app_identity`] for details.
+    pub fn app_identity(&self) -> Option<&str> {
+        self.app_identity.as_deref()
+    }
+
+    /// async method for querying the user this client is authenticated as
+    ///
+    /// The bodhi server does not provide an endpoint for querying the currently authenticated user,
+    /// so this method queries the user with the name that was used for authentication (see
+    /// [`BodhiClient::username`]). The returned [`User`] includes the list of groups the user is a
+    /// member of.
+    ///
+    /// If the client is not authenticated, a [`QueryError::NotAuthenticated`] error is returned
+    /// without sending a request to the server.
+    pub async fn whoami(&self) -> Result<User, QueryError> {
+        match &self.username {
+            Some(username) => self.request(&UserNameQuery::new(username)).await,
+            None => Err(QueryError::NotAuthenticated),
+        }
+    }
+
+    /// async method for checking whether the credentials of this client are still valid
+    ///
+    /// This method is intended for failing fast (for example, at the start of CI jobs) instead of
+    /// failing only when the first authenticated request is made. It does not modify any server
+    /// state: it checks that the session cookies have not expired yet, fetches a CSRF token, and
+    /// queries the user this client is authenticated as (see [`BodhiClient::whoami`]).
+    ///
+    /// The bodhi server does not provide an endpoint for validating sessions, so a successful
+    /// check cannot guarantee that the server still accepts the session. Wrong usernames or
+    /// passwords are already detected when logging in, in [`BodhiClientBuilder::build`].
+    ///
+    /// Failed requests are classified into [`PingError`] variants for rejected credentials,
+    /// expired sessions, and network failures.
+    pub async fn ping_authenticated(&self) -> Result<User, PingError> {
+        let username = self.username.as_deref().ok_or(PingError::NotAuthenticated)?;
+
+        if self.export_session().is_expired() {
+            return Err(PingError::SessionExpired {
+                username: username.to_owned(),
+            });
+        }
+
+        let classify = |error: QueryError| match error {
+            QueryError::RequestError { .. }
+            | QueryError::EmptyResponse { .. }
+            | QueryError::ServiceUnavailable { .. } => PingError::NetworkError { error: Box::new(error) },
+            QueryError::NotFound { .. } => PingError::InvalidCredentials {
+                username: username.to_owned(),
+                error: Box::new(error),
+            },
+            error if matches!(error.status(), Some(401 | 403)) => PingError::InvalidCredentials {
+                username: username.to_owned(),
+                error: Box::new(error),
+            },
+            error => PingError::QueryError { error },
+        };
+
+        self.request(&CSRFQuery::new()).await.map_err(classify)?;
+        self.request(&UserNameQuery::new(username)).await.map_err(classify)
+    }
+
+    /// render the health metrics of this client in the Prometheus text exposition format
+    ///
+    /// The output contains counters for the number of HTTP requests that were sent, failed, and
+    /// retried (labeled by request method), a counter for the time spent waiting before retries,
+    /// and a histogram of request durations. It can be served directly from the metrics endpoint
+    /// of long-running services.
+    ///
+    /// Metrics are tracked per client, starting from the time it was built. This method is only
+    /// available with the `metrics` feature.
+    #[cfg(feature = "metrics")]
+    pub fn render_prometheus(&self) -> String {
+        self.metrics.render()
+    }
+
+    /// async method for making a single-page `GET` or a `POST` request
+    ///
+    /// This method is used to handle single-page `GET` and `POST` requests. By default, `GET`
+    /// requests are retried for the specified number of times (default: 3) before an error is
+    /// returned. `POST` requests are not retried by default, because they might have already
+    /// modified server state even if the request timed out or returned an error. Retrying some
+    /// `POST` requests can be enabled with [`BodhiClientBuilder::post_retries`].
+    pub async fn request<P, T>(&self, request: &dyn SingleRequest<P, T>) -> Result<T, QueryError>
+    where
+        T: DeserializeOwned,
+    {
+        match request.method() {
+            RequestMethod::GET => self.request_get(request).await,
+            RequestMethod::POST => self.request_post(request).await,
+        }
+    }
+
+    /// async method for making a single-page request for an item that might not exist
+    ///
+    /// This method behaves the same as [`BodhiClient::request`], except that HTTP 404 responses
+    /// are mapped to `Ok(None)` instead of returning a [`QueryError::NotFound`] error. It is
+    /// intended to be used with queries for single items by ID, name, or NVR (for example,
+    /// [`UpdateIDQuery`], [`OverrideNVRQuery`], [`ReleaseNameQuery`], or [`UserNameQuery`]), so
+    /// that missing items can be handled separately from other failures.
+    pub async fn request_opt<P, T>(&self, request: &dyn SingleRequest<P, T>) -> Result<Option<T>, QueryError>
+    where
+        T: DeserializeOwned,
+    {
+        match self.request(request).await {
+            Ok(result) => Ok(Some(result)),
+            Err(QueryError::NotFound { .. }) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    // requests are passed by generic reference (instead of as trait objects) so the returned
+    // futures are `Send` if the concrete request type is `Sync`
+    async fn request_get<R, P, T>(&self, request: &R) -> Result<T, QueryError>
+    where
+        R: SingleRequest<P, T> + ?Sized,
+        T: DeserializeOwned,
+    {
+        let page = self.page_request_get(request).await?;
+        Ok(request.extract(page))
+    }
+
+    pub(crate) async fn page_request_get<R, P, T>(&self, request: &R) -> Result<P, QueryError>
+    where
+        R: SingleRequest<P, T> + ?Sized,
+        T: DeserializeOwned,
+    {
+        let (page, _size, _retries) = self.sized_page_request_get(request, self.max_response_size).await?;
+        Ok(page)
+    }
+
+    // make a single-page `GET` request with the given response size limit, and return the size of
+    // the response body and the number of retries that were needed together with the parsed page
+    async fn sized_page_request_get<R, P, T>(&self, request: &R, limit: usize) -> Result<(P, usize, usize), QueryError>
+    where
+        R: SingleRequest<P, T> + ?Sized,
+        T: DeserializeOwned,
+    {
+        let url = self
+            .url
+            .join(&request.path()?)
+            .map_err(|e| QueryError::UrlParsingError { error: e })?;
+        let body = request.body(None)?;
+        let request_id = RequestId::new(&self.request_id_header, self.app_identity.as_deref());
+
+        let mut retries = 0;
+        let mut reauthenticated = false;
+
+        loop {
+            #[cfg(feature = "metrics")]
+            let start = std::time::Instant::now();
+
+            let overrides = self.authorized_overrides().await?;
+
+            // the response body is read as part of every attempt, so failures in the middle of
+            // a response are retried in the same way as failures while sending the request
+            let result = match try_get(
+                self.session(),
+                self.transport.as_ref(),
+                url.clone(),
+                body.clone(),
+                &request_id,
+                &overrides,
+            )
+            .await
+            {
+                Ok(response) => handle_response(response, request, limit).await,
+                Err(error) => Err(error),
+            };
+
+            #[cfg(feature = "metrics")]
+            self.metrics
+                .record_request(RequestMethod::GET, start.elapsed(), result.is_ok());
+
+            match result {
+                Ok((page, size)) => break Ok((page, size, retries)),
+                Err(error) if !reauthenticated && self.is_rejected_token(&error) => {
+                    log::info!("Access token was rejected by the server, requesting a new one.");
+                    self.drop_access_token(&overrides).await;
+                    reauthenticated = true;
+                },
+                Err(error) if retries < self.retries && is_transient(&error) => {
+                    log::warn!("Retrying failed HTTP request {}: {}", request_id, error);
+                    let delay = retry_delay(&error, Duration::from_secs(1));
+                    tokio::time::sleep(delay).await;
+                    #[cfg(feature = "metrics")]
+                    self.metrics.record_retry(RequestMethod::GET, delay);
+                    retries += 1;
+                },
+                Err(error) => break Err(error),
+            }
+        }
+    }
+
+    async fn request_post<P, T>(&self, request: &dyn SingleRequest<P, T>) -> Result<T, QueryError>
+    where
+        T: DeserializeOwned,
+    {
+        let page = self.page_request_post(request).await?;
+        Ok(request.extract(page))
+    }
+
+    async fn page_request_post<P, T>(&self, request: &dyn SingleRequest<P, T>) -> Result<P, QueryError>
+    where
+        T: DeserializeOwned,
+    {
+        if let Some(check) = request.conflict_check() {
+            self.check_conflict(&check).await?;
+        }
+
+        let neutral_karma = match request.karma_check() {
+            Some(check) => self.check_karma(&check).await?,
+            None => false,
+        };
+
+        let (token, cached) = match self.csrf.get() {
+            CachedToken::Fresh(token) => (token, true),
+            CachedToken::Expiring(token) => {
+                // refresh the token at the same time as the request is sent
+                let refresh = async {
+                    if let Err(error) = self.fetch_csrf_token().await {
+                        log::warn!("Failed to refresh CSRF token: {}", error);
+                    }
+                };
+                let (result, ()) =
+                    futures_util::future::join(self.submit_post(request, token.clone(), neutral_karma), refresh).await;
+                return self
+                    .resubmit_on_csrf_error(request, result, &token, neutral_karma)
+                    .await;
+            },
+            CachedToken::Missing => (self.fetch_csrf_token().await?, false),
+        };
+
+        let result = self.submit_post(request, token.clone(), neutral_karma).await;
+
+        if cached {
+            self.resubmit_on_csrf_error(request, result, &token, neutral_karma)
+                .await
+        } else {
+            result
+        }
+    }
+
+    // request a new CSRF token and store it in the cache
+    async fn fetch_csrf_token(&self) -> Result<String, QueryError> {
+        let token = match self.csrf.timeout() {
+            Some(timeout) => {
+                let mut overrides = self.overrides.clone();
+                overrides.timeout = Some(timeout);
+                self.with_overrides(overrides, self.retries, self.post_retries)
+                    .request_get(&CSRFQuery::new())
+                    .await?
+            },
+            None => self.request_get(&CSRFQuery::new()).await?,
+        };
+
+        self.csrf.store(token.clone());
+        Ok(token)
+    }
+
+    // send the request again with a new token if the server rejected the cached CSRF token
+    async fn resubmit_on_csrf_error<P, T>(
+        &self,
+        request: &dyn SingleRequest<P, T>,
+        result: Result<P, QueryError>,
+        token: &str,
+        neutral_karma: bool,
+    ) -> Result<P, QueryError>
+    where
+        T: DeserializeOwned,
+    {
+        match result {
+            Err(QueryError::BodhiError { error, .. }) if is_csrf_error(&error.errors) => {
+                log::info!("Cached CSRF token was rejected by the server, requesting a new one.");
+                self.csrf.invalidate(token);
+                let token = self.fetch_csrf_token().await?;
+                self.submit_post(request, token, neutral_karma).await
+            },
+            result => result,
+        }
+    }
+
+    // send a `POST` request with the given CSRF token (and retry it, if supported and enabled)
+    async fn submit_post<P, T>(
+        &self,
+        request: &dyn SingleRequest<P, T>,
+        token: String,
+        neutral_karma: bool,
+    ) -> Result<P, QueryError>
+    where
+        T: DeserializeOwned,
+    {
+        let url = self
+            .url
+            .join(&request.path()?)
+            .map_err(|e| QueryError::UrlParsingError { error: e })?;
+        let body = request.body(Some(token))?;
+        let body = if neutral_karma {
+            KarmaCheck::neutralize(body)?
+        } else {
+            body
+        };
+
+        // only retry requests for which it can be checked whether they were already applied
+        let check = request.retry_check();
+        let mut retries = if check.is_some() { self.post_retries } else { 0 };
+
+        let request_id = RequestId::new(&self.request_id_header, self.app_identity.as_deref());
+        let mut reauthenticated = false;
+
+        loop {
+            #[cfg(feature = "metrics")]
+            let start = std::time::Instant::now();
+
+            let overrides = self.authorized_overrides().await?;
+
+            let result = match try_post(
+                self.session(),
+                self.transport.as_ref(),
+                url.clone(),
+                body.clone(),
+                &request_id,
+                &overrides,
+            )
+            .await
+            {
+                Ok(response) => Ok(handle_response(response, request, self.max_response_size).await),
+                Err(error) => Err(error),
+            };
+
+            #[cfg(feature = "metrics")]
+            self.metrics
+                .record_request(RequestMethod::POST, start.elapsed(), matches!(result, Ok(Ok(_))));
+
+            match result {
+                // requests that were rejected as unauthorized were not applied, so they can be
+                // sent again with a new access token
+                Ok(Err(error)) if !reauthenticated && self.is_rejected_token(&error) => {
+                    log::info!("Access token was rejected by the server, requesting a new one.");
+                    self.drop_access_token(&overrides).await;
+                    reauthenticated = true;
+                },
+                Ok(handled) => break handled.map(|(page, _size)| page),
+                Err(error) => match &check {
+                    Some(check) if retries > 0 => {
+                        let delay = retry_delay(&error, Duration::from_secs(1));
+                        tokio::time::sleep(delay).await;
+                        #[cfg(feature = "metrics")]
+                        self.metrics.record_retry(RequestMethod::POST, delay);
+
+                        if self.already_applied(check).await? {
+                            break Err(QueryError::AlreadyApplied { error: Box::new(error) });
+                        }
+
+                        log::warn!("Retrying failed HTTP request {}: {}", request_id, error);
+                        retries -= 1;
+                    },
+                    _ => break Err(error),
+                },
+            }
+        }
+    }
+
+    // settings that apply to the next request, including the current access token (for clients
+    // that are authenticated with OpenID Connect)
+    async fn authorized_overrides(&self) -> Result<Cow<'_, RequestOverrides>, QueryError> {
+        match &self.oidc {
+            Some(oidc) => {
+                let mut overrides = self.overrides.clone();
+                overrides.headers.insert(AUTHORIZATION, oidc.authorization().await?);
+                Ok(Cow::Owned(overrides))
+            },
+            None => Ok(Cow::Borrowed(&self.overrides)),
+        }
+    }
+
+    // check whether a request failed because the server did not accept the access token
+    fn is_rejected_token(&self, error: &QueryError) -> bool {
+        self.oidc.is_some() && error.status() == Some(401)
+    }
+
+    // drop the access token that was sent with a rejected request
+    async fn drop_access_token(&self, overrides: &RequestOverrides) {
+        if let (Some(oidc), Some(rejected)) = (&self.oidc, overrides.headers.get(AUTHORIZATION)) {
+            oidc.invalidate(rejected).await;
+        }
+    }
+
+    // check whether an item has been modified on the server since an edit was prepared
+    async fn check_conflict(&self, check: &ConflictCheck<'_>) -> Result<(), QueryError> {
+        let current = self.request_get(&UpdateIDQuery::new(check.alias)).await?;
+
+        match check.conflict(&current) {
+            Some(error) => Err(QueryError::ConflictError { error: Box::new(error) }),
+            None => Ok(()),
+        }
+    }
+
+    // check whether the authenticated user has already submitted karma for an update, and return
+    // whether the karma of the new comment needs to be replaced with neutral karma
+    async fn check_karma(&self, check: &KarmaCheck<'_>) -> Result<bool, QueryError> {
+        let username = self.username.as_deref().ok_or(QueryError::NotAuthenticated)?;
+
+        let updates = [check.update];
+        let users = [username];
+        let query = CommentQuery::new()
+            .updates(&updates)
+            .users(&users)
+            .embedded_updates(false);
+        let comments: Vec<Comment> = self.paginated_request(&query).await?;
+
+        let existing = comments
+            .iter()
+            .any(|comment| comment.user.name == username && comment.karma != Karma::Neutral);
+
+        match (existing, check.reject) {
+            (false, _) => Ok(false),
+            (true, false) => Ok(true),
+            (true, true) => Err(QueryError::AlreadyCommented {
+                update: check.update.to_owned(),
+                user: username.to_owned(),
+            }),
+        }
+    }
+
+    // check whether a failed `POST` request has already been applied on the server
+    async fn already_applied(&self, check: &RetryCheck<'_>) -> Result<bool, QueryError> {
+        match check {
+            RetryCheck::Override {
+                nvr,
+                notes,
+                expiration_date,
+            } => {
+                // this cannot use request_opt, since that would result in a recursive async call
+                let existing = match self.request_get(&OverrideNVRQuery::new(nvr)).await {
+                    Ok(over_ride) => over_ride,
+                    Err(QueryError::NotFound { .. }) => return Ok(false),
+                    Err(error) => return Err(error),
+                };
+
+                Ok(existing.expired_date.is_none()
+                    && existing.notes == *notes
+                    && existing.expiration_date == **expiration_date)
+            },
+            RetryCheck::Update { builds } => {
+                let existing: Vec<Update> = self.paginated_request(&UpdateQuery::new().builds(builds)).await?;
+                Ok(!existing.is_empty())
+            },
+        }
+    }
+
+    /// async method for making multi-page / paginated `GET` requests
+    ///
+    /// This method is used to handle paginated `GET` requests. Internally, this will result in a
+    /// stream of single-page requests to be handled by [`BodhiClient::request`]. This method
+    /// is intended to be more convenient than manually constructing and executing single-page
+    /// requests, handling errors, and then reassembling the results - as those things are all
+    /// handled by this method internally.
+    pub async fn paginated_request<P, V, T>(&self, request: &dyn PaginatedRequest<P, V>) -> Result<Vec<T>, QueryError>
+    where
+        P: Pagination,
+        V: IntoIterator<Item = T> + DeserializeOwned,
+        T: DeserializeOwned,
+    {
+        let results = self.paginated_request_all_pages(request).await?;
+        Ok(results.items)
+    }
+
+    /// async method for making multi-page / paginated `GET` requests, including metadata
+    ///
+    /// This method behaves the same as [`BodhiClient::paginated_request`], except that the
+    /// results are returned together with metadata about the request: the number of result pages,
+    /// the total number of items reported by the server, and the number of retries that were
+    /// needed for failed page requests.
+    ///
+    /// Every page request is retried independently (up to the number of retries configured with
+    /// [`BodhiClientBuilder::retries`]), so failures of single pages do not cause the whole query
+    /// to fail. If any retries were needed, the result is marked as [`Paginated::degraded`].
+    pub async fn paginated_request_with_metadata<P, V, T>(
+        &self,
+        request: &dyn PaginatedRequest<P, V>,
+    ) -> Result<Paginated<T>, QueryError>
+    where
+        P: Pagination,
+        V: IntoIterator<Item = T> + DeserializeOwned,
+        T: DeserializeOwned,
+    {
+        self.paginated_request_all_pages(request).await
+    }
+
+    /// method for making multi-page / paginated `GET` requests, returning results as a stream
+    ///
+    /// This method behaves the same as [`BodhiClient::paginated_request`], except that items are
+    /// yielded as soon as the page that contains them has been received, instead of collecting
+    /// the items from all pages before returning them. Pages are only requested when all items
+    /// of the previous page have been consumed, so dropping the stream cancels the remaining page
+    /// requests. Settings that were overridden with [`BodhiClient::with_options`] apply to all
+    /// page requests.
+    ///
+    /// If a page request fails (after retries), an error item is yielded, and the stream ends.
+    ///
+    /// ```no_run
+    /// use bodhi::{BodhiClientBuilder, UpdateQuery};
+    /// use futures_util::StreamExt;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let bodhi = BodhiClientBuilder::default().build().await.unwrap();
+    ///
+    /// let query = UpdateQuery::new().users(&["decathorpe"]);
+    /// let mut updates = Box::pin(bodhi.paginated_stream(&query));
+    ///
+    /// while let Some(update) = updates.next().await {
+    ///     println!("{}", update.unwrap().alias);
+    /// }
+    /// # }
+    /// ```
+    pub fn paginated_stream<'a, P, V, T>(
+        &'a self,
+        request: &'a dyn PaginatedRequest<P, V>,
+    ) -> impl Stream<Item = Result<T, QueryError>> + 'a
+    where
+        P: Pagination + 'a,
+        V: IntoIterator<Item = T> + DeserializeOwned + 'a,
+        V::IntoIter: 'a,
+        T: DeserializeOwned + 'a,
+    {
+        let state = PageStream {
+            page: 1,
+            pages: None,
+            items: None,
+            size: 0,
+            retries: 0,
+            failed: false,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(item) = state.items.as_mut().and_then(Iterator::next) {
+                    return Some((Ok(item), state));
+                }
+
+                if state.failed || state.pages.is_some_and(|pages| state.page > pages) {
+                    return None;
+                }
+
+                if state.page == 1 {
+                    // initialize progress callback with "zero progress"
+                    request.callback(0, 1);
+                }
+
+                let page_request = request.page_request(state.page);
+                let result = self
+                    .paginated_page_request_get(page_request.as_ref(), &mut state.size, &mut state.retries)
+                    .await;
+
+                match result {
+                    Ok(page) => {
+                        let pages = page.pages();
+                        request.callback(state.page, pages);
+
+                        state.items = Some(page_request.extract(page).into_iter());
+                        state.pages = Some(pages);
+                        state.page += 1;
+                    },
+                    Err(error) => {
+                        state.failed = true;
+                        return Some((Err(error), state));
+                    },
+                }
+            }
+        })
+    }
+
+    /// async method for making multi-page / paginated `GET` requests to arbitrary paths
+    ///
+    /// This method can be used for querying endpoints that are not (yet) supported by this crate.
+    /// The path needs to be relative to the base URL of the bodhi server (for example,
+    /// `/updates/`), and the parameters are passed as URL query arguments (parameters can be
+    /// specified multiple times). The `page` and `rows_per_page` parameters are set
+    /// automatically.
+    ///
+    /// Items are collected from the only field of the response which contains a list (for
+    /// example, `updates` for the `/updates/` endpoint) and returned as raw JSON values.
+    ///
+    /// ```no_run
+    /// # async fn doc() {
+    /// let bodhi = bodhi::BodhiClientBuilder::default().build().await.unwrap();
+    /// let results = bodhi
+    ///     .get_paginated_raw(
+    ///         "/updates/",
+    ///         &[("packages", "rust-bodhi"), ("releases", "F40")],
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    /// println!("{} updates", results.total);
+    /// # }
+    /// ```
+    pub async fn get_paginated_raw(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<Paginated<serde_json::Value>, QueryError> {
+        self.paginated_request_all_pages(&RawQuery::new(path, params)).await
+    }
+
+    async fn paginated_request_all_pages<P, V, T>(
+        &self,
+        request: &dyn PaginatedRequest<P, V>,
+    ) -> Result<Paginated<T>, QueryError>
+    where
+        P: Pagination,
+        V: IntoIterator<Item = T> + DeserializeOwned,
+        T: DeserializeOwned,
+    {
+        let mut results: Vec<T> = Vec::new();
+
+        // initialize progress callback with "zero progress"
+        request.callback(0, 1);
+
+        // size of all responses that were received so far
+        let mut size = 0usize;
+        // number of retries that were needed for all pages so far
+        let mut retries = 0usize;
+
+        let first_request = request.page_request(1);
+        let first_page = self
+            .paginated_page_request_get(first_request.as_ref(), &mut size, &mut retries)
+            .await?;
+
+        let mut page = 2u32;
+        let mut pages = first_page.pages();
+        let total = first_page.total();
+
+        // update progress callback with actual total pages
+        request.callback(1, pages);
+
+        results.extend(first_request.extract(first_page));
+
+        while page <= pages {
+            let page_request = request.page_request(page);
+            let next_page = self
+                .paginated_page_request_get(page_request.as_ref(), &mut size, &mut retries)
+                .await?;
+
+            request.callback(page, pages);
+
+            page += 1;
+            pages = next_page.pages();
+
+            results.extend(page_request.extract(next_page));
+        }
+
+        if retries > 0 {
+            log::info!("Paginated request needed {} retries for {} pages.", retries, pages);
+        }
+
+        request.sort(&mut results);
+
+        Ok(Paginated {
+            items: results,
+            pages,
+            total,
+            retries,
+            degraded: retries > 0,
+        })
+    }
+
+    // make a single-page `GET` request as part of a paginated request, limited to the remaining
+    // size budget of the paginated request (every page has its own budget for retries)
+    async fn paginated_page_request_get<P, T>(
+        &self,
+        request: &dyn SingleRequest<P, T>,
+        size: &mut usize,
+        retries: &mut usize,
+    ) -> Result<P, QueryError>
+    where
+        T: DeserializeOwned,
+    {
+        let remaining = self.max_paginated_response_size.saturating_sub(*size);
+        let limit = self.max_response_size.min(remaining);
+
+        match self.sized_page_request_get(request, limit).await {
+            Ok((page, page_size, page_retries)) => {
+                *size += page_size;
+                *retries += page_retries;
+                Ok(page)
+            },
+            // report the limit for the combined size if that was the one that was exceeded
+            Err(QueryError::ResponseTooLarge { response, .. }) if remaining < self.max_response_size => {
+                Err(QueryError::ResponseTooLarge {
+                    limit: self.max_paginated_response_size,
+                    response,
+                })
+            },
+            Err(error) => Err(error),
+        }
+    }
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    use super::*;
+    use crate::CommentCreator;
+
+    // serve a single canned raw HTTP response on a random local port
+    fn serve_raw(response: &'static str) -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buffer = [0u8; 4096];
+            let _ = stream.read(&mut buffer).unwrap();
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        url
+    }
+
+    // serve canned raw HTTP responses on a random local port, one for every connection
+    fn serve_sequence(responses: Vec<String>) -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+
+        std::thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut buffer = [0u8; 4096];
+                let _ = stream.read(&mut buffer).unwrap();
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        url
+    }
+
+    // serve JSON responses with the given status and body for every connection on a random local
+    // port, and record the request line of every request
+    //
+    // The response function is called with the complete request (starting with the request line).
+    fn serve_recorded<F>(mut respond: F) -> (Url, Arc<std::sync::Mutex<Vec<String>>>)
+    where
+        F: FnMut(&str) -> (&'static str, String) + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                // read until the headers and the complete request body have been received
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 4096];
+                loop {
+                    let size = stream.read(&mut buffer).unwrap();
+                    request.extend_from_slice(&buffer[..size]);
+
+                    let text = String::from_utf8_lossy(&request).to_lowercase();
+                    if let Some(end) = text.find("\r\n\r\n") {
+                        let length = text
+                            .lines()
+                            .find_map(|line| line.strip_prefix("content-length: "))
+                            .map(|length| length.trim().parse::<usize>().unwrap())
+                            .unwrap_or(0);
+                        if size == 0 || request.len() >= end + 4 + length {
+                            break;
+                        }
+                    }
+                }
+
+                let request = String::from_utf8_lossy(&request).into_owned();
+                let line = request.lines().next().unwrap_or_default().to_owned();
+                let (status, body) = respond(&request);
+                recorded.lock().unwrap().push(line);
+
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    // serve a single canned HTTP response with the given body on a random local port
+    fn serve(body: &'static str, chunked: bool) -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buffer = [0u8; 4096];
+            let _ = stream.read(&mut buffer).unwrap();
+
+            let response = if chunked {
+                format!(
+                    "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n{:x}\r\n{}\r\n0\r\n\r\n",
+                    body.len(),
+                    body
+                )
+            } else {
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+            };
+
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn response_size() {
+        let client = Client::new();
+
+        for chunked in [false, true] {
+            let response = client
+                .get(serve("{\"csrf_token\": \"abc\"}", chunked))
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(read_body(response, 64).await.unwrap(), "{\"csrf_token\": \"abc\"}");
+
+            let response = client
+                .get(serve("{\"csrf_token\": \"abc\"}", chunked))
+                .send()
+                .await
+                .unwrap();
+            assert!(matches!(
+                read_body(response, 8).await,
+                Err(QueryError::ResponseTooLarge { limit: 8, .. })
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn request_ids() {
+        let url = crate::mock::MockServer::new().start().unwrap();
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .request_id_header("X-Correlation-Id")
+            .retries(0)
+            .build()
+            .await
+            .unwrap();
+
+        let first = client.request(&UpdateIDQuery::new("FOO")).await.unwrap_err();
+        let second = client.request(&UpdateIDQuery::new("FOO")).await.unwrap_err();
+
+        let first = first.client_request_id().unwrap();
+        assert_eq!(first.len(), 36);
+        assert_ne!(Some(first), second.client_request_id());
+
+        assert!(matches!(
+            BodhiClientBuilder::default()
+                .request_id_header("X Request Id")
+                .build()
+                .await,
+            Err(BuilderError::ConfigurationError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn app_identity() {
+        let url = crate::mock::MockServer::new().start().unwrap();
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .app_identity("fedora-update-feedback/2.1")
+            .retries(0)
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(client.app_identity(), Some("fedora-update-feedback/2.1"));
+
+        let error = client.request(&UpdateIDQuery::new("FOO")).await.unwrap_err();
+        assert_eq!(error.app_identity(), Some("fedora-update-feedback/2.1"));
+
+        assert!(matches!(
+            BodhiClientBuilder::default()
+                .app_identity("invalid\nidentity")
+                .build()
+                .await,
+            Err(BuilderError::ConfigurationError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn service_unavailable() {
+        let client = Client::new();
+        let request_id = RequestId::new(&HeaderName::from_static("x-request-id"), None);
+
+        let url = serve_raw(
+            "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 120\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        );
+        match try_get(&client, &client, url, None, &request_id, &RequestOverrides::default()).await {
+            Err(error @ QueryError::ServiceUnavailable { retry_after, .. }) => {
+                assert_eq!(retry_after, Some(Duration::from_secs(120)));
+                assert_eq!(error.status(), Some(503));
+                assert_eq!(retry_delay(&error, Duration::from_secs(1)), MAX_RETRY_DELAY);
+            },
+            other => panic!("Unexpected result: {other:?}"),
+        }
+
+        let url = serve_raw(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: 22\r\nConnection: close\r\n\r\n<h1>Maintenance</h1>\r\n",
+        );
+        assert!(matches!(
+            try_post(&client, &client, url, None, &request_id, &RequestOverrides::default()).await,
+            Err(QueryError::ServiceUnavailable { retry_after: None, .. })
+        ));
+
+        let url = serve_raw(
+            "HTTP/1.1 502 Bad Gateway\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}",
+        );
+        assert!(matches!(
+            try_get(&client, &client, url, None, &request_id, &RequestOverrides::default()).await,
+            Err(QueryError::ServiceUnavailable { .. })
+        ));
+
+        assert_eq!(parse_retry_after(" 30 "), Some(Duration::from_secs(30)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+
+    #[cfg(feature = "raw-client")]
+    #[tokio::test]
+    async fn http_client() {
+        let url = serve("{\"status\": \"ok\"}", false);
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .user_agent("raw-client-test")
+            .build()
+            .await
+            .unwrap();
+
+        let response = client.http_client().get(url).send().await.unwrap();
+        assert_eq!(response.text().await.unwrap(), "{\"status\": \"ok\"}");
+    }
+
+    #[tokio::test]
+    async fn paginated_retries() {
+        let body = crate::mock::fixture("releases.json").unwrap();
+        let url = serve_sequence(vec![
+            String::from("HTTP/1.1 503 Service Unavailable\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"),
+            // connection is closed in the middle of the response body
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                &body[..body.len() / 2]
+            ),
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            ),
+        ]);
+
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .retries(2)
+            .build()
+            .await
+            .unwrap();
+
+        let result: Paginated<crate::data::Release> = client
+            .paginated_request_with_metadata(&crate::query::ReleaseQuery::new())
+            .await
+            .unwrap();
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.retries, 2);
+        assert!(result.degraded);
+
+        #[cfg(feature = "metrics")]
+        {
+            let metrics = client.render_prometheus();
+            assert!(metrics.contains("bodhi_requests_total{method=\"GET\"} 3\n"));
+            assert!(metrics.contains("bodhi_request_errors_total{method=\"GET\"} 2\n"));
+            assert!(metrics.contains("bodhi_request_retries_total{method=\"GET\"} 2\n"));
+            assert!(metrics.contains("bodhi_request_duration_seconds_count 3\n"));
+        }
+    }
+
+    // build a client with a session for the given user, which expires after the given duration
+    async fn session_client(url: &Url, username: &str, max_age: u32) -> BodhiClient {
+        use reqwest::cookie::CookieStore as _;
+
+        let jar = CachingJar::default();
+        let header = HeaderValue::from_str(&format!("authtkt=deadbeef; Path=/; Max-Age={max_age}")).unwrap();
+        jar.set_cookies(&mut std::iter::once(&header), url);
+        let state = SessionState::new(url.clone(), jar.to_store(), Some(username.to_owned()));
+
+        BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .with_session(state)
+            .retries(0)
+            .build()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn duplicate_karma() {
+        let url = crate::mock::MockServer::with_fixtures().start().unwrap();
+
+        // the "tester" user already submitted positive karma
+        let tester = session_client(&url, "tester", 3600).await;
+        let check = KarmaCheck {
+            update: "FEDORA-2024-1a2b3c4d5e",
+            reject: false,
+        };
+        assert!(tester.check_karma(&check).await.unwrap());
+
+        let creator = crate::CommentCreator::new("FEDORA-2024-1a2b3c4d5e")
+            .karma(Karma::Positive)
+            .duplicate_karma(crate::DuplicateKarma::Reject);
+        assert!(matches!(
+            tester.request(&creator).await,
+            Err(QueryError::AlreadyCommented { update, user })
+                if update == "FEDORA-2024-1a2b3c4d5e" && user == "tester"
+        ));
+
+        // the "bodhi" user only submitted comments with neutral karma
+        let bodhi = session_client(&url, "bodhi", 3600).await;
+        assert!(!bodhi.check_karma(&check).await.unwrap());
+
+        // existing karma cannot be checked without authentication
+        let anonymous = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .build()
+            .await
+            .unwrap();
+        assert!(matches!(
+            anonymous.check_karma(&check).await,
+            Err(QueryError::NotAuthenticated)
+        ));
+    }
+
+    #[tokio::test]
+    async fn ping_authenticated() {
+        let url = crate::mock::MockServer::with_fixtures().start().unwrap();
+
+        let client = session_client(&url, "packager", 3600).await;
+        assert_eq!(client.ping_authenticated().await.unwrap().name, "packager");
+
+        // the server does not know the user
+        let client = session_client(&url, "nobody", 3600).await;
+        assert!(matches!(
+            client.ping_authenticated().await,
+            Err(PingError::InvalidCredentials { username, .. }) if username == "nobody"
+        ));
+
+        // the session expires after the client was built
+        let client = session_client(&url, "packager", 1).await;
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        assert!(matches!(
+            client.ping_authenticated().await,
+            Err(PingError::SessionExpired { username }) if username == "packager"
+        ));
+
+        let anonymous = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .build()
+            .await
+            .unwrap();
+        assert!(matches!(
+            anonymous.ping_authenticated().await,
+            Err(PingError::NotAuthenticated)
+        ));
+
+        // the server cannot be reached
+        let closed = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap()
+        };
+        let client = session_client(&closed, "packager", 3600).await;
+        assert!(matches!(
+            client.ping_authenticated().await,
+            Err(PingError::NetworkError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn request_options() {
+        // serve one response and send the received request to the test
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buffer = [0u8; 4096];
+            let size = stream.read(&mut buffer).unwrap();
+            sender
+                .send(String::from_utf8_lossy(&buffer[..size]).into_owned())
+                .unwrap();
+            let body = crate::mock::fixture("csrf.json").unwrap();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .retries(3)
+            .build()
+            .await
+            .unwrap();
+
+        let scoped = client
+            .with_options(
+                &RequestOptions::new()
+                    .timeout(Duration::from_secs(5))
+                    .retries(0)
+                    .header("User-Agent", "custom-agent")
+                    .header("X-Custom", "value"),
+            )
+            .unwrap();
+        assert_eq!(scoped.retries, 0);
+        assert_eq!(client.retries, 3);
+
+        scoped.request(&CSRFQuery::new()).await.unwrap();
+
+        let request = receiver.recv().unwrap().to_lowercase();
+        assert!(request.contains("user-agent: custom-agent\r\n"));
+        assert!(request.contains("x-custom: value\r\n"));
+
+        assert!(matches!(
+            client.with_options(&RequestOptions::new().header("X-Custom", "invalid\nvalue")),
+            Err(BuilderError::ConfigurationError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn oidc_auth() {
+        let mut tokens = 0;
+        let (url, requests) = serve_recorded(move |request| {
+            if request.starts_with("POST /openidc/Token") {
+                tokens += 1;
+                return (
+                    "200 OK",
+                    format!(r#"{{"access_token": "token-{tokens}", "token_type": "Bearer", "expires_in": 3600}}"#),
+                );
+            }
+
+            // the first access token is rejected as if it had been revoked
+            if !request.to_lowercase().contains("authorization: bearer token-2\r\n") {
+                let error = r#"{"errors": [{"location": "header", "name": "authorization", "description": "Invalid token"}], "status": "error"}"#;
+                return ("401 Unauthorized", error.to_owned());
+            }
+
+            if request.starts_with("GET /csrf") {
+                ("200 OK", crate::mock::fixture("csrf.json").unwrap().to_owned())
+            } else {
+                ("200 OK", new_comment_json())
+            }
+        });
+        let openid_url = url.join("/openid/").unwrap();
+
+        let client = BodhiClientBuilder::custom(url.to_string(), openid_url.to_string())
+            .oidc_auth("bodhi-rs", "secret", &[])
+            .retries(0)
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(client.username(), None);
+
+        let creator = CommentCreator::new("FEDORA-2024-1a2b3c4d5e").text("comment");
+        client.request(&creator).await.unwrap();
+
+        // the rejected request is sent again with a new access token, which is reused afterwards
+        let lines: Vec<String> = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|line| line.split(' ').take(2).collect::<Vec<_>>().join(" "))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "POST /openidc/Token",
+                "GET /csrf",
+                "POST /openidc/Token",
+                "GET /csrf",
+                "POST /comments/"
+            ]
+        );
+
+        // username / password and client credentials cannot be combined
+        assert!(matches!(
+            BodhiClientBuilder::custom(url.to_string(), openid_url.to_string())
+                .authentication("bodhi-rs", "password")
+                .oidc_auth("bodhi-rs", "secret", &[])
+                .build()
+                .await,
+            Err(BuilderError::ConfigurationError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn oidc_auth_invalid_credentials() {
+        let (url, _requests) = serve_recorded(|_| ("401 Unauthorized", String::from(r#"{"error": "invalid_client"}"#)));
+
+        let result = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .oidc_auth("bodhi-rs", "wrong", &["openid"])
+            .build()
+            .await;
+        assert!(matches!(
+            result,
+            Err(BuilderError::LoginError {
+                error: LoginError::InvalidCredentials
+            })
+        ));
+    }
+
+    #[cfg(feature = "keep-alive")]
+    #[tokio::test]
+    async fn keep_alive_oidc() {
+        use futures_util::StreamExt;
+
+        let mut tokens = 0;
+        let (url, requests) = serve_recorded(move |_| {
+            tokens += 1;
+            // access tokens expire before the next check of the keep-alive task
+            (
+                "200 OK",
+                format!(r#"{{"access_token": "token-{tokens}", "token_type": "Bearer", "expires_in": 31}}"#),
+            )
+        });
+
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .oidc_auth("bodhi-rs", "secret", &[])
+            .build()
+            .await
+            .unwrap();
+
+        let mut keep_alive = client.keep_alive(Duration::from_millis(50)).unwrap();
+        let event = tokio::time::timeout(Duration::from_secs(5), keep_alive.next())
+            .await
+            .unwrap();
+        assert!(matches!(event, Some(AuthEvent::TokenRefreshed)));
+        assert!(requests.lock().unwrap().len() >= 2);
+
+        assert!(!keep_alive.is_finished());
+        keep_alive.cancel();
+    }
+
+    #[cfg(feature = "keep-alive")]
+    #[tokio::test]
+    async fn keep_alive_session() {
+        use futures_util::StreamExt;
+
+        let (url, requests) = serve_recorded(|_| ("200 OK", crate::mock::fixture("csrf.json").unwrap().to_owned()));
+
+        // session cookies are renewed until they expire
+        let client = session_client(&url, "packager", 1).await;
+        let mut keep_alive = client.keep_alive(Duration::from_millis(200)).unwrap();
+
+        let event = tokio::time::timeout(Duration::from_secs(5), keep_alive.next())
+            .await
+            .unwrap();
+        assert!(matches!(event, Some(AuthEvent::SessionExpired)));
+        assert!(keep_alive.next().await.is_none());
+        assert!(requests
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|line| line.starts_with("GET /csrf")));
+
+        let anonymous = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .build()
+            .await
+            .unwrap();
+        assert!(matches!(
+            anonymous.keep_alive(Duration::from_secs(60)),
+            Err(QueryError::NotAuthenticated)
+        ));
+    }
+
+    // response for `POST` requests to the `/comments/` endpoint
+    fn new_comment_json() -> String {
+        let mut comment: serde_json::Value =
+            serde_json::from_str(crate::mock::fixture("comment.json").unwrap()).unwrap();
+        comment["caveats"] = serde_json::json!([]);
+        comment.to_string()
+    }
+
+    #[tokio::test]
+    async fn csrf_token_cache() {
+        let (url, requests) = serve_recorded(|line| {
+            if line.starts_with("GET /csrf") {
+                ("200 OK", crate::mock::fixture("csrf.json").unwrap().to_owned())
+            } else {
+                ("200 OK", new_comment_json())
+            }
+        });
+
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .build()
+            .await
+            .unwrap();
+
+        let creator = CommentCreator::new("FEDORA-2024-1a2b3c4d5e").text("comment");
+        client.request(&creator).await.unwrap();
+        client.request(&creator).await.unwrap();
+
+        // scoped clients share the cached token
+        let scoped = client.with_options(&RequestOptions::new().retries(0)).unwrap();
+        scoped.request(&creator).await.unwrap();
+
+        let csrf = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.starts_with("GET /csrf"))
+            .count();
+        let posts = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.starts_with("POST"))
+            .count();
+        assert_eq!(csrf, 1);
+        assert_eq!(posts, 3);
+
+        // caching can be disabled
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .csrf_token_ttl(Duration::ZERO)
+            .build()
+            .await
+            .unwrap();
+        requests.lock().unwrap().clear();
+
+        client.request(&creator).await.unwrap();
+        client.request(&creator).await.unwrap();
+
+        let csrf = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.starts_with("GET /csrf"))
+            .count();
+        assert_eq!(csrf, 2);
+    }
+
+    #[tokio::test]
+    async fn csrf_token_refresh() {
+        let (url, requests) = serve_recorded(|line| {
+            if line.starts_with("GET /csrf") {
+                ("200 OK", crate::mock::fixture("csrf.json").unwrap().to_owned())
+            } else {
+                ("200 OK", new_comment_json())
+            }
+        });
+
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .csrf_token_ttl(Duration::from_millis(400))
+            .build()
+            .await
+            .unwrap();
+
+        let creator = CommentCreator::new("FEDORA-2024-1a2b3c4d5e").text("comment");
+        client.request(&creator).await.unwrap();
+
+        // the cached token is close to its expiry: it is used, but refreshed at the same time
+        tokio::time::sleep(Duration::from_millis(320)).await;
+        client.request(&creator).await.unwrap();
+        assert!(matches!(client.csrf.get(), CachedToken::Fresh(_)));
+
+        let csrf = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.starts_with("GET /csrf"))
+            .count();
+        let posts = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.starts_with("POST"))
+            .count();
+        assert_eq!(csrf, 2);
+        assert_eq!(posts, 2);
+    }
+
+    #[tokio::test]
+    async fn csrf_token_rejected() {
+        let mut posts = 0;
+        let (url, requests) = serve_recorded(move |line| {
+            if line.starts_with("GET /csrf") {
+                return ("200 OK", crate::mock::fixture("csrf.json").unwrap().to_owned());
+            }
+
+            // reject the second request as if the cached token had become invalid
+            posts += 1;
+            if posts == 2 {
+                (
+                    "400 Bad Request",
+                    String::from(
+                        r#"{"status": "error", "errors": [{"location": "body", "name": "csrf_token", "description": "CSRF tokens do not match"}]}"#,
+                    ),
+                )
+            } else {
+                ("200 OK", new_comment_json())
+            }
+        });
+
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .build()
+            .await
+            .unwrap();
+
+        // the first request uses a new token, which is cached
+        let creator = CommentCreator::new("FEDORA-2024-1a2b3c4d5e").text("comment");
+        client.request(&creator).await.unwrap();
+
+        // the cached token is rejected, so a new token is requested and the request is sent again
+        client.request(&creator).await.unwrap();
+
+        let lines: Vec<String> = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|r| r.split(' ').take(2).collect::<Vec<_>>().join(" "))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "GET /csrf",
+                "POST /comments/",
+                "POST /comments/",
+                "GET /csrf",
+                "POST /comments/"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn paginated_stream() {
+        use futures_util::StreamExt;
+
+        // three pages with one user each, where the third page fails
+        let (url, requests) = serve_recorded(|line| {
+            let page = if line.contains("page=1&") {
+                1
+            } else if line.contains("page=2&") {
+                2
+            } else {
+                return ("500 Internal Server Error", String::from("{}"));
+            };
+
+            let mut users: serde_json::Value =
+                serde_json::from_str(crate::mock::fixture("users.json").unwrap()).unwrap();
+            users["page"] = serde_json::json!(page);
+            users["pages"] = serde_json::json!(3);
+            users["total"] = serde_json::json!(3);
+            users["users"][0]["name"] = serde_json::json!(format!("user{page}"));
+            ("200 OK", users.to_string())
+        });
+
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .retries(0)
+            .build()
+            .await
+            .unwrap();
+        let query = crate::UserQuery::new();
+
+        // only the first page is requested if the stream is dropped early
+        let first: Vec<User> = client
+            .paginated_stream(&query)
+            .take(1)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(first[0].name, "user1");
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        // items are yielded until a page request fails, and the stream ends after the error
+        let results: Vec<Result<User, QueryError>> = client.paginated_stream(&query).collect().await;
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().name, "user1");
+        assert_eq!(results[1].as_ref().unwrap().name, "user2");
+        assert!(results[2].is_err());
+    }
+}
@@ -0,0 +1,261 @@
+//! # OpenID Connect authentication
+//!
+//! This module contains the implementation for authenticating requests with OAuth2 access tokens
+//! that are issued by an OpenID Connect provider, as an alternative to logging in with username
+//! and password (see [`BodhiClientBuilder::oidc_auth`](crate::BodhiClientBuilder::oidc_auth)).
+//! Access tokens are obtained with the "client credentials" grant, and they are sent as bearer
+//! tokens in the `Authorization` header of every request.
+//!
+//! Access tokens are refreshed shortly before they expire (with the refresh token, if the provider
+//! issued one, or by requesting a new access token otherwise). Tokens that are rejected by the
+//! server are dropped, and a new token is requested before the request is sent again.
+
+use std::time::{Duration, Instant};
+
+use reqwest::header::{HeaderValue, ACCEPT};
+use reqwest::Client;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+use url::Url;
+
+use crate::error::LoginError;
+
+// path of the token endpoint of the Fedora OpenID Connect provider (relative to the URL of the
+// OpenID provider)
+pub(crate) const OIDC_TOKEN_PATH: &str = "/openidc/Token";
+
+// scopes that are requested if no scopes were specified
+pub(crate) const OIDC_DEFAULT_SCOPES: &[&str] = &["openid", "https://github.com/fedora-infra/bodhi"];
+
+// access tokens are refreshed if they expire within this duration
+pub(crate) const REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
+
+// response format of the token endpoint for successful requests
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    token_type: String,
+    expires_in: Option<u64>,
+    refresh_token: Option<String>,
+}
+
+// response format of the token endpoint for failed requests
+#[derive(Debug, Deserialize)]
+struct TokenErrorResponse {
+    error: String,
+    error_description: Option<String>,
+}
+
+#[derive(Debug)]
+struct AccessToken {
+    header: HeaderValue,
+    refresh_token: Option<String>,
+    expires: Option<Instant>,
+}
+
+impl AccessToken {
+    fn is_fresh(&self, margin: Duration) -> bool {
+        match self.expires {
+            Some(expires) => expires.saturating_duration_since(Instant::now()) > margin,
+            None => true,
+        }
+    }
+}
+
+// client credentials and the current access token
+#[derive(Debug)]
+pub(crate) struct OidcSession {
+    token_url: Url,
+    client_id: String,
+    client_secret: String,
+    scope: String,
+    session: Client,
+    token: Mutex<Option<AccessToken>>,
+}
+
+impl OidcSession {
+    pub(crate) fn new(
+        token_url: Url,
+        client_id: String,
+        client_secret: String,
+        scopes: &[&str],
+        session: Client,
+    ) -> Self {
+        OidcSession {
+            token_url,
+            client_id,
+            client_secret,
+            scope: scopes.join(" "),
+            session,
+            token: Mutex::new(None),
+        }
+    }
+
+    // value of the `Authorization` header for the current access token
+    //
+    // A new access token is requested if there is no current token, or if it expires soon.
+    pub(crate) async fn authorization(&self) -> Result<HeaderValue, LoginError> {
+        self.authorization_within(REFRESH_MARGIN)
+            .await
+            .map(|(header, _)| header)
+    }
+
+    // value of the `Authorization` header for an access token that is valid for at least the
+    // given duration, and whether a new access token had to be requested for that
+    pub(crate) async fn authorization_within(&self, margin: Duration) -> Result<(HeaderValue, bool), LoginError> {
+        let mut token = self.token.lock().await;
+
+        if let Some(current) = &*token {
+            if current.is_fresh(margin) {
+                return Ok((current.header.clone(), false));
+            }
+        }
+
+        let refresh_token = token.take().and_then(|token| token.refresh_token);
+
+        let fresh = match refresh_token {
+            Some(refresh_token) => {
+                log::debug!("Access token expires soon, refreshing it.");
+                match self
+                    .request_token(&[("grant_type", "refresh_token"), ("refresh_token", &refresh_token)])
+                    .await
+                {
+                    Ok(fresh) => fresh,
+                    Err(error) => {
+                        log::info!("Failed to refresh access token, requesting a new one: {}", error);
+                        self.request_token(&[("grant_type", "client_credentials"), ("scope", &self.scope)])
+                            .await?
+                    },
+                }
+            },
+            None => {
+                self.request_token(&[("grant_type", "client_credentials"), ("scope", &self.scope)])
+                    .await?
+            },
+        };
+
+        let header = fresh.header.clone();
+        *token = Some(fresh);
+        Ok((header, true))
+    }
+
+    // drop the current access token if it matches the token that was rejected by the server
+    pub(crate) async fn invalidate(&self, rejected: &HeaderValue) {
+        let mut token = self.token.lock().await;
+        if matches!(&*token, Some(current) if current.header == rejected) {
+            *token = None;
+        }
+    }
+
+    async fn request_token(&self, params: &[(&str, &str)]) -> Result<AccessToken, LoginError> {
+        let requested = Instant::now();
+
+        let response = self
+            .session
+            .post(self.token_url.clone())
+            .header(ACCEPT, "application/json")
+            .basic_auth(&self.client_id, Some(&self.client_secret))
+            .form(params)
+            .send()
+            .await?;
+
+        let status = response.status();
+        let string = response.text().await?;
+
+        if !status.is_success() {
+            return Err(token_error(&string));
+        }
+
+        let token: TokenResponse = serde_json::from_str(&string).map_err(|error| LoginError::AuthenticationError {
+            error: format!("Invalid response from token endpoint: {error}"),
+        })?;
+
+        parse_token(token, requested)
+    }
+}
+
+fn token_error(body: &str) -> LoginError {
+    match serde_json::from_str::<TokenErrorResponse>(body) {
+        Ok(error) if matches!(error.error.as_str(), "invalid_client" | "unauthorized_client") => {
+            LoginError::InvalidCredentials
+        },
+        Ok(error) => LoginError::AuthenticationError {
+            error: match error.error_description {
+                Some(description) => format!("{}: {}", error.error, description),
+                None => error.error,
+            },
+        },
+        Err(_) => LoginError::AuthenticationError {
+            error: String::from("Token endpoint returned an error code."),
+        },
+    }
+}
+
+fn parse_token(token: TokenResponse, requested: Instant) -> Result<AccessToken, LoginError> {
+    if !token.token_type.eq_ignore_ascii_case("bearer") {
+        return Err(LoginError::AuthenticationError {
+            error: format!("Unsupported access token type: {}", token.token_type),
+        });
+    }
+
+    let mut header = HeaderValue::from_str(&format!("Bearer {}", token.access_token)).map_err(|_| {
+        LoginError::AuthenticationError {
+            error: String::from("Token endpoint returned an invalid access token."),
+        }
+    })?;
+    header.set_sensitive(true);
+
+    Ok(AccessToken {
+        header,
+        refresh_token: token.refresh_token,
+        // the lifetime of the token is counted from when it was requested
+        expires: token.expires_in.map(|seconds| requested + Duration::from_secs(seconds)),
+    })
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_expiry() {
+        let response = r#"{"access_token": "abc", "token_type": "Bearer", "expires_in": 3600}"#;
+        let token = parse_token(serde_json::from_str(response).unwrap(), Instant::now()).unwrap();
+        assert_eq!(token.header, "Bearer abc");
+        assert!(token.header.is_sensitive());
+        assert!(token.is_fresh(REFRESH_MARGIN));
+        assert!(!token.is_fresh(Duration::from_secs(7200)));
+
+        // tokens are refreshed shortly before they expire
+        let response = r#"{"access_token": "abc", "token_type": "bearer", "expires_in": 10}"#;
+        let token = parse_token(serde_json::from_str(response).unwrap(), Instant::now()).unwrap();
+        assert!(!token.is_fresh(REFRESH_MARGIN));
+
+        // tokens without expiry date are used until they are rejected
+        let response = r#"{"access_token": "abc", "token_type": "Bearer"}"#;
+        let token = parse_token(serde_json::from_str(response).unwrap(), Instant::now()).unwrap();
+        assert!(token.is_fresh(REFRESH_MARGIN));
+
+        let response = r#"{"access_token": "abc", "token_type": "mac"}"#;
+        assert!(parse_token(serde_json::from_str(response).unwrap(), Instant::now()).is_err());
+    }
+
+    #[test]
+    fn token_errors() {
+        assert!(matches!(
+            token_error(r#"{"error": "invalid_client"}"#),
+            LoginError::InvalidCredentials
+        ));
+        assert!(matches!(
+            token_error(r#"{"error": "invalid_scope", "error_description": "unknown scope"}"#),
+            LoginError::AuthenticationError { error } if error == "invalid_scope: unknown scope"
+        ));
+        assert!(matches!(
+            token_error("<html></html>"),
+            LoginError::AuthenticationError { .. }
+        ));
+    }
+}
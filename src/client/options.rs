@@ -0,0 +1,91 @@
+//! # per-request overrides of client settings
+//!
+//! Timeouts, retry counts, and default headers are configured for all requests when a
+//! [`BodhiClient`] is built. This module contains the [`RequestOptions`] type, which can be used
+//! to override these settings for a subset of requests, with [`BodhiClient::with_options`].
+
+use std::time::Duration;
+
+// imports for intra-doc links
+#[cfg(doc)]
+use super::{BodhiClient, BodhiClientBuilder};
+
+/// data type wrapping overrides of client settings for a subset of requests
+///
+/// Settings that are not overridden keep the values that were used for building the client. The
+/// overrides apply to all requests that are sent with the client returned by
+/// [`BodhiClient::with_options`]: single-page requests, paginated requests, and all helper
+/// methods that are built on top of them.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use bodhi::{RequestOptions, UpdateQuery};
+/// # use bodhi::mock::MockServer;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// // long-running query that is allowed to take more time and retry more often
+/// let patient = bodhi
+///     .with_options(
+///         &RequestOptions::new()
+///             .timeout(Duration::from_secs(300))
+///             .retries(10),
+///     )
+///     .unwrap();
+///
+/// let updates = patient
+///     .paginated_request(&UpdateQuery::new())
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RequestOptions {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) retries: Option<usize>,
+    pub(crate) post_retries: Option<usize>,
+    pub(crate) headers: Vec<(String, String)>,
+}
+
+impl RequestOptions {
+    /// constructor for [`RequestOptions`] without any overrides
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// override the timeout for requests (see [`BodhiClientBuilder::timeout`])
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// override the number of retries for failed `GET` requests (see
+    /// [`BodhiClientBuilder::retries`])
+    #[must_use]
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = Some(retries);
+        self
+    }
+
+    /// override the number of retries for failed `POST` requests (see
+    /// [`BodhiClientBuilder::post_retries`])
+    #[must_use]
+    pub fn post_retries(mut self, retries: usize) -> Self {
+        self.post_retries = Some(retries);
+        self
+    }
+
+    /// add an HTTP header that is sent with requests
+    ///
+    /// Headers with the same name as default headers of the client (for example, `User-Agent`)
+    /// replace the default values. Header names and values are validated in
+    /// [`BodhiClient::with_options`].
+    #[must_use]
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+}
@@ -0,0 +1,116 @@
+//! # request IDs for correlating client-side and server-side logs
+//!
+//! Every request is tagged with a randomly generated ID (a version 4 UUID), which is sent to the
+//! server in a configurable HTTP header. The ID is included in log messages and in errors, so
+//! failures can be correlated with server-side logs. Requests that are retried keep their ID.
+
+use std::collections::hash_map::RandomState;
+use std::fmt::{Display, Formatter};
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
+
+use reqwest::header::HeaderName;
+
+// counter that ensures that IDs are unique even if they are generated at the same time
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+// generate 64 random bits with the randomly seeded hasher from the standard library
+fn random_u64(counter: u64) -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(counter);
+    hasher.write_u128(nanos);
+    hasher.finish()
+}
+
+// generate a random (version 4) UUID in its canonical string representation
+fn generate_uuid() -> String {
+    let counter = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let mut value = (u128::from(random_u64(counter)) << 64) | u128::from(random_u64(!counter));
+
+    // set version (4: random) and variant (RFC 4122) bits
+    value = (value & !(0xf << 76)) | (0x4 << 76);
+    value = (value & !(0x3 << 62)) | (0x2 << 62);
+
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        value >> 96,
+        (value >> 80) & 0xffff,
+        (value >> 64) & 0xffff,
+        (value >> 48) & 0xffff,
+        value & 0xffff_ffff_ffff
+    )
+}
+
+
+// ID of a single request, the name of the header it is sent in, and the identity of the
+// application that sent it
+//
+// Values of this type are also attached to the extensions of server responses, so the ID can be
+// included in errors.
+#[derive(Clone, Debug)]
+pub(crate) struct RequestId {
+    header: HeaderName,
+    id: String,
+    app_identity: Option<String>,
+}
+
+impl RequestId {
+    pub(crate) fn new(header: &HeaderName, app_identity: Option<&str>) -> Self {
+        RequestId {
+            header: header.clone(),
+            id: generate_uuid(),
+            app_identity: app_identity.map(String::from),
+        }
+    }
+
+    pub(crate) fn header(&self) -> &HeaderName {
+        &self.header
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.id
+    }
+
+    pub(crate) fn app_identity(&self) -> Option<&str> {
+        self.app_identity.as_deref()
+    }
+}
+
+impl Display for RequestId {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match &self.app_identity {
+            Some(app_identity) => write!(f, "{} ({})", self.id, app_identity),
+            None => write!(f, "{}", self.id),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uuid_format() {
+        let first = generate_uuid();
+        let second = generate_uuid();
+        assert_ne!(first, second);
+
+        for uuid in [first, second] {
+            let groups: Vec<&str> = uuid.split('-').collect();
+            assert_eq!(
+                groups.iter().map(|group| group.len()).collect::<Vec<_>>(),
+                vec![8, 4, 4, 4, 12]
+            );
+            assert!(uuid.chars().all(|c| c == '-' || c.is_ascii_hexdigit()));
+            assert!(groups[2].starts_with('4'));
+            assert!(groups[3].starts_with(['8', '9', 'a', 'b']));
+        }
+    }
+}
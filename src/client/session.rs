@@ -0,0 +1,258 @@
+//! # networking sessions
+//!
+//! This module contains the implementation for setting up the networking session that is used by
+//! [`BodhiClient`](super::BodhiClient) instances: either an anonymous session, or a session that
+//! is authenticated with a Fedora OpenID provider. Cookies of authenticated sessions are cached on
+//! disk, so logging in again is only necessary when the cached session cookies have expired.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, LOCATION, USER_AGENT};
+use reqwest::redirect::Policy;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use super::cookies::{CachingJar, CookieCacheError};
+use crate::error::LoginError;
+
+// OpenID authentication endpoint of the Fedora production infrastructure
+pub(crate) const FEDORA_OPENID_URL: &str = "https://id.fedoraproject.org/api/v1/";
+
+// OpenID authentication endpoint of the Fedora staging infrastructure
+pub(crate) const FEDORA_OPENID_STG_URL: &str = "https://id.stg.fedoraproject.org/api/v1/";
+
+// additional request parameters that are required by the Fedora OpenID provider
+const OPENID_AUTH_MODULE: &str = "fedoauth.auth.fas.Auth_FAS";
+const OPENID_AUTH_FLOW: &str = "fedora";
+
+
+// response format of the OpenID provider API
+#[derive(Debug, Deserialize)]
+struct OpenIDResponse {
+    success: bool,
+    response: OpenIDParameters,
+}
+
+// parameters that are returned by the OpenID provider after successful authentication
+//
+// Only the `openid.return_to` parameter is needed for completing the authentication flow, all
+// other parameters are passed back to the original site as-is.
+#[derive(Debug, Deserialize, Serialize)]
+struct OpenIDParameters {
+    #[serde(rename = "openid.return_to")]
+    return_to: String,
+    #[serde(flatten)]
+    extra: HashMap<String, serde_json::Value>,
+}
+
+
+fn default_headers(user_agent: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+
+    headers.insert(
+        USER_AGENT,
+        HeaderValue::from_str(user_agent).unwrap_or_else(|_| HeaderValue::from_static(super::USER_AGENT)),
+    );
+    headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+
+    headers
+}
+
+// settings that apply to all requests of a session
+#[derive(Debug)]
+pub(crate) struct SessionOptions<'a> {
+    pub(crate) user_agent: &'a str,
+    pub(crate) timeout: Duration,
+    pub(crate) compression: bool,
+}
+
+fn build_client(options: &SessionOptions, jar: Arc<CachingJar>, redirects: bool) -> Client {
+    let builder = Client::builder()
+        .default_headers(default_headers(options.user_agent))
+        .timeout(options.timeout)
+        .cookie_provider(jar);
+
+    let builder = if redirects {
+        builder
+    } else {
+        builder.redirect(Policy::none())
+    };
+
+    // compressed responses are supported if the corresponding reqwest features are enabled,
+    // but the "no_*" methods for disabling them are always available
+    let builder = if options.compression {
+        builder
+    } else {
+        builder.no_gzip().no_brotli().no_deflate()
+    };
+
+    builder.build().expect("Failed to initialize the network stack.")
+}
+
+// construct a new session without authentication (optionally with existing session cookies)
+//
+// Redirects are not followed, since bodhi never returns redirects for API requests.
+pub(crate) fn anonymous(options: &SessionOptions, jar: Option<CachingJar>) -> (Client, Arc<CachingJar>) {
+    let jar = Arc::new(jar.unwrap_or_default());
+    (build_client(options, jar.clone(), false), jar)
+}
+
+// construct a new session that is authenticated with the given OpenID provider
+//
+// If no existing session cookies are passed, cached cookies are loaded from disk. If there are
+// non-expired cookies for the login URL, they are reused, and the OpenID authentication flow is
+// skipped.
+pub(crate) async fn openid_login(
+    login_url: Url,
+    auth_url: Url,
+    options: &SessionOptions<'_>,
+    username: &str,
+    password: &str,
+    jar: Option<CachingJar>,
+) -> Result<(Client, Arc<CachingJar>), LoginError> {
+    let jar = match jar.map_or_else(CachingJar::read_from_disk, Ok) {
+        Ok(jar) => jar,
+        Err(CookieCacheError::DoesNotExist) => {
+            log::info!("Creating new cookie cache.");
+            CachingJar::default()
+        },
+        Err(error) => {
+            log::info!("Failed to load cached cookies: {}", error);
+            CachingJar::default()
+        },
+    };
+
+    let jar = Arc::new(jar);
+
+    if jar.is_fresh(&login_url) {
+        log::debug!("Session cookie(s) are fresh, no re-authentication necessary.");
+    } else {
+        log::info!("Session cookie(s) have expired, re-authentication necessary.");
+
+        // redirects need to be handled manually to collect the OpenID request parameters
+        let client = build_client(options, jar.clone(), false);
+        authenticate(&client, login_url, auth_url, username, password).await?;
+    }
+
+    if let Err(error) = jar.write_to_disk() {
+        log::error!("Failed to write cached cookies: {}", error);
+    }
+
+    Ok((build_client(options, jar.clone(), true), jar))
+}
+
+async fn authenticate(
+    client: &Client,
+    login_url: Url,
+    auth_url: Url,
+    username: &str,
+    password: &str,
+) -> Result<(), LoginError> {
+    let mut url = login_url;
+    let mut state: HashMap<Cow<str>, Cow<str>> = HashMap::new();
+
+    // follow redirects until the login form is reached, and collect request parameters on the way
+    loop {
+        let response = client.get(url.clone()).send().await?;
+
+        for (key, value) in url.query_pairs() {
+            state.insert(Cow::Owned(key.into_owned()), Cow::Owned(value.into_owned()));
+        }
+
+        if !response.status().is_redirection() {
+            break;
+        }
+
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .ok_or_else(|| LoginError::RedirectionError {
+                error: String::from("No redirect URL provided in HTTP redirect headers."),
+            })?
+            .to_str()
+            .map_err(|_| LoginError::RedirectionError {
+                error: String::from("Failed to decode redirect URL."),
+            })?;
+
+        // redirect destinations can be relative to the current URL
+        url = url.join(location)?;
+    }
+
+    state.insert(Cow::Borrowed("username"), Cow::Borrowed(username));
+    state.insert(Cow::Borrowed("password"), Cow::Borrowed(password));
+    state.insert(Cow::Borrowed("auth_module"), Cow::Borrowed(OPENID_AUTH_MODULE));
+    state.insert(Cow::Borrowed("auth_flow"), Cow::Borrowed(OPENID_AUTH_FLOW));
+    state
+        .entry(Cow::Borrowed("openid.mode"))
+        .or_insert(Cow::Borrowed("checkid_setup"));
+
+    let response = client.post(auth_url).form(&state).send().await?;
+
+    // the only indication that authentication failed is a non-JSON response
+    let string = response.text().await?;
+    let openid: OpenIDResponse = serde_json::from_str(&string).map_err(|_| LoginError::InvalidCredentials)?;
+
+    if !openid.success {
+        return Err(LoginError::AuthenticationError {
+            error: String::from("OpenID endpoint returned an error code."),
+        });
+    }
+
+    let return_url = Url::parse(&openid.response.return_to)?;
+    let response = client.post(return_url).form(&openid.response).send().await?;
+
+    if !response.status().is_success() && !response.status().is_redirection() {
+        return Err(LoginError::AuthenticationError {
+            error: String::from("Failed to complete authentication with the original site."),
+        });
+    }
+
+    Ok(())
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn openid_parameters() {
+        let json = r#"{
+            "success": true,
+            "response": {
+                "openid.return_to": "https://bodhi.fedoraproject.org/login?method=openid",
+                "openid.mode": "id_res",
+                "openid.sreg.nickname": "janedoe"
+            }
+        }"#;
+
+        let parsed: OpenIDResponse = serde_json::from_str(json).unwrap();
+        assert!(parsed.success);
+        assert_eq!(
+            parsed.response.return_to,
+            "https://bodhi.fedoraproject.org/login?method=openid"
+        );
+
+        // all parameters need to be passed back to the original site
+        let form = serde_json::to_value(&parsed.response).unwrap();
+        assert_eq!(form["openid.return_to"], parsed.response.return_to);
+        assert_eq!(form["openid.mode"], "id_res");
+        assert_eq!(form["openid.sreg.nickname"], "janedoe");
+    }
+
+    #[test]
+    fn user_agent() {
+        let headers = default_headers("bodhi-rs tests");
+        assert_eq!(headers[USER_AGENT], "bodhi-rs tests");
+        assert_eq!(headers[ACCEPT], "application/json");
+
+        // invalid header values fall back to the default
+        let headers = default_headers("invalid\nheader");
+        assert_eq!(headers[USER_AGENT], super::super::USER_AGENT);
+    }
+}
@@ -0,0 +1,201 @@
+//! # sets of clients for multiple bodhi instances
+//!
+//! This module contains a keyed collection of [`BodhiClient`] instances, for tools that work with
+//! multiple bodhi instances at the same time (for example, both the Fedora production and staging
+//! instances), including methods for running the same request against all of them concurrently.
+
+use futures_util::future::join_all;
+use serde::de::DeserializeOwned;
+
+use super::{BodhiClient, BodhiClientBuilder, BuilderError};
+use crate::error::QueryError;
+use crate::request::{PaginatedRequest, Pagination, SingleRequest};
+
+/// data type that contains a set of [`BodhiClient`]s for multiple bodhi instances, keyed by name
+///
+/// Clients for the Fedora production and staging instances can be constructed by name with
+/// [`BodhiClientSet::with`], and clients for other instances can be added with
+/// [`BodhiClientSet::insert`]. Results of broadcast requests are returned in the same order in
+/// which the clients were added, and are tagged with the name of the instance.
+///
+/// ```no_run
+/// # async fn doc() {
+/// use bodhi::{BodhiClientSet, UpdateIDQuery};
+///
+/// let clients = BodhiClientSet::with(["prod", "staging"]).await.unwrap();
+/// let staging = clients.get("staging").unwrap();
+///
+/// for (instance, result) in clients
+///     .request_all(&UpdateIDQuery::new("FEDORA-2022-1234567890"))
+///     .await
+/// {
+///     println!("{instance}: {}", result.is_ok());
+/// }
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct BodhiClientSet {
+    clients: Vec<(String, BodhiClient)>,
+}
+
+impl BodhiClientSet {
+    /// constructor for an empty [`BodhiClientSet`]
+    pub fn new() -> Self {
+        BodhiClientSet::default()
+    }
+
+    /// constructor for a [`BodhiClientSet`] with anonymous clients for well-known bodhi instances
+    ///
+    /// The following instance names are supported:
+    ///
+    /// - `prod` / `production` / `default`: the Fedora production instance of bodhi
+    /// - `staging` / `stg`: the Fedora staging instance of bodhi
+    ///
+    /// Clients are constructed with default settings, and are registered under the names that
+    /// were passed to this method. Other names result in a [`BuilderError::ConfigurationError`].
+    pub async fn with<I, S>(names: I) -> Result<Self, BuilderError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut set = BodhiClientSet::new();
+
+        for name in names {
+            let name = name.into();
+
+            let builder = match name.as_str() {
+                "prod" | "production" | "default" => BodhiClientBuilder::default(),
+                "staging" | "stg" => BodhiClientBuilder::staging(),
+                _ => {
+                    return Err(BuilderError::ConfigurationError {
+                        error: format!("Unknown bodhi instance: {name}"),
+                    })
+                },
+            };
+
+            set.insert(name, builder.build().await?);
+        }
+
+        Ok(set)
+    }
+
+    /// method for adding a client to the set
+    ///
+    /// If a client with the same name was already present, it is replaced, and the old client is
+    /// returned.
+    pub fn insert(&mut self, name: impl Into<String>, client: BodhiClient) -> Option<BodhiClient> {
+        let name = name.into();
+
+        match self.clients.iter_mut().find(|(key, _)| *key == name) {
+            Some((_, existing)) => Some(std::mem::replace(existing, client)),
+            None => {
+                self.clients.push((name, client));
+                None
+            },
+        }
+    }
+
+    /// method for removing a client from the set
+    pub fn remove(&mut self, name: &str) -> Option<BodhiClient> {
+        let index = self.clients.iter().position(|(key, _)| key == name)?;
+        Some(self.clients.remove(index).1)
+    }
+
+    /// method for getting the client with the given name
+    pub fn get(&self, name: &str) -> Option<&BodhiClient> {
+        self.clients
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, client)| client)
+    }
+
+    /// names of all clients in this set (in the order in which they were added)
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.clients.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// number of clients in this set
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// check whether this set contains no clients
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// async method for running a single-page request against all instances concurrently
+    ///
+    /// The request is passed to [`BodhiClient::request`] for every client in this set. Results
+    /// are tagged with the name of the instance they were returned from.
+    pub async fn request_all<P, T>(&self, request: &dyn SingleRequest<P, T>) -> Vec<(&str, Result<T, QueryError>)>
+    where
+        T: DeserializeOwned,
+    {
+        let results = join_all(self.clients.iter().map(|(_, client)| client.request(request))).await;
+        self.names().zip(results).collect()
+    }
+
+    /// async method for running a multi-page / paginated request against all instances
+    /// concurrently
+    ///
+    /// The request is passed to [`BodhiClient::paginated_request`] for every client in this set.
+    /// Results are tagged with the name of the instance they were returned from.
+    pub async fn paginated_request_all<P, V, T>(
+        &self,
+        request: &dyn PaginatedRequest<P, V>,
+    ) -> Vec<(&str, Result<Vec<T>, QueryError>)>
+    where
+        P: Pagination,
+        V: IntoIterator<Item = T> + DeserializeOwned,
+        T: DeserializeOwned,
+    {
+        let results = join_all(self.clients.iter().map(|(_, client)| client.paginated_request(request))).await;
+        self.names().zip(results).collect()
+    }
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn instances() {
+        let mut set = BodhiClientSet::with(["prod", "staging"]).await.unwrap();
+
+        assert_eq!(set.names().collect::<Vec<_>>(), vec!["prod", "staging"]);
+        assert_eq!(
+            set.get("staging").unwrap().url.as_str(),
+            "https://bodhi.stg.fedoraproject.org/"
+        );
+        assert!(set.get("stg").is_none());
+
+        let custom = BodhiClientBuilder::custom(
+            String::from("https://bodhi.example.com"),
+            String::from("https://id.example.com/api/v1/"),
+        )
+        .build()
+        .await
+        .unwrap();
+
+        assert!(set.insert("custom", custom).is_none());
+        assert_eq!(set.len(), 3);
+
+        let replaced = set.insert("custom", BodhiClientBuilder::default().build().await.unwrap());
+        assert_eq!(replaced.unwrap().url.as_str(), "https://bodhi.example.com/");
+        assert_eq!(set.len(), 3);
+
+        assert!(set.remove("prod").is_some());
+        assert_eq!(set.names().collect::<Vec<_>>(), vec!["staging", "custom"]);
+    }
+
+    #[tokio::test]
+    async fn unknown_instance() {
+        assert!(matches!(
+            BodhiClientSet::with(["prod", "private"]).await,
+            Err(BuilderError::ConfigurationError { .. })
+        ));
+    }
+}
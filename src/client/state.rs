@@ -0,0 +1,209 @@
+//! # export and import of session state
+//!
+//! This module contains the implementation for exporting the state of the networking session of a
+//! [`BodhiClient`](super::BodhiClient) (i.e. its session cookies), and for constructing new clients
+//! that reuse an exported session. This makes it possible for long-lived command-line tools to
+//! persist authenticated sessions across invocations without storing the user's password.
+
+use std::path::Path;
+
+use chrono::DateTime;
+use cookie_store::{CookieExpiration, CookieStore};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use super::cookies::write_private;
+use crate::data::BodhiDate;
+use crate::error::SessionError;
+
+/// data type that contains the exported state of the networking session of a [`BodhiClient`]
+///
+/// Values of this type are returned by [`BodhiClient::export_session`], and can be passed to
+/// [`BodhiClientBuilder::with_session`] to construct a new client that reuses the session. The
+/// bodhi server does not use any other session tokens than cookies (CSRF tokens are requested for
+/// every `POST` request), so only persistent, non-expired cookies are included.
+///
+/// Session state can be (de)serialized with [`serde`], or written to and read from files with
+/// [`SessionState::write_to_file`] and [`SessionState::read_from_file`].
+///
+/// <div class="warning">The session state grants access to the authenticated session, and needs to
+/// be treated like a password.</div>
+///
+/// [`BodhiClient`]: super::BodhiClient
+/// [`BodhiClient::export_session`]: super::BodhiClient::export_session
+/// [`BodhiClientBuilder::with_session`]: super::BodhiClientBuilder::with_session
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionState {
+    url: Url,
+    cookies: CookieStore,
+    #[serde(default)]
+    username: Option<String>,
+}
+
+impl SessionState {
+    pub(crate) fn new(url: Url, cookies: CookieStore, username: Option<String>) -> Self {
+        SessionState { url, cookies, username }
+    }
+
+    pub(crate) fn into_parts(self) -> (CookieStore, Option<String>) {
+        (self.cookies, self.username)
+    }
+
+    /// base URL of the bodhi instance this session belongs to
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// name of the user this session was authenticated for (if it was authenticated)
+    pub fn username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+
+    /// earliest expiration date of the session cookies for the bodhi instance (if there are any)
+    pub fn expires(&self) -> Option<BodhiDate> {
+        self.cookies
+            .iter_unexpired()
+            .filter(|cookie| cookie.domain.matches(&self.url))
+            .filter_map(|cookie| match cookie.expires {
+                CookieExpiration::AtUtc(date) => DateTime::from_timestamp(date.unix_timestamp(), 0),
+                CookieExpiration::SessionEnd => None,
+            })
+            .min()
+            .map(BodhiDate::from)
+    }
+
+    /// flag to indicate whether there are no non-expired session cookies for the bodhi instance
+    ///
+    /// Expired sessions can still be passed to [`BodhiClientBuilder::with_session`], but they
+    /// will not be authenticated unless username and password are supplied as well.
+    ///
+    /// [`BodhiClientBuilder::with_session`]: super::BodhiClientBuilder::with_session
+    pub fn is_expired(&self) -> bool {
+        !self
+            .cookies
+            .iter_unexpired()
+            .any(|cookie| cookie.domain.matches(&self.url))
+    }
+
+    /// read session state from a JSON file
+    pub fn read_from_file(path: impl AsRef<Path>) -> Result<Self, SessionError> {
+        let contents = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// write session state to a JSON file
+    ///
+    /// On unix systems, the file is only readable and writable by the current user (mode `0600`),
+    /// even if it already existed with different permissions.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<(), SessionError> {
+        let contents = serde_json::to_string_pretty(self)?;
+        write_private(path.as_ref(), &contents)?;
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use reqwest::cookie::CookieStore as _;
+    use reqwest::header::HeaderValue;
+
+    use super::super::cookies::CachingJar;
+    use super::super::{BodhiClientBuilder, BuilderError};
+    use super::*;
+    use crate::error::QueryError;
+
+    #[test]
+    fn roundtrip() {
+        let url = Url::parse("https://bodhi.fedoraproject.org/").unwrap();
+
+        let jar = CachingJar::default();
+        let header = HeaderValue::from_static("authtkt=deadbeef; Path=/; Max-Age=3600");
+        jar.set_cookies(&mut std::iter::once(&header), &url);
+
+        let state = SessionState::new(url.clone(), jar.to_store(), Some(String::from("janedoe")));
+        assert!(!state.is_expired());
+        assert!(state.expires().is_some());
+
+        let path = std::env::temp_dir().join(format!("bodhi-rs-session-test-{}.json", std::process::id()));
+        state.write_to_file(&path).unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let read = SessionState::read_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(read.url(), &url);
+        assert_eq!(read.username(), Some("janedoe"));
+        assert_eq!(read.expires(), state.expires());
+
+        let (cookies, _) = read.into_parts();
+        let jar = CachingJar::from_store(cookies);
+        assert_eq!(jar.cookies(&url).unwrap(), "authtkt=deadbeef");
+    }
+
+    #[tokio::test]
+    async fn builder() {
+        let url = Url::parse("https://bodhi.fedoraproject.org/").unwrap();
+
+        let jar = CachingJar::default();
+        let header = HeaderValue::from_static("authtkt=deadbeef; Path=/; Max-Age=3600");
+        jar.set_cookies(&mut std::iter::once(&header), &url);
+        let state = SessionState::new(url, jar.to_store(), Some(String::from("janedoe")));
+
+        let client = BodhiClientBuilder::default()
+            .with_session(state.clone())
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(client.export_session().expires(), state.expires());
+        assert_eq!(client.username(), Some("janedoe"));
+
+        assert!(matches!(
+            BodhiClientBuilder::staging().with_session(state).build().await,
+            Err(BuilderError::ConfigurationError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn whoami() {
+        let url = crate::mock::MockServer::with_fixtures().start().unwrap();
+
+        let jar = CachingJar::default();
+        let header = HeaderValue::from_static("authtkt=deadbeef; Path=/; Max-Age=3600");
+        jar.set_cookies(&mut std::iter::once(&header), &url);
+        let state = SessionState::new(url.clone(), jar.to_store(), Some(String::from("packager")));
+
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .with_session(state)
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(client.whoami().await.unwrap().name, "packager");
+
+        // usernames of expired sessions are not used
+        let state = SessionState::new(url.clone(), CookieStore::default(), Some(String::from("packager")));
+        let client = BodhiClientBuilder::custom(url.to_string(), url.to_string())
+            .with_session(state)
+            .build()
+            .await
+            .unwrap();
+        assert!(client.username().is_none());
+        assert!(matches!(client.whoami().await, Err(QueryError::NotAuthenticated)));
+    }
+
+    #[test]
+    fn expired() {
+        let url = Url::parse("https://bodhi.fedoraproject.org/").unwrap();
+        let state = SessionState::new(url, CookieStore::default(), None);
+
+        assert!(state.is_expired());
+        assert!(state.expires().is_none());
+    }
+}
@@ -0,0 +1,39 @@
+//! # pluggable transport for requests to bodhi servers
+//!
+//! This module contains the [`Transport`] trait, which abstracts over how requests to a bodhi
+//! server are sent. By default, requests are sent over the network with the HTTP client of the
+//! networking session of a [`BodhiClient`]. Other transports can be used instead by passing them
+//! to [`BodhiClientBuilder::transport`], for example, for answering requests with canned responses
+//! in tests of programs that use this crate.
+
+use std::fmt::Debug;
+
+use futures_util::future::BoxFuture;
+use reqwest::{Client, Request, Response};
+
+// imports for intra-doc links
+#[cfg(doc)]
+use super::{BodhiClient, BodhiClientBuilder};
+
+
+/// trait for types that send requests to a bodhi server and return its responses
+///
+/// Requests are passed to [`Transport::execute`] after they have been fully constructed, including
+/// URL, timeout, body, and all headers that are specific to the request (authorization, request
+/// ID, CSRF tokens, and custom headers from [`RequestOptions`](super::RequestOptions)). The
+/// default headers of the networking session (`User-Agent` and `Accept`) and session cookies are
+/// only added by the [`reqwest::Client`] implementation of this trait.
+///
+/// The request and response types are part of the public API of the [`reqwest`] crate, so updates
+/// of the `reqwest` dependency of this crate can be breaking changes for implementations of this
+/// trait, even in minor releases.
+pub trait Transport: Debug + Send + Sync {
+    /// send a request and return the response of the server
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response, reqwest::Error>>;
+}
+
+impl Transport for Client {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response, reqwest::Error>> {
+        Box::pin(Client::execute(self, request))
+    }
+}
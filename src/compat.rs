@@ -0,0 +1,329 @@
+//! # blocking wrappers for code written against the 0.x / 1.x API
+//!
+//! Versions of this crate before 2.0 exposed a synchronous `BodhiService` type, and requests were
+//! run by calling `.query(&bodhi)`, `.create(&bodhi)`, or `.edit(&bodhi)` on them. This module
+//! contains deprecated adapters for these call patterns, which are implemented on top of the
+//! asynchronous [`BodhiClient`], so existing tools can be ported to the async API incrementally.
+//!
+//! The [`BodhiService`] type owns a single-threaded tokio runtime, which is used to drive the
+//! futures returned by the underlying [`BodhiClient`] to completion. Because of this, its methods
+//! must not be called from within an async context (they will panic if they are).
+//!
+//! ```
+//! #![allow(deprecated)]
+//!
+//! use bodhi::compat::{BodhiServiceBuilder, Query, SingleQuery};
+//! # use bodhi::mock::MockServer;
+//! use bodhi::{ReleaseQuery, UpdateIDQuery};
+//!
+//! # let url = MockServer::with_fixtures().start().unwrap();
+//! # let openid_url = url.join("/openid/").unwrap();
+//! # let builder = BodhiServiceBuilder::custom(url.to_string(), openid_url.to_string());
+//! # /*
+//! let builder = BodhiServiceBuilder::default();
+//! # */
+//! let bodhi = builder.build().unwrap();
+//!
+//! let releases = ReleaseQuery::new().query(&bodhi).unwrap();
+//! let update = UpdateIDQuery::new("FEDORA-2024-1a2b3c4d5e")
+//!     .query(&bodhi)
+//!     .unwrap();
+//! ```
+//!
+//! New code should use [`BodhiClient`] directly. The methods of the blocking wrappers map onto
+//! the asynchronous API like this:
+//!
+//! - `query.query(&bodhi)` for paginated queries: [`BodhiClient::paginated_request`]
+//! - `query.query(&bodhi)` for single-page queries: [`BodhiClient::request`]
+//! - `creator.create(&bodhi)` and `editor.edit(&bodhi)`: [`BodhiClient::request`]
+//!
+//! [`BodhiService::client`] can be used to get access to the wrapped [`BodhiClient`] while
+//! porting code from the blocking wrappers to the asynchronous API.
+
+#![allow(deprecated)]
+
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use tokio::runtime::Runtime;
+
+use crate::client::{BodhiClient, BodhiClientBuilder, BuilderError};
+use crate::error::QueryError;
+use crate::request::{PaginatedRequest, Pagination, SingleRequest};
+
+
+/// blocking wrapper around a [`BodhiClientBuilder`]
+///
+/// This type mirrors the API of the `BodhiServiceBuilder` type from versions of this crate before
+/// 2.0, and builds a [`BodhiService`] without requiring an async runtime.
+#[deprecated(since = "3.0.0", note = "use `BodhiClientBuilder` instead")]
+#[derive(Debug)]
+pub struct BodhiServiceBuilder<'a> {
+    builder: BodhiClientBuilder<'a>,
+}
+
+impl<'a> BodhiServiceBuilder<'a> {
+    #[allow(clippy::should_implement_trait)]
+    /// constructor for [`BodhiServiceBuilder`] for the default / production instance of bodhi
+    pub fn default() -> Self {
+        BodhiClientBuilder::default().into()
+    }
+
+    /// constructor for [`BodhiServiceBuilder`] for the staging instance of bodhi
+    pub fn staging() -> Self {
+        BodhiClientBuilder::staging().into()
+    }
+
+    /// constructor for [`BodhiServiceBuilder`] with custom settings (user-specified base URLs)
+    pub fn custom(url: String, openid_url: String) -> Self {
+        BodhiClientBuilder::custom(url, openid_url).into()
+    }
+
+    /// method for overriding the default request timeout (see [`BodhiClientBuilder::timeout`])
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+
+    /// method for overriding the default `User-Agent` header (see
+    /// [`BodhiClientBuilder::user_agent`])
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: &'a str) -> Self {
+        self.builder = self.builder.user_agent(user_agent);
+        self
+    }
+
+    /// method for overriding the default number of retries (see [`BodhiClientBuilder::retries`])
+    #[must_use]
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.builder = self.builder.retries(retries);
+        self
+    }
+
+    /// method for providing username and password for authenticated requests (see
+    /// [`BodhiClientBuilder::authentication`])
+    #[must_use]
+    pub fn authentication(mut self, username: &'a str, password: &'a str) -> Self {
+        self.builder = self.builder.authentication(username, password);
+        self
+    }
+
+    /// method for building a [`BodhiService`] from the specified settings
+    ///
+    /// This starts the runtime that is used by the [`BodhiService`], and then blocks until the
+    /// underlying [`BodhiClient`] has been built (including logging in, if authentication was
+    /// requested).
+    pub fn build(self) -> Result<BodhiService, BuilderError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|error| BuilderError::RuntimeError { error })?;
+
+        let client = runtime.block_on(self.builder.build())?;
+        Ok(BodhiService { client, runtime })
+    }
+}
+
+impl<'a> From<BodhiClientBuilder<'a>> for BodhiServiceBuilder<'a> {
+    fn from(builder: BodhiClientBuilder<'a>) -> Self {
+        BodhiServiceBuilder { builder }
+    }
+}
+
+
+/// blocking wrapper around a [`BodhiClient`]
+///
+/// All methods block the current thread until the request has been completed. They must not be
+/// called from within an async context.
+#[deprecated(since = "3.0.0", note = "use `BodhiClient` instead")]
+#[derive(Debug)]
+pub struct BodhiService {
+    client: BodhiClient,
+    runtime: Runtime,
+}
+
+impl BodhiService {
+    /// wrap an existing [`BodhiClient`] for use with blocking calls
+    pub fn from_client(client: BodhiClient) -> Result<Self, BuilderError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|error| BuilderError::RuntimeError { error })?;
+
+        Ok(BodhiService { client, runtime })
+    }
+
+    /// method for accessing the wrapped [`BodhiClient`]
+    pub fn client(&self) -> &BodhiClient {
+        &self.client
+    }
+
+    /// method for unwrapping the [`BodhiClient`]
+    pub fn into_client(self) -> BodhiClient {
+        self.client
+    }
+
+    /// blocking method for making single-page requests (see [`BodhiClient::request`])
+    pub fn request<P, T>(&self, request: &dyn SingleRequest<P, T>) -> Result<T, QueryError>
+    where
+        T: DeserializeOwned,
+    {
+        self.runtime.block_on(self.client.request(request))
+    }
+
+    /// blocking method for making paginated requests (see [`BodhiClient::paginated_request`])
+    pub fn paginated_request<P, V, T>(&self, request: &dyn PaginatedRequest<P, V>) -> Result<Vec<T>, QueryError>
+    where
+        P: Pagination,
+        V: IntoIterator<Item = T> + DeserializeOwned,
+        T: DeserializeOwned,
+    {
+        self.runtime.block_on(self.client.paginated_request(request))
+    }
+}
+
+
+/// trait for running paginated queries with a [`BodhiService`]
+///
+/// This trait is implemented for all paginated queries (for example, [`UpdateQuery`]). The results
+/// from all pages are collected into a single vector.
+///
+/// [`UpdateQuery`]: crate::UpdateQuery
+#[deprecated(since = "3.0.0", note = "use `BodhiClient::paginated_request` instead")]
+pub trait Query<P, V>
+where
+    V: IntoIterator,
+{
+    /// blocking method for running the query
+    fn query(&self, bodhi: &BodhiService) -> Result<Vec<V::Item>, QueryError>;
+}
+
+impl<R, P, V, T> Query<P, V> for R
+where
+    R: PaginatedRequest<P, V>,
+    P: Pagination,
+    V: IntoIterator<Item = T> + DeserializeOwned,
+    T: DeserializeOwned,
+{
+    fn query(&self, bodhi: &BodhiService) -> Result<Vec<T>, QueryError> {
+        bodhi.paginated_request(self)
+    }
+}
+
+
+/// trait for running single-page queries with a [`BodhiService`]
+///
+/// This trait is implemented for all single-page requests (for example, [`UpdateIDQuery`]).
+///
+/// [`UpdateIDQuery`]: crate::UpdateIDQuery
+#[deprecated(since = "3.0.0", note = "use `BodhiClient::request` instead")]
+pub trait SingleQuery<P, T> {
+    /// blocking method for running the query
+    fn query(&self, bodhi: &BodhiService) -> Result<T, QueryError>;
+}
+
+impl<R, P, T> SingleQuery<P, T> for R
+where
+    R: SingleRequest<P, T>,
+    T: DeserializeOwned,
+{
+    fn query(&self, bodhi: &BodhiService) -> Result<T, QueryError> {
+        bodhi.request(self)
+    }
+}
+
+
+/// trait for creating new things with a [`BodhiService`]
+///
+/// This trait is implemented for all single-page requests, but it is intended to be used with
+/// creators (for example, [`CommentCreator`]).
+///
+/// [`CommentCreator`]: crate::CommentCreator
+#[deprecated(since = "3.0.0", note = "use `BodhiClient::request` instead")]
+pub trait Create<P, T> {
+    /// blocking method for submitting the request
+    fn create(&self, bodhi: &BodhiService) -> Result<T, QueryError>;
+}
+
+impl<R, P, T> Create<P, T> for R
+where
+    R: SingleRequest<P, T>,
+    T: DeserializeOwned,
+{
+    fn create(&self, bodhi: &BodhiService) -> Result<T, QueryError> {
+        bodhi.request(self)
+    }
+}
+
+
+/// trait for editing existing things with a [`BodhiService`]
+///
+/// This trait is implemented for all single-page requests, but it is intended to be used with
+/// editors (for example, [`UpdateEditor`]).
+///
+/// [`UpdateEditor`]: crate::UpdateEditor
+#[deprecated(since = "3.0.0", note = "use `BodhiClient::request` instead")]
+pub trait Edit<P, T> {
+    /// blocking method for submitting the request
+    fn edit(&self, bodhi: &BodhiService) -> Result<T, QueryError>;
+}
+
+impl<R, P, T> Edit<P, T> for R
+where
+    R: SingleRequest<P, T>,
+    T: DeserializeOwned,
+{
+    fn edit(&self, bodhi: &BodhiService) -> Result<T, QueryError> {
+        bodhi.request(self)
+    }
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+    use crate::{CommentCreator, ReleaseQuery, UpdateIDQuery};
+
+    fn service() -> BodhiService {
+        let url = MockServer::with_fixtures().start().unwrap();
+        let openid_url = url.join("/openid/").unwrap();
+
+        BodhiServiceBuilder::custom(url.to_string(), openid_url.to_string())
+            .retries(0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn blocking_queries() {
+        let bodhi = service();
+
+        let releases = ReleaseQuery::new().query(&bodhi).unwrap();
+        let expected = bodhi
+            .runtime
+            .block_on(bodhi.client().paginated_request(&ReleaseQuery::new()))
+            .unwrap();
+        let names: Vec<String> = releases.iter().map(|r| r.name.to_string()).collect();
+        let expected: Vec<String> = expected.iter().map(|r| r.name.to_string()).collect();
+        assert_eq!(names, expected);
+
+        let update = UpdateIDQuery::new("FEDORA-2024-1a2b3c4d5e").query(&bodhi).unwrap();
+        assert_eq!(update.alias, "FEDORA-2024-1a2b3c4d5e");
+
+        assert!(matches!(
+            UpdateIDQuery::new("FEDORA-2024-0000000000").query(&bodhi),
+            Err(QueryError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn blocking_create_unauthenticated() {
+        let bodhi = service();
+
+        let creator = CommentCreator::new("FEDORA-2024-1a2b3c4d5e").text("comment");
+        assert!(creator.create(&bodhi).is_err());
+    }
+}
@@ -2,9 +2,9 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::data::{Comment, Karma, Update};
+use crate::data::{Bug, Comment, Karma, TestCase, Update};
 use crate::error::QueryError;
-use crate::request::{RequestMethod, SingleRequest};
+use crate::request::{KarmaCheck, RequestMethod, SingleRequest};
 
 #[derive(Debug, Serialize)]
 struct CommentData<'a> {
@@ -19,7 +19,10 @@ struct CommentData<'a> {
 
 
 /// data type for bug feedback
-#[derive(Debug, Serialize)]
+///
+/// Values of this type can also be constructed for bugs that are associated with an update with
+/// [`Bug::feedback`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
 pub struct BugFeedbackData {
     bug_id: u32,
     karma: Karma,
@@ -30,11 +33,24 @@ impl BugFeedbackData {
     pub fn new(bug_id: u32, karma: Karma) -> Self {
         BugFeedbackData { bug_id, karma }
     }
+
+    /// ID of the bug this feedback is for
+    pub fn bug_id(&self) -> u32 {
+        self.bug_id
+    }
+
+    /// karma value of this feedback
+    pub fn karma(&self) -> Karma {
+        self.karma
+    }
 }
 
 
 /// data type for test case feedback
-#[derive(Debug, Serialize)]
+///
+/// Values of this type can also be constructed for test cases that are associated with an update
+/// with [`TestCase::feedback`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
 pub struct TestCaseFeedbackData<'a> {
     testcase_name: &'a str,
     karma: Karma,
@@ -45,6 +61,16 @@ impl<'a> TestCaseFeedbackData<'a> {
     pub fn new(testcase_name: &'a str, karma: Karma) -> Self {
         TestCaseFeedbackData { testcase_name, karma }
     }
+
+    /// name of the test case this feedback is for
+    pub fn testcase_name(&self) -> &str {
+        self.testcase_name
+    }
+
+    /// karma value of this feedback
+    pub fn karma(&self) -> Karma {
+        self.karma
+    }
 }
 
 
@@ -63,6 +89,26 @@ pub struct NewComment {
 }
 
 
+/// behaviour of [`CommentCreator`] for users who already submitted karma for an update
+///
+/// Every user can only contribute one karma value to the total karma of an update. Karma that is
+/// submitted again by the same user is not counted by the server, which can result in surprising
+/// no-ops in automated tools. Checking for existing karma is opt-in, see
+/// [`CommentCreator::duplicate_karma`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum DuplicateKarma {
+    /// submit the comment without checking for existing karma (default)
+    #[default]
+    Submit,
+    /// submit the comment with neutral karma if the user already submitted karma for the update
+    Neutral,
+    /// do not submit the comment if the user already submitted karma for the update, and return
+    /// a [`QueryError::AlreadyCommented`] error instead
+    Reject,
+}
+
+
 /// data type wrapping all mandatory and optional parameters for creating a new comment
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/comments.html#service-1-POST>
@@ -73,6 +119,7 @@ pub struct CommentCreator<'a> {
     karma: Option<Karma>,
     bug_feedback: Option<&'a [BugFeedbackData]>,
     testcase_feedback: Option<&'a [TestCaseFeedbackData<'a>]>,
+    duplicate_karma: DuplicateKarma,
 }
 
 impl<'a> CommentCreator<'a> {
@@ -84,6 +131,7 @@ impl<'a> CommentCreator<'a> {
             karma: None,
             bug_feedback: None,
             testcase_feedback: None,
+            duplicate_karma: DuplicateKarma::Submit,
         }
     }
 
@@ -120,6 +168,18 @@ impl<'a> CommentCreator<'a> {
         self.testcase_feedback = Some(feedbacks);
         self
     }
+
+    /// method for opting into checking for existing karma before submitting the comment
+    ///
+    /// If enabled, and if the comment contains positive or negative karma, the comments of the
+    /// update are queried before the comment is submitted. If the authenticated user has already
+    /// submitted non-neutral karma for the update, the comment is either submitted with neutral
+    /// karma, or not submitted at all, depending on the [`DuplicateKarma`] value.
+    #[must_use]
+    pub fn duplicate_karma(mut self, duplicate_karma: DuplicateKarma) -> Self {
+        self.duplicate_karma = duplicate_karma;
+        self
+    }
 }
 
 impl<'a> SingleRequest<NewComment, NewComment> for CommentCreator<'a> {
@@ -132,6 +192,39 @@ impl<'a> SingleRequest<NewComment, NewComment> for CommentCreator<'a> {
     }
 
     fn body(&self, csrf_token: Option<String>) -> Result<Option<String>, QueryError> {
+        // do some data sanity verification
+        if let Some(items) = &self.bug_feedback {
+            for (pos, item) in items.iter().enumerate() {
+                if item.bug_id == 0 {
+                    return Err(QueryError::InvalidDataError {
+                        error: String::from("Bug IDs for feedback must be positive."),
+                    });
+                }
+                if items[..pos].iter().any(|other| other.bug_id == item.bug_id) {
+                    return Err(QueryError::InvalidDataError {
+                        error: format!("Duplicate feedback for bug #{}.", item.bug_id),
+                    });
+                }
+            }
+        }
+        if let Some(items) = &self.testcase_feedback {
+            for (pos, item) in items.iter().enumerate() {
+                if item.testcase_name.trim().is_empty() {
+                    return Err(QueryError::InvalidDataError {
+                        error: String::from("Test case names for feedback must not be empty."),
+                    });
+                }
+                if items[..pos]
+                    .iter()
+                    .any(|other| other.testcase_name == item.testcase_name)
+                {
+                    return Err(QueryError::InvalidDataError {
+                        error: format!("Duplicate feedback for test case '{}'.", item.testcase_name),
+                    });
+                }
+            }
+        }
+
         let mut feedback: HashMap<String, String> = HashMap::new();
 
         let karma_string = |k: Karma| match k {
@@ -180,6 +273,20 @@ impl<'a> SingleRequest<NewComment, NewComment> for CommentCreator<'a> {
     fn extract(&self, page: NewComment) -> NewComment {
         page
     }
+
+    fn karma_check(&self) -> Option<KarmaCheck<'_>> {
+        match (self.duplicate_karma, self.karma) {
+            (DuplicateKarma::Submit, _) | (_, None | Some(Karma::Neutral)) => None,
+            (DuplicateKarma::Neutral, Some(_)) => Some(KarmaCheck {
+                update: self.update,
+                reject: false,
+            }),
+            (DuplicateKarma::Reject, Some(_)) => Some(KarmaCheck {
+                update: self.update,
+                reject: true,
+            }),
+        }
+    }
 }
 
 
@@ -189,3 +296,19 @@ impl Update {
         CommentCreator::new(self.alias.as_str())
     }
 }
+
+
+impl Bug {
+    /// constructor for [`BugFeedbackData`] for this bug with the given karma value
+    pub fn feedback(&self, karma: Karma) -> BugFeedbackData {
+        BugFeedbackData::new(self.bug_id, karma)
+    }
+}
+
+
+impl TestCase {
+    /// constructor for [`TestCaseFeedbackData`] for this test case with the given karma value
+    pub fn feedback(&self, karma: Karma) -> TestCaseFeedbackData<'_> {
+        TestCaseFeedbackData::new(self.name.as_str(), karma)
+    }
+}
@@ -4,7 +4,7 @@
 //! comments, overrides, and updates on a bodhi instance.
 
 mod comments;
-pub use comments::{BugFeedbackData, CommentCreator, NewComment, TestCaseFeedbackData};
+pub use comments::{BugFeedbackData, CommentCreator, DuplicateKarma, NewComment, TestCaseFeedbackData};
 
 mod overrides;
 pub use overrides::{NewOverride, OverrideCreator};
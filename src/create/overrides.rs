@@ -2,9 +2,13 @@ use std::collections::HashMap;
 
 use serde::Deserialize;
 
-use crate::data::{BodhiDate, Build, Override, OverrideData};
+use crate::data::{BodhiDate, Build, Override, OverrideData, Update};
 use crate::error::QueryError;
-use crate::request::{RequestMethod, SingleRequest};
+use crate::request::{RequestMethod, RetryCheck, SingleRequest};
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::BodhiClient;
 
 /// data of this type is returned after successfully creating a new buildroot [`Override`]
 #[derive(Debug, Deserialize)]
@@ -42,6 +46,36 @@ impl<'a> OverrideCreator<'a> {
             expiration_date,
         }
     }
+
+    /// constructor for [`OverrideCreator`]s for all builds of an update
+    ///
+    /// This returns one request for every build of the update (in the same order), which is useful
+    /// for building dependent packages against the builds of an update that has not been pushed to
+    /// stable yet. The requests can be submitted together with [`BodhiClient::create_overrides`].
+    ///
+    /// If the update does not contain any builds, a [`QueryError::InvalidDataError`] is returned.
+    pub fn for_update(
+        update: &'a Update,
+        notes: &'a str,
+        expiration_date: &'a BodhiDate,
+    ) -> Result<Vec<OverrideCreator<'a>>, QueryError> {
+        if update.builds.is_empty() {
+            return Err(QueryError::InvalidDataError {
+                error: format!("Update {} does not contain any builds.", update.alias),
+            });
+        }
+
+        Ok(update
+            .builds
+            .iter()
+            .map(|build| build.buildroot_override(notes, expiration_date))
+            .collect())
+    }
+
+    // NVR of the build for which the buildroot override is created
+    pub(crate) fn nvr(&self) -> &'a str {
+        self.nvr
+    }
 }
 
 impl<'a> SingleRequest<NewOverride, NewOverride> for OverrideCreator<'a> {
@@ -76,6 +110,14 @@ impl<'a> SingleRequest<NewOverride, NewOverride> for OverrideCreator<'a> {
     fn extract(&self, page: NewOverride) -> NewOverride {
         page
     }
+
+    fn retry_check(&self) -> Option<RetryCheck<'_>> {
+        Some(RetryCheck::Override {
+            nvr: self.nvr,
+            notes: self.notes,
+            expiration_date: self.expiration_date,
+        })
+    }
 }
 
 
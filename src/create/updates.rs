@@ -4,7 +4,7 @@ use serde::Deserialize;
 
 use crate::data::{Update, UpdateData, UpdateRequest, UpdateSeverity, UpdateSuggestion, UpdateType};
 use crate::error::QueryError;
-use crate::request::{RequestMethod, SingleRequest};
+use crate::request::{RequestMethod, RetryCheck, SingleRequest};
 
 // imports for intra-doc links
 #[cfg(doc)]
@@ -348,4 +348,12 @@ impl<'a> SingleRequest<NewUpdate, NewUpdate> for UpdateCreator<'a> {
     fn extract(&self, page: NewUpdate) -> NewUpdate {
         page
     }
+
+    fn retry_check(&self) -> Option<RetryCheck<'_>> {
+        // updates that are created from side tags cannot be reliably matched
+        match &self.source {
+            UpdateSource::Builds { builds } => Some(RetryCheck::Update { builds }),
+            UpdateSource::Tag { .. } => None,
+        }
+    }
 }
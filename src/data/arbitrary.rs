@@ -0,0 +1,157 @@
+// implementations of the quickcheck Arbitrary trait for enumerated types (for property tests)
+
+use quickcheck::{Arbitrary, Gen};
+
+use super::enums::*;
+
+impl Arbitrary for CommentKind {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[CommentKind::User, CommentKind::BodhiBot, CommentKind::CISystem])
+            .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
+
+impl Arbitrary for ComposeRequest {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[ComposeRequest::Stable, ComposeRequest::Testing])
+            .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
+
+impl Arbitrary for ComposeState {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[
+            ComposeState::Cleaning,
+            ComposeState::Failed,
+            ComposeState::Initializing,
+            ComposeState::Notifying,
+            ComposeState::Pending,
+            ComposeState::Punging,
+            ComposeState::Requested,
+            ComposeState::SigningRepo,
+            ComposeState::Success,
+            ComposeState::SyncingRepo,
+            ComposeState::UpdateInfo,
+        ])
+        .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
+
+impl Arbitrary for ContentType {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[
+            ContentType::Container,
+            ContentType::Flatpak,
+            ContentType::Module,
+            ContentType::RPM,
+        ])
+        .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
+
+impl Arbitrary for Karma {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[Karma::Positive, Karma::Neutral, Karma::Negative])
+            .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
+
+impl Arbitrary for PackageManager {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[PackageManager::DNF, PackageManager::Unspecified, PackageManager::YUM])
+            .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
+
+impl Arbitrary for ReleaseState {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[
+            ReleaseState::Archived,
+            ReleaseState::Current,
+            ReleaseState::Disabled,
+            ReleaseState::Frozen,
+            ReleaseState::Pending,
+        ])
+        .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
+
+impl Arbitrary for TestGatingStatus {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[
+            TestGatingStatus::Failed,
+            TestGatingStatus::GreenwaveFailed,
+            TestGatingStatus::Ignored,
+            TestGatingStatus::Passed,
+            TestGatingStatus::Queued,
+            TestGatingStatus::Running,
+            TestGatingStatus::Waiting,
+        ])
+        .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
+
+impl Arbitrary for UpdateRequest {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[
+            UpdateRequest::Obsolete,
+            UpdateRequest::Revoke,
+            UpdateRequest::Stable,
+            UpdateRequest::Testing,
+            UpdateRequest::Unpush,
+        ])
+        .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
+
+impl Arbitrary for UpdateSeverity {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[
+            UpdateSeverity::High,
+            UpdateSeverity::Low,
+            UpdateSeverity::Medium,
+            UpdateSeverity::Unspecified,
+            UpdateSeverity::Urgent,
+        ])
+        .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
+
+impl Arbitrary for UpdateStatus {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[
+            UpdateStatus::Obsolete,
+            UpdateStatus::Pending,
+            UpdateStatus::SideTagActive,
+            UpdateStatus::SideTagExpired,
+            UpdateStatus::Stable,
+            UpdateStatus::Testing,
+            UpdateStatus::Unpushed,
+        ])
+        .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
+
+impl Arbitrary for UpdateSuggestion {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[
+            UpdateSuggestion::Logout,
+            UpdateSuggestion::Reboot,
+            UpdateSuggestion::Unspecified,
+        ])
+        .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
+
+impl Arbitrary for UpdateType {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&[
+            UpdateType::BugFix,
+            UpdateType::Enhancement,
+            UpdateType::NewPackage,
+            UpdateType::Security,
+            UpdateType::Unspecified,
+        ])
+        .expect("Failed to choose from non-empty list, this should not happen.")
+    }
+}
@@ -0,0 +1,121 @@
+use super::User;
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::query::CommentQuery;
+
+// user name of the account that bodhi uses for posting automated comments
+pub(crate) const BODHI_BOT_USER: &str = "bodhi";
+
+// user names of accounts that are used by CI systems for posting automated comments
+pub(crate) const CI_SYSTEM_USERS: &[&str] = &["fedora-ci", "openqa", "osci", "packit", "taskotron", "zuul"];
+
+/// user names of well-known accounts of bots and CI systems on the Fedora bodhi instance
+///
+/// This list includes the account that bodhi uses for posting automated comments, accounts of CI
+/// systems that post test results, and other service accounts (like `zodbot`). It can be passed to
+/// [`CommentQuery::ignore_users`] directly, or with [`CommentQuery::ignore_known_bots`].
+pub const KNOWN_BOTS: &[&str] = &[
+    "bodhi",
+    "fedora-ci",
+    "openqa",
+    "osci",
+    "packit",
+    "taskotron",
+    "zodbot",
+    "zuul",
+];
+
+
+/// list of user names of bot accounts that can be modified at runtime
+///
+/// The default list contains all [`KNOWN_BOTS`]. Names can be added or removed, for example, to
+/// filter out additional service accounts that are specific to a bodhi instance:
+///
+/// ```
+/// use bodhi::{BotList, CommentQuery};
+///
+/// let bots = BotList::default().with("my-service").without("packit");
+///
+/// let names = bots.names();
+/// let query = CommentQuery::new().ignore_users(&names);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BotList {
+    names: Vec<String>,
+}
+
+impl Default for BotList {
+    fn default() -> Self {
+        BotList {
+            names: KNOWN_BOTS.iter().map(|name| (*name).to_owned()).collect(),
+        }
+    }
+}
+
+impl BotList {
+    /// constructor for an empty [`BotList`]
+    pub fn empty() -> Self {
+        BotList { names: Vec::new() }
+    }
+
+    /// add a user name to the list
+    #[must_use]
+    pub fn with(mut self, name: &str) -> Self {
+        if !self.contains(name) {
+            self.names.push(name.to_owned());
+        }
+        self
+    }
+
+    /// remove a user name from the list
+    #[must_use]
+    pub fn without(mut self, name: &str) -> Self {
+        self.names.retain(|n| n != name);
+        self
+    }
+
+    /// flag to indicate whether the given user name is in the list
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.iter().any(|n| n == name)
+    }
+
+    /// flag to indicate whether the given user is a bot account according to this list
+    ///
+    /// Users are matched based on their user name and the name of their OpenID identity.
+    pub fn is_bot(&self, user: &User) -> bool {
+        user.matches_any(&self.names)
+    }
+
+    /// user names in this list
+    pub fn names(&self) -> Vec<&str> {
+        self.names.iter().map(String::as_str).collect()
+    }
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_bots() {
+        // all accounts that are classified as bots when posting comments are known bots
+        assert!(KNOWN_BOTS.contains(&BODHI_BOT_USER));
+        for name in CI_SYSTEM_USERS {
+            assert!(KNOWN_BOTS.contains(name));
+        }
+    }
+
+    #[test]
+    fn runtime_list() {
+        let bots = BotList::default().with("my-service").with("zodbot").without("packit");
+        assert!(bots.contains("my-service"));
+        assert!(bots.contains("bodhi"));
+        assert!(!bots.contains("packit"));
+        assert_eq!(bots.names().len(), KNOWN_BOTS.len());
+
+        assert!(BotList::empty().names().is_empty());
+    }
+}
@@ -20,12 +20,25 @@ pub struct BodhiDate {
     date: DateTime<Utc>,
 }
 
+impl BodhiDate {
+    /// constructor for a [`BodhiDate`] with the current date & time
+    pub fn now() -> Self {
+        BodhiDate { date: Utc::now() }
+    }
+}
+
 impl From<DateTime<Utc>> for BodhiDate {
     fn from(date: DateTime<Utc>) -> Self {
         BodhiDate { date }
     }
 }
 
+impl From<BodhiDate> for DateTime<Utc> {
+    fn from(date: BodhiDate) -> Self {
+        date.date
+    }
+}
+
 impl TryFrom<&str> for BodhiDate {
     type Error = chrono::ParseError;
 
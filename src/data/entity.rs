@@ -0,0 +1,53 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::types::*;
+
+/// trait for structured data types that can be converted to and from their JSON representation
+///
+/// The JSON representation matches the format of bodhi server responses, so the
+/// [`Entity::to_json`] and [`Entity::from_json`] methods can be used for caching values that were
+/// returned by queries, for example.
+///
+/// Converting values to JSON and back is lossless: all fields are preserved (including deprecated
+/// fields that are only kept for backwards compatibility, and unknown fields that were collected
+/// in the `extra` maps), and deserializing the JSON representation of a value results in a value
+/// with the same JSON representation.
+///
+/// ```
+/// use bodhi::{Entity, UpdateSummary};
+///
+/// let json = r#"{"alias":"FEDORA-2024-1234567890","title":"rust-bodhi-2.2.0-1.fc40"}"#;
+///
+/// let summary = UpdateSummary::from_json(json).unwrap();
+/// assert_eq!(summary.to_json().unwrap(), json);
+/// ```
+pub trait Entity: Serialize + DeserializeOwned {
+    /// serialize this value into its JSON representation
+    fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// deserialize a value from its JSON representation
+    fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+impl Entity for Bug {}
+impl Entity for BugFeedback {}
+impl Entity for Build {}
+impl Entity for Comment {}
+impl Entity for Compose {}
+impl Entity for GatingDecision {}
+impl Entity for GatingRequirement {}
+impl Entity for Group {}
+impl Entity for Override {}
+impl Entity for Package {}
+impl Entity for Release {}
+impl Entity for TestCase {}
+impl Entity for TestCaseFeedback {}
+impl Entity for TestResults {}
+impl Entity for Update {}
+impl Entity for UpdateSummary {}
+impl Entity for User {}
@@ -9,11 +9,67 @@ use super::InvalidValueError;
 
 // imports for intra-doc links
 #[cfg(doc)]
-use super::FedoraRelease;
+use super::{Comment, FedoraRelease, Release, Update};
+#[cfg(doc)]
+use crate::client::BodhiClient;
+
+/// kinds of authors of update comments
+///
+/// Values of this type are returned by [`Comment::kind`], and can be used to distinguish comments
+/// that were posted by real users from automated comments (for example, when counting karma, or
+/// for hiding comments from bots in user interfaces).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum CommentKind {
+    /// comment posted by a regular user
+    #[serde(rename = "user")]
+    User,
+    /// automated comment posted by bodhi itself
+    #[serde(rename = "bodhi-bot")]
+    BodhiBot,
+    /// automated comment posted by a CI system
+    #[serde(rename = "ci-system")]
+    CISystem,
+}
+
+impl Display for CommentKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let value = match self {
+            CommentKind::User => "user",
+            CommentKind::BodhiBot => "bodhi-bot",
+            CommentKind::CISystem => "ci-system",
+        };
+
+        write!(f, "{value}")
+    }
+}
+
+impl TryFrom<&str> for CommentKind {
+    type Error = InvalidValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "user" => Ok(CommentKind::User),
+            "bodhi-bot" => Ok(CommentKind::BodhiBot),
+            "ci-system" => Ok(CommentKind::CISystem),
+            _ => Err(InvalidValueError::new("CommentKind", value.to_owned())),
+        }
+    }
+}
+
+impl FromStr for CommentKind {
+    type Err = InvalidValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TryFrom::try_from(s)
+    }
+}
+
 
 /// valid `request` values for composes
 #[allow(missing_docs)]
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum ComposeRequest {
     #[serde(rename = "stable")]
     Stable,
@@ -56,6 +112,7 @@ impl FromStr for ComposeRequest {
 /// valid `state` values for composes
 #[allow(missing_docs)]
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum ComposeState {
     #[serde(rename = "cleaning")]
     Cleaning,
@@ -133,6 +190,7 @@ impl FromStr for ComposeState {
 /// valid / known content types
 #[allow(missing_docs)]
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum ContentType {
     // tag for container image updates
     #[serde(rename = "container")]
@@ -211,6 +269,237 @@ impl FromStr for ContentType {
     }
 }
 
+/// names of critical path groups that updates can be associated with
+///
+/// The list of critical path groups is defined by the Fedora infrastructure and can change over
+/// time, so group names that are not (yet) known to this crate are preserved as
+/// [`CritpathGroup::Other`] instead of being rejected.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum CritpathGroup {
+    /// `critical-path-apps`
+    Apps,
+    /// `critical-path-base`
+    Base,
+    /// `critical-path-build`
+    Build,
+    /// `critical-path-compose`
+    Compose,
+    /// `critical-path-deployment`
+    Deployment,
+    /// `critical-path-gnome`
+    Gnome,
+    /// `critical-path-kde`
+    KDE,
+    /// `critical-path-server`
+    Server,
+    /// `critical-path-standard`
+    Standard,
+    /// any other critical path group
+    Other(String),
+}
+
+impl CritpathGroup {
+    /// method for returning the name of this group as it is used by the bodhi server
+    pub fn as_str(&self) -> &str {
+        match self {
+            CritpathGroup::Apps => "critical-path-apps",
+            CritpathGroup::Base => "critical-path-base",
+            CritpathGroup::Build => "critical-path-build",
+            CritpathGroup::Compose => "critical-path-compose",
+            CritpathGroup::Deployment => "critical-path-deployment",
+            CritpathGroup::Gnome => "critical-path-gnome",
+            CritpathGroup::KDE => "critical-path-kde",
+            CritpathGroup::Server => "critical-path-server",
+            CritpathGroup::Standard => "critical-path-standard",
+            CritpathGroup::Other(name) => name,
+        }
+    }
+}
+
+impl Display for CritpathGroup {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<&str> for CritpathGroup {
+    fn from(value: &str) -> Self {
+        match value {
+            "critical-path-apps" => CritpathGroup::Apps,
+            "critical-path-base" => CritpathGroup::Base,
+            "critical-path-build" => CritpathGroup::Build,
+            "critical-path-compose" => CritpathGroup::Compose,
+            "critical-path-deployment" => CritpathGroup::Deployment,
+            "critical-path-gnome" => CritpathGroup::Gnome,
+            "critical-path-kde" => CritpathGroup::KDE,
+            "critical-path-server" => CritpathGroup::Server,
+            "critical-path-standard" => CritpathGroup::Standard,
+            _ => CritpathGroup::Other(value.to_owned()),
+        }
+    }
+}
+
+impl Serialize for CritpathGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CritpathGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        Ok(CritpathGroup::from(string.as_str()))
+    }
+}
+
+// critical path groups are returned by the server as a single string of space-separated names
+pub(crate) mod critpath_groups_format {
+    use super::CritpathGroup;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(groups: &Option<Vec<CritpathGroup>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match groups {
+            Some(groups) => {
+                let names: Vec<&str> = groups.iter().map(CritpathGroup::as_str).collect();
+                serializer.serialize_str(&names.join(" "))
+            },
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<CritpathGroup>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string: Option<String> = Option::deserialize(deserializer)?;
+        Ok(string.map(|string| string.split_whitespace().map(CritpathGroup::from).collect()))
+    }
+}
+
+
+/// prefixes of update aliases that are used for different types of releases
+///
+/// The prefix of update aliases is configured per release on the bodhi server (see
+/// [`Release::id_prefix`]). Prefixes that are not (yet) known to this crate are preserved as
+/// [`IdPrefix::Other`] instead of being rejected.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum IdPrefix {
+    /// `FEDORA`: Fedora releases with RPM packages
+    Fedora,
+    /// `FEDORA-CONTAINER`: Fedora releases with container images
+    FedoraContainer,
+    /// `FEDORA-FLATPAK`: Fedora releases with flatpaks
+    FedoraFlatpak,
+    /// `FEDORA-MODULAR`: Fedora releases with modules
+    FedoraModular,
+    /// `FEDORA-EPEL`: EPEL releases with RPM packages
+    Epel,
+    /// `FEDORA-EPEL-MODULAR`: EPEL releases with modules
+    EpelModular,
+    /// `FEDORA-EPEL-NEXT`: EPEL Next releases with RPM packages
+    EpelNext,
+    /// any other prefix
+    Other(String),
+}
+
+impl IdPrefix {
+    /// method for returning this prefix as it is used by the bodhi server
+    pub fn as_str(&self) -> &str {
+        match self {
+            IdPrefix::Fedora => "FEDORA",
+            IdPrefix::FedoraContainer => "FEDORA-CONTAINER",
+            IdPrefix::FedoraFlatpak => "FEDORA-FLATPAK",
+            IdPrefix::FedoraModular => "FEDORA-MODULAR",
+            IdPrefix::Epel => "FEDORA-EPEL",
+            IdPrefix::EpelModular => "FEDORA-EPEL-MODULAR",
+            IdPrefix::EpelNext => "FEDORA-EPEL-NEXT",
+            IdPrefix::Other(prefix) => prefix,
+        }
+    }
+
+    /// method for returning the [`ContentType`] of updates with this prefix
+    ///
+    /// This returns `None` for unknown prefixes.
+    pub fn content_type(&self) -> Option<ContentType> {
+        match self {
+            IdPrefix::Fedora | IdPrefix::Epel | IdPrefix::EpelNext => Some(ContentType::RPM),
+            IdPrefix::FedoraContainer => Some(ContentType::Container),
+            IdPrefix::FedoraFlatpak => Some(ContentType::Flatpak),
+            IdPrefix::FedoraModular | IdPrefix::EpelModular => Some(ContentType::Module),
+            IdPrefix::Other(_) => None,
+        }
+    }
+
+    /// method for checking whether this prefix is used for EPEL releases
+    ///
+    /// Unknown prefixes are considered to belong to EPEL releases if they start with
+    /// `FEDORA-EPEL`.
+    pub fn is_epel(&self) -> bool {
+        match self {
+            IdPrefix::Epel | IdPrefix::EpelModular | IdPrefix::EpelNext => true,
+            IdPrefix::Fedora | IdPrefix::FedoraContainer | IdPrefix::FedoraFlatpak | IdPrefix::FedoraModular => false,
+            IdPrefix::Other(prefix) => prefix.starts_with("FEDORA-EPEL"),
+        }
+    }
+}
+
+impl Display for IdPrefix {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<&str> for IdPrefix {
+    fn from(value: &str) -> Self {
+        match value {
+            "FEDORA" => IdPrefix::Fedora,
+            "FEDORA-CONTAINER" => IdPrefix::FedoraContainer,
+            "FEDORA-FLATPAK" => IdPrefix::FedoraFlatpak,
+            "FEDORA-MODULAR" => IdPrefix::FedoraModular,
+            "FEDORA-EPEL" => IdPrefix::Epel,
+            "FEDORA-EPEL-MODULAR" => IdPrefix::EpelModular,
+            "FEDORA-EPEL-NEXT" => IdPrefix::EpelNext,
+            _ => IdPrefix::Other(value.to_owned()),
+        }
+    }
+}
+
+impl PartialEq<str> for IdPrefix {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl Serialize for IdPrefix {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for IdPrefix {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        Ok(IdPrefix::from(string.as_str()))
+    }
+}
+
+
 /// valid "karma" values that are associated for update comments and feedback
 ///
 /// Only three values are valid: **-1** for positive feedback, **±0** for neutral (or unspecified)
@@ -270,10 +559,12 @@ impl FromStr for Karma {
 ///
 /// Values of this type are used to print installation instructions for updates on the server.
 #[allow(missing_docs)]
-#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum PackageManager {
     #[serde(rename = "dnf")]
     DNF,
+    #[default]
     #[serde(rename = "unspecified")]
     Unspecified,
     #[serde(rename = "yum")]
@@ -298,6 +589,7 @@ impl TryFrom<&str> for PackageManager {
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value.to_lowercase().as_str() {
             "dnf" => Ok(PackageManager::DNF),
+            "unspecified" => Ok(PackageManager::Unspecified),
             "yum" => Ok(PackageManager::YUM),
             _ => Err(InvalidValueError::new("PackageManager", value.to_owned())),
         }
@@ -315,6 +607,7 @@ impl FromStr for PackageManager {
 
 /// valid `state` values for releases
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum ReleaseState {
     /// release has been archived after it has reached its EOL
     #[serde(rename = "archived")]
@@ -371,9 +664,86 @@ impl FromStr for ReleaseState {
 }
 
 
+/// kinds of koji tags that are associated with a release
+///
+/// The names of the tags for a given release are available with [`Release::tag`]. The bodhi server
+/// does not support filtering builds by the koji tag they are currently tagged into, but the tags
+/// correspond to update states, which can be used for querying builds with
+/// [`BodhiClient::builds_in_tag`]:
+///
+/// | tag                              | builds                                                  |
+/// |----------------------------------|---------------------------------------------------------|
+/// | [`ReleaseTag::Candidate`]        | all builds for the release                              |
+/// | [`ReleaseTag::Override`]         | builds in active buildroot overrides for the release    |
+/// | [`ReleaseTag::PendingSigning`]   | builds for the release which have not been signed yet   |
+/// | [`ReleaseTag::PendingTesting`]   | builds in updates with a request for testing            |
+/// | [`ReleaseTag::PendingStable`]    | builds in updates with a request for stable             |
+/// | [`ReleaseTag::Testing`]          | builds in updates with the status testing               |
+/// | [`ReleaseTag::Stable`]           | builds in updates with the status stable                |
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ReleaseTag {
+    /// tag for update candidates ([`Release::candidate_tag`])
+    Candidate,
+    /// tag for builds in buildroot overrides ([`Release::override_tag`])
+    Override,
+    /// tag for builds that are pending to be signed ([`Release::pending_signing_tag`])
+    PendingSigning,
+    /// tag for builds that are pending to be pushed to stable ([`Release::pending_stable_tag`])
+    PendingStable,
+    /// tag for builds that are pending to be pushed to testing ([`Release::pending_testing_tag`])
+    PendingTesting,
+    /// tag for builds that have been pushed to stable ([`Release::stable_tag`])
+    Stable,
+    /// tag for builds that have been pushed to testing ([`Release::testing_tag`])
+    Testing,
+}
+
+impl Display for ReleaseTag {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let value = match self {
+            ReleaseTag::Candidate => "candidate",
+            ReleaseTag::Override => "override",
+            ReleaseTag::PendingSigning => "pending_signing",
+            ReleaseTag::PendingStable => "pending_stable",
+            ReleaseTag::PendingTesting => "pending_testing",
+            ReleaseTag::Stable => "stable",
+            ReleaseTag::Testing => "testing",
+        };
+
+        write!(f, "{value}")
+    }
+}
+
+impl TryFrom<&str> for ReleaseTag {
+    type Error = InvalidValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "candidate" => Ok(ReleaseTag::Candidate),
+            "override" => Ok(ReleaseTag::Override),
+            "pending_signing" => Ok(ReleaseTag::PendingSigning),
+            "pending_stable" => Ok(ReleaseTag::PendingStable),
+            "pending_testing" => Ok(ReleaseTag::PendingTesting),
+            "stable" => Ok(ReleaseTag::Stable),
+            "testing" => Ok(ReleaseTag::Testing),
+            _ => Err(InvalidValueError::new("ReleaseTag", value.to_owned())),
+        }
+    }
+}
+
+impl FromStr for ReleaseTag {
+    type Err = InvalidValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TryFrom::try_from(s)
+    }
+}
+
+
 /// valid `state` values for an update's gating tests
 #[allow(missing_docs)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum TestGatingStatus {
     #[serde(rename = "failed")]
     Failed,
@@ -437,6 +807,7 @@ impl FromStr for TestGatingStatus {
 // - internal, numerical ID (only for compatibility with old releases)
 // - public, human-readable "alias" (`FEDORA-2019-1A2BB23E`)
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub(crate) enum UpdateID {
     // identified via numerical update ID
@@ -459,6 +830,7 @@ impl Display for UpdateID {
 
 /// valid `request` values for updates
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum UpdateRequest {
     /// request for an update to be marked as "obsolete" (usually when another update supersedes it)
     #[serde(rename = "obsolete")]
@@ -520,6 +892,7 @@ impl FromStr for UpdateRequest {
 /// This field is required to not be `Unspecified` for updates with type [`UpdateType::Security`].
 #[allow(missing_docs)]
 #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum UpdateSeverity {
     #[serde(rename = "high")]
     High,
@@ -574,6 +947,7 @@ impl FromStr for UpdateSeverity {
 
 /// valid `status` values for updates
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum UpdateStatus {
     /// status of updates that have been obsoleted by another update
     #[serde(rename = "obsolete")]
@@ -639,9 +1013,28 @@ impl FromStr for UpdateStatus {
     }
 }
 
+impl UpdateStatus {
+    /// method for returning the requests that are valid for updates with this [`UpdateStatus`]
+    ///
+    /// This does not include [`UpdateRequest::Revoke`], which is valid for updates with a pending
+    /// request, independent of their status. Use [`Update::allowed_requests`] to take both into
+    /// account.
+    pub const fn allowed_requests(&self) -> &'static [UpdateRequest] {
+        use UpdateStatus::*;
+
+        match self {
+            Pending => &[UpdateRequest::Testing, UpdateRequest::Obsolete],
+            Testing => &[UpdateRequest::Stable, UpdateRequest::Unpush, UpdateRequest::Obsolete],
+            Unpushed => &[UpdateRequest::Testing],
+            Obsolete | SideTagActive | SideTagExpired | Stable => &[],
+        }
+    }
+}
+
 
 /// valid `suggestion` values for updates
 #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum UpdateSuggestion {
     /// recommendation for logging out after this update has been installed
     #[serde(rename = "logout")]
@@ -691,6 +1084,7 @@ impl FromStr for UpdateSuggestion {
 
 /// valid `type` values for updates
 #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum UpdateType {
     /// the update contains fixes for known bugs
     #[serde(rename = "bugfix")]
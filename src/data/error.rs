@@ -15,3 +15,24 @@ impl InvalidValueError {
         InvalidValueError { name, value }
     }
 }
+
+
+/// error type that represents a failure to migrate persisted data to the current schema version
+#[derive(Debug, Error)]
+pub enum MigrationError {
+    /// data was written with a newer schema version than the one supported by this crate
+    #[error("Unsupported schema version {version} (newest supported version: {supported})")]
+    UnsupportedVersion {
+        /// schema version of the data
+        version: u32,
+        /// newest schema version supported by this version of the crate
+        supported: u32,
+    },
+    /// migrated data could not be deserialized
+    #[error("Failed to deserialize migrated data: {error}")]
+    DeserializationError {
+        /// error that occurred during deserialization
+        #[from]
+        error: serde_json::Error,
+    },
+}
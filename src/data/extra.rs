@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+
+/// catch-all map for fields of structured data types that are not (yet) known to this crate
+///
+/// Almost all server responses only contain known fields, so this type does not allocate anything
+/// unless it contains at least one field, which significantly reduces the memory usage of large
+/// query results. It is only one pointer wide, compared to the size of an empty [`HashMap`].
+///
+/// The fields of structured data types can be accessed with their `extra` and `extra_mut`
+/// methods (for example, [`Update::extra`](crate::Update::extra)).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExtraFields {
+    // the map is boxed to keep the size of empty values down to a single (null) pointer
+    #[allow(clippy::box_collection)]
+    fields: Option<Box<HashMap<String, serde_json::Value>>>,
+}
+
+impl ExtraFields {
+    /// constructor for an empty [`ExtraFields`] map (this does not allocate)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// number of unknown fields
+    pub fn len(&self) -> usize {
+        self.fields.as_ref().map_or(0, |fields| fields.len())
+    }
+
+    /// check whether there are no unknown fields
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// value of the unknown field with the given name (if present)
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.fields.as_ref().and_then(|fields| fields.get(key))
+    }
+
+    /// check whether there is an unknown field with the given name
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// iterator over the names and values of all unknown fields (in arbitrary order)
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &serde_json::Value)> {
+        self.fields.iter().flat_map(|fields| fields.iter())
+    }
+
+    /// insert a field, returning the previous value of the field with the same name (if any)
+    pub fn insert(&mut self, key: String, value: serde_json::Value) -> Option<serde_json::Value> {
+        self.fields.get_or_insert_with(Default::default).insert(key, value)
+    }
+
+    /// remove a field, returning its value (if it was present)
+    pub fn remove(&mut self, key: &str) -> Option<serde_json::Value> {
+        let fields = self.fields.as_mut()?;
+        let value = fields.remove(key);
+
+        // release the allocation once the last field is removed
+        if fields.is_empty() {
+            self.fields = None;
+        }
+
+        value
+    }
+
+    /// deserialize a typed view of (a subset of) the unknown fields
+    ///
+    /// Fields that are not known to the target type are ignored (unless it uses the
+    /// `#[serde(deny_unknown_fields)]` attribute).
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        let map: serde_json::Map<String, serde_json::Value> =
+            self.iter().map(|(key, value)| (key.clone(), value.clone())).collect();
+        serde_json::from_value(serde_json::Value::Object(map))
+    }
+
+    /// convert the unknown fields into a plain [`HashMap`]
+    pub fn into_map(self) -> HashMap<String, serde_json::Value> {
+        self.fields.map(|fields| *fields).unwrap_or_default()
+    }
+}
+
+impl From<HashMap<String, serde_json::Value>> for ExtraFields {
+    fn from(fields: HashMap<String, serde_json::Value>) -> Self {
+        if fields.is_empty() {
+            ExtraFields { fields: None }
+        } else {
+            ExtraFields {
+                fields: Some(Box::new(fields)),
+            }
+        }
+    }
+}
+
+
+/// trait for typed extensions of structured data types with fields that are not known to this crate
+///
+/// Patched bodhi servers (or newer versions of bodhi) can include additional fields in their
+/// responses, which end up in the [`ExtraFields`] of the deserialized values. Implementing this
+/// trait for a type that deserializes (a subset of) these fields registers it as an extension of
+/// the given entity type, which makes it possible to access the fields with the `extra_typed`
+/// methods (for example, [`Update::extra_typed`](crate::Update::extra_typed)):
+///
+/// ```
+/// use bodhi::{ExtraExtension, Update};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Tracking {
+///     ticket: Option<String>,
+/// }
+///
+/// impl ExtraExtension for Tracking {
+///     type Entity = Update;
+/// }
+///
+/// fn ticket(update: &Update) -> Option<String> {
+///     update.extra_typed::<Tracking>().ok()?.ticket
+/// }
+/// ```
+pub trait ExtraExtension: DeserializeOwned {
+    /// structured data type this extension applies to
+    type Entity;
+}
+
+
+// (de)serialization helpers for the catch-all maps of unknown fields in the structured data types
+//
+// By default, fields in JSON server responses which are not explicitly handled by the data type
+// definitions are collected in the `extra` maps. With the `strict-schema` feature, deserializing
+// data that contains such unknown fields fails instead, which makes it possible to detect server
+// schema changes early (for example, in CI jobs), while normal builds stay lenient.
+pub(crate) mod extra_fields {
+    use std::collections::HashMap;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::ExtraFields;
+
+    pub fn serialize<S>(extra: &ExtraFields, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match &extra.fields {
+            Some(fields) => fields.serialize(serializer),
+            None => HashMap::<String, serde_json::Value>::new().serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<ExtraFields, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let extra: HashMap<String, serde_json::Value> = HashMap::deserialize(deserializer)?;
+
+        #[cfg(feature = "strict-schema")]
+        if !extra.is_empty() {
+            let mut keys: Vec<&str> = extra.keys().map(|key| key.as_str()).collect();
+            keys.sort_unstable();
+
+            return Err(serde::de::Error::custom(format!(
+                "unknown field(s) with strict schema validation: {}",
+                keys.join(", ")
+            )));
+        }
+
+        Ok(extra.into())
+    }
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extra_fields_size() {
+        assert_eq!(std::mem::size_of::<ExtraFields>(), std::mem::size_of::<usize>());
+        assert!(std::mem::size_of::<ExtraFields>() < std::mem::size_of::<HashMap<String, serde_json::Value>>());
+    }
+
+    #[test]
+    fn extra_fields_allocation() {
+        let mut extra = ExtraFields::new();
+        assert!(extra.fields.is_none());
+
+        assert_eq!(extra.insert(String::from("foo"), serde_json::json!(1)), None);
+        assert_eq!(extra.get("foo"), Some(&serde_json::json!(1)));
+        assert_eq!(extra.len(), 1);
+
+        assert_eq!(extra.remove("foo"), Some(serde_json::json!(1)));
+        assert!(extra.fields.is_none());
+
+        assert!(ExtraFields::from(HashMap::new()).fields.is_none());
+    }
+}
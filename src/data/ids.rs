@@ -0,0 +1,61 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+// definitions of newtypes for numerical IDs (with conversions from and to `u32`)
+macro_rules! numerical_ids {
+    ($($(#[$attr:meta])* $name:ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+            #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+            #[serde(transparent)]
+            pub struct $name(u32);
+
+            impl $name {
+                /// numerical value of this ID
+                pub fn get(&self) -> u32 {
+                    self.0
+                }
+            }
+
+            impl Display for $name {
+                fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+
+            impl From<u32> for $name {
+                fn from(id: u32) -> Self {
+                    $name(id)
+                }
+            }
+
+            impl From<$name> for u32 {
+                fn from(id: $name) -> Self {
+                    id.0
+                }
+            }
+
+            impl PartialEq<u32> for $name {
+                fn eq(&self, other: &u32) -> bool {
+                    self.0 == *other
+                }
+            }
+        )*
+    };
+}
+
+numerical_ids! {
+    /// numerical ID of a build that is associated with an update
+    BuildId;
+
+    /// numerical ID of a comment on an update
+    CommentId;
+
+    /// numerical ID of a release
+    ReleaseId;
+
+    /// numerical ID of a user
+    UserId;
+}
@@ -0,0 +1,131 @@
+use std::collections::{BTreeMap, HashMap};
+
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{InstanceType, Metadata, RootSchema, Schema, SchemaObject};
+use schemars::{schema_for, JsonSchema};
+
+use super::*;
+
+
+// schema for values that are (de)serialized as plain strings
+fn string_schema(description: &str) -> Schema {
+    SchemaObject {
+        metadata: Some(Box::new(Metadata {
+            description: Some(description.to_owned()),
+            ..Default::default()
+        })),
+        instance_type: Some(InstanceType::String.into()),
+        ..Default::default()
+    }
+    .into()
+}
+
+impl JsonSchema for BodhiDate {
+    fn schema_name() -> String {
+        String::from("BodhiDate")
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        string_schema(&format!(
+            "date & time (UTC) in the format \"{}\"",
+            BODHI_DATETIME_FORMAT
+        ))
+    }
+}
+
+impl JsonSchema for CritpathGroup {
+    fn schema_name() -> String {
+        String::from("CritpathGroup")
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        string_schema("name of a critical path group")
+    }
+}
+
+impl JsonSchema for ExtraFields {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        String::from("ExtraFields")
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        gen.subschema_for::<HashMap<String, serde_json::Value>>()
+    }
+}
+
+impl JsonSchema for FedoraRelease {
+    fn schema_name() -> String {
+        String::from("FedoraRelease")
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        string_schema("Fedora or EPEL release identifier (for example, \"F40\" or \"EPEL-9\")")
+    }
+}
+
+impl JsonSchema for IdPrefix {
+    fn schema_name() -> String {
+        String::from("IdPrefix")
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        string_schema("prefix of update aliases")
+    }
+}
+
+// karma values are (de)serialized as integers instead of variant names
+impl JsonSchema for Karma {
+    fn schema_name() -> String {
+        String::from("Karma")
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::Integer.into()),
+            enum_values: Some(vec![(-1).into(), 0.into(), 1.into()]),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+
+/// generate JSON Schema definitions for all structured data types that are returned by bodhi
+///
+/// The returned map contains one self-contained root schema per data type, keyed by the name of
+/// the type (for example, `"Update"`). It can be serialized as a single JSON document and used to
+/// validate server responses or to generate bindings for other languages.
+///
+/// ```
+/// let bundle = bodhi::schema_bundle();
+/// assert!(bundle.contains_key("Update"));
+///
+/// let json = serde_json::to_string_pretty(&bundle).unwrap();
+/// ```
+pub fn schema_bundle() -> BTreeMap<&'static str, RootSchema> {
+    let mut bundle = BTreeMap::new();
+
+    bundle.insert("Bug", schema_for!(Bug));
+    bundle.insert("BugFeedback", schema_for!(BugFeedback));
+    bundle.insert("Build", schema_for!(Build));
+    bundle.insert("Comment", schema_for!(Comment));
+    bundle.insert("Compose", schema_for!(Compose));
+    bundle.insert("GatingDecision", schema_for!(GatingDecision));
+    bundle.insert("GatingRequirement", schema_for!(GatingRequirement));
+    bundle.insert("Group", schema_for!(Group));
+    bundle.insert("Override", schema_for!(Override));
+    bundle.insert("Package", schema_for!(Package));
+    bundle.insert("Release", schema_for!(Release));
+    bundle.insert("TestCase", schema_for!(TestCase));
+    bundle.insert("TestCaseFeedback", schema_for!(TestCaseFeedback));
+    bundle.insert("TestResults", schema_for!(TestResults));
+    bundle.insert("Update", schema_for!(Update));
+    bundle.insert("UpdateSummary", schema_for!(UpdateSummary));
+    bundle.insert("User", schema_for!(User));
+
+    bundle
+}
@@ -0,0 +1,74 @@
+//! # versioning and migration of persisted data
+//!
+//! The JSON representation of the structured data types matches the format of bodhi server
+//! responses, but it is not guaranteed to be stable across versions of this crate. Tools that
+//! persist serialized values (for example, in local caches) can store the [`DATA_SCHEMA_VERSION`]
+//! alongside the data, and pass both to [`migrate_json`] when reading the data back with a newer
+//! version of this crate.
+
+use serde_json::Value;
+
+use super::error::MigrationError;
+use super::types::Update;
+
+/// version of the JSON representation of the structured data types in this crate
+///
+/// This version is incremented whenever the JSON representation of [`Update`] (or any of the
+/// types it contains) changes in a way that requires data written with previous versions of this
+/// crate to be migrated. The versions correspond to these versions of this crate:
+///
+/// - `0`: data written with bodhi-rs 2.2.0 and earlier (no schema version was available)
+/// - `1`: data written with bodhi-rs 3.0.0 and later
+pub const DATA_SCHEMA_VERSION: u32 = 1;
+
+// migrations of JSON representations of updates, where the migration at index `n` migrates data
+// from schema version `n` to schema version `n + 1`
+const UPDATE_MIGRATIONS: [fn(&mut Value); DATA_SCHEMA_VERSION as usize] = [update_v0_to_v1];
+
+// comments that are nested inside updates no longer contain an (empty) "update" field
+fn update_v0_to_v1(value: &mut Value) {
+    if let Some(comments) = value.get_mut("comments").and_then(Value::as_array_mut) {
+        for comment in comments.iter_mut().filter_map(Value::as_object_mut) {
+            if comment.get("update").is_some_and(Value::is_null) {
+                comment.remove("update");
+            }
+        }
+    }
+}
+
+/// migrate the JSON representation of an [`Update`] that was written with an older schema version
+///
+/// All migrations from `old_version` up to the current [`DATA_SCHEMA_VERSION`] are applied to the
+/// value, which is then deserialized. Values that were written with the current schema version
+/// are deserialized without modifications. Values that were written with a newer version of this
+/// crate result in a [`MigrationError::UnsupportedVersion`] error.
+///
+/// ```
+/// use bodhi::{migrate_json, DATA_SCHEMA_VERSION};
+///
+/// # let update = bodhi::mock::fixture("update.json").unwrap();
+/// # let cached = serde_json::from_str::<serde_json::Value>(update).unwrap()["update"].to_string();
+/// // value and schema version that were read from a local cache
+/// let (version, json) = (0, cached);
+///
+/// let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+/// let update = migrate_json(version, value).unwrap();
+///
+/// // persist the migrated value together with the current schema version
+/// let (version, json) = (DATA_SCHEMA_VERSION, serde_json::to_string(&update).unwrap());
+/// ```
+pub fn migrate_json(old_version: u32, value: Value) -> Result<Update, MigrationError> {
+    if old_version > DATA_SCHEMA_VERSION {
+        return Err(MigrationError::UnsupportedVersion {
+            version: old_version,
+            supported: DATA_SCHEMA_VERSION,
+        });
+    }
+
+    let mut value = value;
+    for migration in &UPDATE_MIGRATIONS[old_version as usize..] {
+        migration(&mut value);
+    }
+
+    Ok(serde_json::from_value(value)?)
+}
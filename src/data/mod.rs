@@ -13,6 +13,25 @@
 //! as closely as possible to the python class definitions of the server and bodhi client
 //! implementations.
 //!
+//! Most structured data types also contain a catch-all map of [`ExtraFields`] (accessible with
+//! their `extra` methods), which collects all fields from JSON server responses that are not (yet)
+//! known to this crate. When building this crate with the `strict-schema` feature, deserializing
+//! data that contains unknown fields results in an error instead, which can be used to detect
+//! changes of the server-side data schema early. Typed access to custom fields that are added by
+//! patched bodhi servers can be provided by implementing [`ExtraExtension`].
+//!
+//! ## JSON Schema definitions
+//!
+//! When building this crate with the `schemars` feature, all data types implement
+//! `schemars::JsonSchema`, and the `schema_bundle` function generates JSON Schema definitions for
+//! all structured data types at once.
+//!
+//! ## persisted data
+//!
+//! The JSON representation of structured data types can change between versions of this crate.
+//! Tools that persist serialized updates can store the [`DATA_SCHEMA_VERSION`] together with the
+//! data, and use [`migrate_json`] to read data that was written with older versions.
+//!
 //! ## enumerated string types
 //!
 //! Some fields in structured JSON data are strings, but there is only a limited number of values
@@ -21,6 +40,12 @@
 //! invalid values for those fields, and they are also turned into enum variants when they are
 //! deserialized from JSON.
 //!
+//! ## typed identifiers
+//!
+//! Numerical IDs of builds, comments, releases, and users are wrapped in distinct newtypes (for
+//! example, [`CommentId`] and [`UserId`]), which makes it impossible to accidentally mix them up.
+//! They can be converted from and into plain `u32` values.
+//!
 //! ## custom datetime type
 //!
 //! The bodhi server also returns date/time values in a non-standard format (neither RFC 3339 nor
@@ -44,14 +69,40 @@
 //! for new releases, but release values are still validated against the expected format of Fedora
 //! and EPEL release identifiers.
 
+#[cfg(test)]
+mod arbitrary;
+
+mod bots;
+pub use bots::{BotList, KNOWN_BOTS};
+
 mod dates;
 pub use dates::*;
 
+mod entity;
+pub use entity::Entity;
+
 mod enums;
 pub use enums::*;
 
 mod error;
-pub use error::InvalidValueError;
+pub use error::{InvalidValueError, MigrationError};
+
+mod extra;
+pub use extra::{ExtraExtension, ExtraFields};
+
+mod ids;
+pub use ids::*;
+
+#[cfg(feature = "schemars")]
+mod json_schema;
+#[cfg(feature = "schemars")]
+pub use json_schema::schema_bundle;
+
+mod migrate;
+pub use migrate::{migrate_json, DATA_SCHEMA_VERSION};
+
+mod nvr;
+pub(crate) use nvr::split_nvr;
 
 mod release;
 pub use release::*;
@@ -62,6 +113,9 @@ pub(crate) use schemas::*;
 mod types;
 pub use types::*;
 
+mod update_set;
+pub use update_set::UpdateSet;
+
 // base URL of the fedora bodhi instance
 pub(crate) const FEDORA_BODHI_URL: &str = "https://bodhi.fedoraproject.org";
 
@@ -0,0 +1,39 @@
+// split an NVR (Name-Version-Release) string into its three components
+//
+// Package names can contain dashes, but versions and releases can not, so the string is split at
+// the last two dashes.
+pub(crate) fn split_nvr(nvr: &str) -> Option<(&str, &str, &str)> {
+    let mut parts = nvr.rsplitn(3, '-');
+
+    let release = parts.next()?;
+    let version = parts.next()?;
+    let name = parts.next()?;
+
+    if name.is_empty() || version.is_empty() || release.is_empty() {
+        return None;
+    }
+
+    Some((name, version, release))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_valid() {
+        assert_eq!(
+            split_nvr("rust-bodhi-2.2.0-1.fc40"),
+            Some(("rust-bodhi", "2.2.0", "1.fc40"))
+        );
+        assert_eq!(split_nvr("bash-5.2.26-3.fc40"), Some(("bash", "5.2.26", "3.fc40")));
+    }
+
+    #[test]
+    fn split_invalid() {
+        assert_eq!(split_nvr("bash"), None);
+        assert_eq!(split_nvr("bash-5.2.26"), None);
+        assert_eq!(split_nvr("-5.2.26-3.fc40"), None);
+        assert_eq!(split_nvr("bash--3.fc40"), None);
+    }
+}
@@ -185,7 +185,12 @@ mod el {
 /// - [`FedoraRelease::CURRENT`]
 /// - [`FedoraRelease::PENDING`]
 /// - [`FedoraRelease::ARCHIVED`]
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+///
+/// All methods of constructing [`FedoraRelease`] values (including parsing and deserialization)
+/// normalize release identifiers to their canonical uppercase form (i.e. `f40` is equivalent to
+/// `F40`), so values can be compared, hashed, and used as keys in maps reliably. The ordering of
+/// values is based on the lexicographic ordering of their canonical string representation.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(transparent)]
 pub struct FedoraRelease {
     release: Cow<'static, str>,
@@ -216,6 +221,15 @@ impl FedoraRelease {
         }
     }
 
+    // internal method for normalizing release identifiers to their canonical form
+    // (special values for queries like "__current__" are always lowercase)
+    fn canonicalize(mut string: String) -> String {
+        if !string.starts_with("__") {
+            string.make_ascii_uppercase();
+        }
+        string
+    }
+
     /// construct and validate a Fedora [`FedoraRelease`] value from its parts
     ///
     /// The validation process takes various constraints into account:
@@ -263,7 +277,9 @@ impl TryFrom<&str> for FedoraRelease {
     type Error = InvalidValueError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
+        let canonical = FedoraRelease::canonicalize(value.to_owned());
+
+        match canonical.as_str() {
             "" => Err(InvalidValueError::new("FedoraRelease", String::from("(empty string)"))),
             "ELN" => Ok(FedoraRelease::from_str("ELN")),
             f if f.starts_with('F') => fedora::release_validate(f),
@@ -282,26 +298,28 @@ impl FromStr for FedoraRelease {
     }
 }
 
+impl<'de> Deserialize<'de> for FedoraRelease {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // release identifiers returned by the server are not validated, since they can refer to
+        // releases that are not supported by this crate yet
+        let string = String::deserialize(deserializer)?;
+
+        Ok(FedoraRelease {
+            release: Cow::Owned(FedoraRelease::canonicalize(string)),
+        })
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
-    use quickcheck::{Arbitrary, Gen, QuickCheck};
+    use quickcheck::QuickCheck;
 
     use super::*;
 
-    // hacky implementation of the Arbitrary trait for ContentType
-    // this only works because the enum has exactly four variants
-    impl Arbitrary for ContentType {
-        fn arbitrary(g: &mut Gen) -> Self {
-            match (bool::arbitrary(g), bool::arbitrary(g)) {
-                (true, true) => ContentType::RPM,
-                (true, false) => ContentType::Container,
-                (false, true) => ContentType::Flatpak,
-                (false, false) => ContentType::Module,
-            }
-        }
-    }
-
     // helper function that returns a quickcheck value
     fn quickchecker() -> QuickCheck {
         QuickCheck::new().tests(1_000_000)
@@ -431,6 +449,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_canonical() {
+        use std::collections::HashSet;
+
+        #[rustfmt::skip]
+        let fixtures = [
+            ("f40", "F40"), ("f40c", "F40C"), ("epel-9n", "EPEL-9N"), ("el-6", "EL-6"), ("eln", "ELN"),
+        ];
+
+        for (value, expected) in fixtures {
+            let release = FedoraRelease::try_from(value).unwrap();
+            assert_eq!(release.to_string(), expected);
+            assert_eq!(release, FedoraRelease::try_from(expected).unwrap());
+        }
+
+        let deserialized: FedoraRelease = serde_json::from_str(r#""epel-9""#).unwrap();
+        assert_eq!(deserialized, FedoraRelease::epel(9, ContentType::RPM, false).unwrap());
+
+        let deserialized: FedoraRelease = serde_json::from_str(r#""__current__""#).unwrap();
+        assert_eq!(deserialized, FedoraRelease::CURRENT);
+
+        let releases: HashSet<FedoraRelease> = ["F40", "f40", "F39"]
+            .into_iter()
+            .map(|value| value.parse().unwrap())
+            .collect();
+        assert_eq!(releases.len(), 2);
+    }
+
     #[test]
     fn parse_invalid() {
         #[rustfmt::skip]
@@ -1,15 +1,55 @@
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 
-use fedora::url::Url;
+use chrono::{DateTime, Duration, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use url::Url;
 
+use super::bots::*;
 use super::dates::*;
 use super::enums::*;
+use super::extra::{extra_fields, ExtraExtension, ExtraFields};
+use super::ids::*;
 use super::release::FedoraRelease;
 
+// bodhi posts a comment with this content on updates that are obsoleted by a newer update
+static OBSOLETED_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"This update has been obsoleted by \[[^\]]*\]\([^)]*/updates/(?P<alias>[^/)]+)/?\)")
+        .expect("Failed to compile hard-coded regex!")
+});
+
+// name of the service account with the given OpenID identity
+//
+// OpenID identities of service accounts have the form "{name}.id.fedoraproject.org".
+fn openid_name(openid: &str) -> Option<&str> {
+    openid
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_end_matches('/')
+        .strip_suffix(".id.fedoraproject.org")
+}
+
+// classify the author of a comment based on the user name and OpenID identity
+fn classify_user(name: &str, openid: Option<&str>) -> CommentKind {
+    let names = [Some(name), openid.and_then(openid_name)];
+    let mut names = names.iter().flatten();
+
+    if names.clone().any(|name| *name == BODHI_BOT_USER) {
+        CommentKind::BodhiBot
+    } else if names.any(|name| CI_SYSTEM_USERS.contains(name) || name.ends_with("-ci")) {
+        CommentKind::CISystem
+    } else {
+        CommentKind::User
+    }
+}
+
 /// data type that represents a BugZilla bug that is associated with an update
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Bug {
     /// bug ID in the BugZilla system: <https://bugzilla.redhat.com/show_bug.cgi?id={bug_id}>
@@ -22,8 +62,9 @@ pub struct Bug {
     pub title: Option<String>,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
 }
 
 impl Display for Bug {
@@ -52,6 +93,7 @@ impl Bug {
 
 /// data type that represents a feedback item for a bug that is associated with an update
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct BugFeedback {
     /// bug this feedback is associated with
@@ -59,13 +101,14 @@ pub struct BugFeedback {
     /// ID of the bug that this feedback is associated with
     pub bug_id: u32,
     /// ID of the comment that this feedback is associated with
-    pub comment_id: Option<u32>,
+    pub comment_id: Option<CommentId>,
     /// feedback karma (positive, neutral, negative)
     pub karma: Karma,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
 }
 
 impl Display for BugFeedback {
@@ -77,6 +120,7 @@ impl Display for BugFeedback {
 
 /// data type that represents a koji build that is associated with an update
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Build {
     /// Epoch value of this build (`None` if unspecified)
@@ -84,7 +128,7 @@ pub struct Build {
     /// NVR (Name-Version-Release) string of this build
     pub nvr: String,
     /// release ID of the release that this build is associated with
-    pub release_id: Option<u32>,
+    pub release_id: Option<ReleaseId>,
     /// flag to indicate whether this build has been signed yet
     pub signed: bool,
     /// build type (RPM, container, flatpak, module)
@@ -92,8 +136,9 @@ pub struct Build {
     pub build_type: ContentType,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
 }
 
 impl Display for Build {
@@ -115,6 +160,7 @@ impl Display for Build {
 
 /// data type that represents a comment on an update (including bug and test case feedback)
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Comment {
     // author of the comment (username), only provided for backwards compatibility
@@ -123,11 +169,13 @@ pub struct Comment {
     /// list of bug feedback items
     pub bug_feedback: Vec<BugFeedback>,
     /// numerical ID of this comment
-    pub id: u32,
+    pub id: CommentId,
     /// karma feedback associated with this comment
     pub karma: Karma,
     // feedback associated with "critpath" checks
+    // (only provided for backwards compatibility, and not included by newer server versions)
     #[deprecated(since = "2.0.0")]
+    #[serde(default)]
     karma_critpath: Karma,
     /// list of test case feedback items
     pub testcase_feedback: Vec<TestCaseFeedback>,
@@ -135,9 +183,16 @@ pub struct Comment {
     pub text: String,
     /// date & time this comment was published
     #[serde(with = "bodhi_date_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "BodhiDate"))]
     pub timestamp: BodhiDate,
     /// update this comment is associated with
-    pub update: Option<Update>,
+    ///
+    /// This field is not included in comments that are nested inside updates, so there is at most
+    /// one level of nesting. The update is boxed to keep the size of comments (which are often
+    /// returned in large numbers) small. It can also be omitted from query results with
+    /// [`CommentQuery::embedded_updates`](crate::CommentQuery::embedded_updates).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update: Option<Box<Update>>,
     /// ID of the update this comment is associated with
     pub update_id: u32,
     // alias of the update this comment is associated with
@@ -147,11 +202,36 @@ pub struct Comment {
     /// user who submitted this comment
     pub user: User,
     /// user ID of the user who submitted this comment
-    pub user_id: u32,
+    pub user_id: UserId,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
+}
+
+impl Comment {
+    /// kind of the author of this comment (regular user, bodhi itself, or a CI system)
+    ///
+    /// The classification is based on the user name and OpenID identity of the author of the
+    /// comment, and uses a list of known service accounts.
+    pub fn kind(&self) -> CommentKind {
+        classify_user(&self.user.name, self.user.openid.as_deref())
+    }
+
+    /// flag to indicate whether this comment was posted by an automated system
+    pub fn is_bot(&self) -> bool {
+        self.kind() != CommentKind::User
+    }
+
+    // alias of the update this comment is associated with, if it is included in the comment
+    #[allow(deprecated)]
+    pub(crate) fn update_alias(&self) -> Option<&str> {
+        match &self.update {
+            Some(update) => Some(update.alias.as_str()),
+            None => self.update_alias.as_deref(),
+        }
+    }
 }
 
 impl Display for Comment {
@@ -165,9 +245,69 @@ impl Display for Comment {
     }
 }
 
+// comment that is deserialized without its embedded update (only the alias of the update is kept)
+//
+// This has the same fields as `Comment`, but all fields of the embedded update except for its
+// alias are skipped with `serde::de::IgnoredAny` instead of being deserialized, which avoids
+// allocating the update with all its builds, bugs, and test cases only to drop it afterwards.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ShallowComment {
+    author: Option<String>,
+    bug_feedback: Vec<BugFeedback>,
+    id: CommentId,
+    karma: Karma,
+    #[serde(default)]
+    karma_critpath: Karma,
+    testcase_feedback: Vec<TestCaseFeedback>,
+    text: String,
+    #[serde(with = "bodhi_date_format")]
+    timestamp: BodhiDate,
+    update: Option<UpdateAlias>,
+    update_id: u32,
+    update_alias: Option<String>,
+    user: User,
+    user_id: UserId,
+
+    #[serde(flatten, with = "extra_fields")]
+    extra: ExtraFields,
+}
+
+// alias of an embedded update
+//
+// The derived implementation of `Deserialize` skips all unknown fields with `IgnoredAny`.
+#[derive(Debug, Deserialize)]
+struct UpdateAlias {
+    alias: Option<String>,
+}
+
+impl From<ShallowComment> for Comment {
+    #[allow(deprecated)]
+    fn from(comment: ShallowComment) -> Self {
+        let embedded = comment.update.and_then(|update| update.alias);
+
+        Comment {
+            author: comment.author,
+            bug_feedback: comment.bug_feedback,
+            id: comment.id,
+            karma: comment.karma,
+            karma_critpath: comment.karma_critpath,
+            testcase_feedback: comment.testcase_feedback,
+            text: comment.text,
+            timestamp: comment.timestamp,
+            update: None,
+            update_id: comment.update_id,
+            update_alias: comment.update_alias.or(embedded),
+            user: comment.user,
+            user_id: comment.user_id,
+            extra: comment.extra,
+        }
+    }
+}
+
 
 /// data type that represents a (running) compose for an "updates" or "updates-testing" repository
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Compose {
     /// string of JSON-formatted checkpoint data for the compose
@@ -176,13 +316,14 @@ pub struct Compose {
     pub content_type: Option<ContentType>,
     /// date & time when this compose was triggered
     #[serde(with = "bodhi_date_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "BodhiDate"))]
     pub date_created: BodhiDate,
     /// error message in case of failures (empty string if no errors have occurred yet)
     pub error_message: Option<String>,
     /// release this compose is associated with
     pub release: Option<Release>,
     /// numerical ID of the release this compose is associated with
-    pub release_id: u32,
+    pub release_id: ReleaseId,
     /// target of the compose:
     ///
     /// - stable: "updates" repository
@@ -194,13 +335,15 @@ pub struct Compose {
     pub state: ComposeState,
     /// date & time when the compose status was last updated
     #[serde(with = "bodhi_date_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "BodhiDate"))]
     pub state_date: BodhiDate,
     /// list of summaries for the contained updates (contains update aliases and titles)
     pub update_summary: Vec<UpdateSummary>,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
 }
 
 impl Display for Compose {
@@ -226,17 +369,214 @@ impl Display for Compose {
     }
 }
 
+// order of the states that composes go through until they are finished
+const COMPOSE_STEPS: &[ComposeState] = &[
+    ComposeState::Requested,
+    ComposeState::Pending,
+    ComposeState::Initializing,
+    ComposeState::UpdateInfo,
+    ComposeState::Punging,
+    ComposeState::SigningRepo,
+    ComposeState::SyncingRepo,
+    ComposeState::Notifying,
+    ComposeState::Cleaning,
+];
+
+impl Compose {
+    /// estimated date & time when this compose will be finished
+    ///
+    /// The bodhi server does not record when compose steps were started or finished (the
+    /// [`Compose::checkpoints`] only contain flags for completed steps), so the estimate is based
+    /// on the current state of the compose, the time when it entered this state
+    /// ([`Compose::state_date`]), and the given average durations of all remaining steps. If the
+    /// current step is already taking longer than its average duration, it is assumed to finish
+    /// immediately.
+    ///
+    /// This returns `None` if the compose has already finished (successfully or not).
+    pub fn eta(&self, durations: &ComposeDurations) -> Option<BodhiDate> {
+        estimate_completion(self.state, &self.state_date, durations, &BodhiDate::now())
+    }
+}
+
+fn estimate_completion(
+    state: ComposeState,
+    state_date: &BodhiDate,
+    durations: &ComposeDurations,
+    now: &BodhiDate,
+) -> Option<BodhiDate> {
+    let index = COMPOSE_STEPS.iter().position(|step| *step == state)?;
+
+    let state_date: DateTime<Utc> = state_date.clone().into();
+    let now: DateTime<Utc> = now.clone().into();
+
+    let current_end = state_date + durations.for_state(state)?;
+    let remaining = COMPOSE_STEPS[index + 1..]
+        .iter()
+        .filter_map(|step| durations.for_state(*step))
+        .fold(Duration::zero(), |sum, duration| sum + duration);
+
+    Some((current_end.max(now) + remaining).into())
+}
+
+
+/// average durations of the steps of a compose, used for estimating when composes will finish
+///
+/// The default values are rough estimates for composes of RPM updates on the Fedora bodhi
+/// instance. Applications that keep track of the durations of past composes can provide more
+/// accurate values.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ComposeDurations {
+    /// average duration of the [`ComposeState::Requested`] state
+    pub requested: Duration,
+    /// average duration of the [`ComposeState::Pending`] state
+    pub pending: Duration,
+    /// average duration of the [`ComposeState::Initializing`] state
+    pub initializing: Duration,
+    /// average duration of the [`ComposeState::UpdateInfo`] state
+    pub updateinfo: Duration,
+    /// average duration of the [`ComposeState::Punging`] state
+    pub punging: Duration,
+    /// average duration of the [`ComposeState::SigningRepo`] state
+    pub signing_repo: Duration,
+    /// average duration of the [`ComposeState::SyncingRepo`] state
+    pub syncing_repo: Duration,
+    /// average duration of the [`ComposeState::Notifying`] state
+    pub notifying: Duration,
+    /// average duration of the [`ComposeState::Cleaning`] state
+    pub cleaning: Duration,
+}
+
+impl Default for ComposeDurations {
+    fn default() -> Self {
+        ComposeDurations {
+            requested: Duration::minutes(5),
+            pending: Duration::minutes(10),
+            initializing: Duration::minutes(5),
+            updateinfo: Duration::minutes(15),
+            punging: Duration::minutes(90),
+            signing_repo: Duration::minutes(10),
+            syncing_repo: Duration::minutes(30),
+            notifying: Duration::minutes(5),
+            cleaning: Duration::minutes(5),
+        }
+    }
+}
+
+impl ComposeDurations {
+    /// average duration of the given compose state
+    ///
+    /// This returns `None` for the final states ([`ComposeState::Success`] and
+    /// [`ComposeState::Failed`]).
+    pub fn for_state(&self, state: ComposeState) -> Option<Duration> {
+        match state {
+            ComposeState::Requested => Some(self.requested),
+            ComposeState::Pending => Some(self.pending),
+            ComposeState::Initializing => Some(self.initializing),
+            ComposeState::UpdateInfo => Some(self.updateinfo),
+            ComposeState::Punging => Some(self.punging),
+            ComposeState::SigningRepo => Some(self.signing_repo),
+            ComposeState::SyncingRepo => Some(self.syncing_repo),
+            ComposeState::Notifying => Some(self.notifying),
+            ComposeState::Cleaning => Some(self.cleaning),
+            ComposeState::Success | ComposeState::Failed => None,
+        }
+    }
+}
+
+
+/// data type that represents a gating decision that was made by greenwave for an update
+///
+/// Decisions are returned as part of the [`TestResults`] of an update. Test results and waivers
+/// that the decision was based on are passed through from resultsdb and waiverdb, and are not
+/// modelled in detail.
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct GatingDecision {
+    /// names of the gating policies that were applied
+    #[serde(default)]
+    pub applicable_policies: Vec<String>,
+    /// flag to indicate whether all applicable gating policies are satisfied
+    pub policies_satisfied: bool,
+    /// test results (from resultsdb) that this decision was based on
+    #[serde(default)]
+    pub results: Vec<serde_json::Value>,
+    /// requirements that are satisfied (passed tests, or waived failures)
+    #[serde(default)]
+    pub satisfied_requirements: Vec<GatingRequirement>,
+    /// human-readable summary of this decision
+    pub summary: String,
+    /// requirements that are not satisfied (failed or missing tests)
+    #[serde(default)]
+    pub unsatisfied_requirements: Vec<GatingRequirement>,
+    /// waivers (from waiverdb) that this decision was based on
+    #[serde(default)]
+    pub waivers: Vec<serde_json::Value>,
+
+    /// catch-all for fields that are not explicitly deserialized
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
+}
+
+impl Display for GatingDecision {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", &self.summary)
+    }
+}
+
+
+/// data type that represents a single requirement of a [`GatingDecision`]
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct GatingRequirement {
+    /// tested item (only included for unsatisfied requirements by some greenwave versions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<serde_json::Value>,
+    /// ID of the test result in resultsdb (if there is a test result)
+    pub result_id: Option<u64>,
+    /// test scenario (for tests that are run in multiple scenarios)
+    pub scenario: Option<String>,
+    /// identifier of the tested subject (for example, the NVR of a build, or an update alias)
+    pub subject_identifier: Option<String>,
+    /// type of the tested subject (for example, `koji_build` or `bodhi_update`)
+    pub subject_type: Option<String>,
+    /// name of the test case
+    pub testcase: Option<String>,
+    /// type of this requirement (for example, `test-result-passed` or `test-result-missing`)
+    #[serde(rename = "type")]
+    pub requirement_type: String,
+
+    /// catch-all for fields that are not explicitly deserialized
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
+}
+
+impl Display for GatingRequirement {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match (&self.testcase, &self.subject_identifier) {
+            (Some(testcase), Some(subject)) => write!(f, "{}: {} ({})", self.requirement_type, testcase, subject),
+            (Some(testcase), None) => write!(f, "{}: {}", self.requirement_type, testcase),
+            (None, _) => write!(f, "{}", self.requirement_type),
+        }
+    }
+}
+
 
 /// data type that represents a group of users in the fedora accounts system (FAS)
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Group {
     /// name of the group
     pub name: String,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
 }
 
 impl Display for Group {
@@ -248,17 +588,20 @@ impl Display for Group {
 
 /// data type that represents a buildroot override and its associated koji build
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Override {
     /// koji build that is associated with this buildroot override
     pub build: Build,
     /// build ID of the koji build that is associated with this buildroot override
-    pub build_id: u32,
+    pub build_id: BuildId,
     /// date & time when this buildroot override will expire
     #[serde(with = "bodhi_date_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "BodhiDate"))]
     pub expiration_date: BodhiDate,
     /// date & time when this buildroot override has expired
     #[serde(with = "option_bodhi_date_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<BodhiDate>"))]
     pub expired_date: Option<BodhiDate>,
     /// notes associated with this buildroot override
     pub notes: String,
@@ -267,15 +610,17 @@ pub struct Override {
     pub nvr: String,
     /// date & time when this buildroot override was submitted
     #[serde(with = "bodhi_date_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "BodhiDate"))]
     pub submission_date: BodhiDate,
     /// user who submitted this buildroot override
     pub submitter: User,
     /// user ID of the user who submitted this buildroot override
-    pub submitter_id: u32,
+    pub submitter_id: UserId,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
 }
 
 impl Display for Override {
@@ -299,6 +644,7 @@ impl Display for Override {
 
 /// data type that represents a package (or other distributable content) known to bodhi
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Package {
     /// unique identifier of the (source) package (or container, flatpak, or module, as appropriate)
@@ -310,8 +656,9 @@ pub struct Package {
     pub requirements: Option<String>,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
 }
 
 impl Display for Package {
@@ -328,6 +675,7 @@ impl Display for Package {
 
 /// data type that represents a release (or release variant, based on content type) known to bodhi
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Release {
     /// name of the dist-git branch that is associated with this release
@@ -348,7 +696,7 @@ pub struct Release {
     /// value of the RPM `%{?dist}` tag on this release
     pub dist_tag: String,
     /// update alias prefix for this release (`FEDORA{-EPEL,}{-CONTAINER,-FLATPAK,-MODULAR,}`)
-    pub id_prefix: String,
+    pub id_prefix: IdPrefix,
     /// long name of this release
     pub long_name: String,
     /// name of the email template for errata
@@ -356,6 +704,10 @@ pub struct Release {
     /// short identifier of this release
     pub name: FedoraRelease,
     /// package manager that is used on this release (parsed into [`PackageManager`] variants)
+    ///
+    /// This field is not included in responses from older bodhi-server versions (5.x), in which
+    /// case it is set to [`PackageManager::Unspecified`].
+    #[serde(default)]
     pub package_manager: PackageManager,
     /// name of the tag for builds in buildroot overrides
     pub override_tag: String,
@@ -370,17 +722,61 @@ pub struct Release {
     /// current state of this release (parsed into [`ReleaseState`] variants)
     pub state: ReleaseState,
     /// name of the repository that is used for testing updates
+    /// (not included in responses from older bodhi-server versions)
     pub testing_repository: Option<String>,
     /// name of the tag for builds that have been pushed to testing
     pub testing_tag: String,
     /// Fedora version string corresponding to this release
     pub version: String,
     /// end-of-life date of this release in the format `YYYY-MM-DD`
+    /// (not included in responses from older bodhi-server versions)
     pub eol: Option<String>,
+    /// date when this release was released in the format `YYYY-MM-DD`
+    /// (present since bodhi-server v7.2.0)
+    pub released_on: Option<String>,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
+}
+
+impl Release {
+    /// flag to indicate whether this release is currently frozen (for example, during a beta or
+    /// final freeze before a new Fedora release)
+    ///
+    /// Updates for frozen releases can still be submitted to stable, but they are only pushed to
+    /// the stable repositories after the freeze has ended (unless they were granted a freeze
+    /// exception).
+    pub fn is_frozen(&self) -> bool {
+        self.state == ReleaseState::Frozen
+    }
+
+    /// flag to indicate whether this is the Fedora Rawhide release
+    ///
+    /// Updates for Rawhide are created automatically for every build, are not composed by bodhi,
+    /// and skip the testing phase: they are pushed to stable directly once they pass gating tests.
+    /// ELN is built from the same branch and also has automatic updates, but it is not considered
+    /// to be Rawhide.
+    pub fn is_rawhide(&self) -> bool {
+        self.create_automatic_updates == Some(true)
+            && !self.composed_by_bodhi
+            && self.name != FedoraRelease::ELN
+            && (self.branch == "rawhide" || self.version.eq_ignore_ascii_case("rawhide"))
+    }
+
+    /// name of the koji tag of the given kind for this release
+    pub fn tag(&self, tag: ReleaseTag) -> &str {
+        match tag {
+            ReleaseTag::Candidate => &self.candidate_tag,
+            ReleaseTag::Override => &self.override_tag,
+            ReleaseTag::PendingSigning => &self.pending_signing_tag,
+            ReleaseTag::PendingStable => &self.pending_stable_tag,
+            ReleaseTag::PendingTesting => &self.pending_testing_tag,
+            ReleaseTag::Stable => &self.stable_tag,
+            ReleaseTag::Testing => &self.testing_tag,
+        }
+    }
 }
 
 impl Display for Release {
@@ -403,6 +799,7 @@ impl Display for Release {
 
 /// data type that represents a test case that is associated with a package
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct TestCase {
     /// name of this test case
@@ -411,8 +808,9 @@ pub struct TestCase {
     pub package: Option<Package>,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
 }
 
 impl Display for TestCase {
@@ -445,10 +843,11 @@ impl TestCase {
 
 /// data type that represents a feedback item for a test case that is associated with an update
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct TestCaseFeedback {
     /// ID of the comment that this feedback is associated with
-    pub comment_id: Option<u32>,
+    pub comment_id: Option<CommentId>,
     /// feedback karma (positive, neutral, negative)
     pub karma: Karma,
     /// test case that this feedback is associated with
@@ -457,8 +856,9 @@ pub struct TestCaseFeedback {
     pub testcase_id: u32,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
 }
 
 impl Display for TestCaseFeedback {
@@ -468,8 +868,61 @@ impl Display for TestCaseFeedback {
 }
 
 
+/// data type that represents the test results of an update
+///
+/// This is the result of querying the gating decisions that greenwave made for an update, based
+/// on test results in resultsdb (see [`UpdateTestResultsQuery`](crate::UpdateTestResultsQuery)).
+/// Updates for releases without test gating have no decisions.
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct TestResults {
+    /// gating decisions for the update (one decision per gating context)
+    pub decisions: Vec<GatingDecision>,
+
+    /// catch-all for fields that are not explicitly deserialized
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
+}
+
+impl Display for TestResults {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.decisions.is_empty() {
+            return writeln!(f, "No gating decisions.");
+        }
+
+        for decision in &self.decisions {
+            writeln!(f, "{decision}")?;
+            for requirement in &decision.unsatisfied_requirements {
+                writeln!(f, "- {requirement}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TestResults {
+    /// flag to indicate whether all gating policies are satisfied
+    ///
+    /// This is also the case if there are no gating decisions for the update.
+    pub fn is_satisfied(&self) -> bool {
+        self.decisions.iter().all(|decision| decision.policies_satisfied)
+    }
+
+    /// iterator over the requirements that are not satisfied (for all gating decisions)
+    pub fn unsatisfied_requirements(&self) -> impl Iterator<Item = &GatingRequirement> {
+        self.decisions
+            .iter()
+            .flat_map(|decision| decision.unsatisfied_requirements.iter())
+    }
+}
+
+
 /// data type that represents an update
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct Update {
     /// user-visible, human-readable update alias (`FEDORA-2019-1A2BB23E`)
@@ -492,30 +945,38 @@ pub struct Update {
     pub content_type: Option<ContentType>,
     /// flag to indicate whether this update contains packages from the "critical path"
     pub critpath: bool,
-    /// optional string containing a space-separated list of critical path groups
+    /// list of critical path groups that this update is associated with
     /// (present since bodhi-server v7.0.0)
-    pub critpath_groups: Option<String>,
+    #[serde(default, with = "critpath_groups_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub critpath_groups: Option<Vec<CritpathGroup>>,
     /// last date & time when this update has been approved
     #[deprecated(
         since = "2.0.0",
         note = "`date_approved` is an unused field: <https://github.com/fedora-infra/bodhi/issues/4171>"
     )]
-    #[serde(with = "option_bodhi_date_format")]
+    #[serde(default, with = "option_bodhi_date_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<BodhiDate>"))]
     pub date_approved: Option<BodhiDate>,
     /// date & time when this update was modified
     #[serde(with = "option_bodhi_date_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<BodhiDate>"))]
     pub date_modified: Option<BodhiDate>,
     /// date & time when this update was pushed
     #[serde(with = "option_bodhi_date_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<BodhiDate>"))]
     pub date_pushed: Option<BodhiDate>,
     /// date & time when this update was pushed to stable
     #[serde(with = "option_bodhi_date_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<BodhiDate>"))]
     pub date_stable: Option<BodhiDate>,
     /// date & time when this update was submitted
     #[serde(with = "option_bodhi_date_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<BodhiDate>"))]
     pub date_submitted: Option<BodhiDate>,
     /// date & time when this update was pushed to testing
     #[serde(with = "option_bodhi_date_format")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<BodhiDate>"))]
     pub date_testing: Option<BodhiDate>,
     /// displayed "pretty" name of this update
     pub display_name: String,
@@ -555,7 +1016,8 @@ pub struct Update {
     pub test_cases: Option<Vec<TestCase>>,
     /// current greenwave gating status
     ///
-    /// If this value is `None`, greenwave was not yet enabled when this update was created.
+    /// If this value is `None`, greenwave was not yet enabled when this update was created, or the
+    /// field is not included in responses from the server (older bodhi-server versions).
     pub test_gating_status: Option<TestGatingStatus>,
     /// title of this update (automatically generated from build NVRs if `display_name` is `None`)
     pub title: String,
@@ -573,11 +1035,16 @@ pub struct Update {
     /// user who first created this update
     pub user: User,
     /// SHA-1 hash of the sorted, space-separated NVRs of the included builds
+    ///
+    /// This field is not included in responses from older bodhi-server versions (5.x), in which
+    /// case it is empty (and [`Update::verify_version_hash`] always returns `false`).
+    #[serde(default)]
     pub version_hash: String,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
 }
 
 impl Display for Update {
@@ -633,9 +1100,118 @@ impl Display for Update {
     }
 }
 
+impl Update {
+    /// builds of this update which have not been signed yet
+    ///
+    /// Updates can only be pushed once all of their builds have been signed, so any builds that
+    /// are returned by this method are currently blocking a push.
+    pub fn unsigned_builds(&self) -> Vec<&Build> {
+        self.builds.iter().filter(|build| !build.signed).collect()
+    }
+
+    /// alias of the newer update that obsoleted this update (if any)
+    ///
+    /// When an update is obsoleted by a newer update for the same packages, bodhi posts a comment
+    /// which links to the newer update. This method looks for such comments among the comments
+    /// that are included in this [`Update`], and returns the alias from the most recent one.
+    pub fn obsoleted_by(&self) -> Option<&str> {
+        self.comments
+            .iter()
+            .flatten()
+            .rev()
+            .find_map(|comment| OBSOLETED_RE.captures(&comment.text))
+            .and_then(|captures| captures.name("alias"))
+            .map(|alias| alias.as_str())
+    }
+
+    /// feedback items from comments on this update, grouped by bug ID
+    ///
+    /// The results contain an entry for every bug that is associated with this update (even if
+    /// no feedback was given for it), and for every other bug that feedback was given for. This
+    /// only considers comments that are included in this [`Update`].
+    pub fn feedback_by_bug(&self) -> HashMap<u32, Vec<&BugFeedback>> {
+        let mut feedback: HashMap<u32, Vec<&BugFeedback>> =
+            self.bugs.iter().map(|bug| (bug.bug_id, Vec::new())).collect();
+
+        for item in self.comments.iter().flatten().flat_map(|comment| &comment.bug_feedback) {
+            feedback.entry(item.bug_id).or_default().push(item);
+        }
+
+        feedback
+    }
+
+    /// feedback items from comments on this update, grouped by test case name
+    ///
+    /// The results contain an entry for every test case that is associated with this update
+    /// (even if no feedback was given for it), and for every other test case that feedback was
+    /// given for. This only considers comments that are included in this [`Update`].
+    pub fn feedback_by_testcase(&self) -> HashMap<&str, Vec<&TestCaseFeedback>> {
+        let mut feedback: HashMap<&str, Vec<&TestCaseFeedback>> = self
+            .test_cases
+            .iter()
+            .flatten()
+            .map(|testcase| (testcase.name.as_str(), Vec::new()))
+            .collect();
+
+        for item in self
+            .comments
+            .iter()
+            .flatten()
+            .flat_map(|comment| &comment.testcase_feedback)
+        {
+            feedback.entry(item.testcase.name.as_str()).or_default().push(item);
+        }
+
+        feedback
+    }
+
+    /// requests that are currently valid for this update
+    ///
+    /// This is based on the status of the update (see [`UpdateStatus::allowed_requests`]), and
+    /// additionally includes [`UpdateRequest::Revoke`] if the update has a pending request.
+    ///
+    /// Updates for Rawhide (see [`Release::is_rawhide`]) are pushed automatically, so requesting
+    /// them to be pushed to testing or stable is never valid.
+    pub fn allowed_requests(&self) -> Vec<UpdateRequest> {
+        let mut allowed = self.status.allowed_requests().to_vec();
+
+        if self.release.is_rawhide() {
+            allowed.retain(|request| !matches!(request, UpdateRequest::Testing | UpdateRequest::Stable));
+        }
+
+        if self.request.is_some() {
+            allowed.push(UpdateRequest::Revoke);
+        }
+
+        allowed
+    }
+
+    /// recompute the version hash of this update from the builds it contains
+    ///
+    /// This is computed in the same way as bodhi does it: the SHA-1 hash of the sorted,
+    /// space-separated NVRs of all builds in this update, in hexadecimal representation.
+    pub fn compute_version_hash(&self) -> String {
+        let mut nvrs: Vec<&str> = self.builds.iter().map(|build| build.nvr.as_str()).collect();
+        nvrs.sort_unstable();
+
+        let hash = Sha1::digest(nvrs.join(" ").as_bytes());
+        format!("{hash:x}")
+    }
+
+    /// check whether the version hash that was returned by the server matches the builds
+    ///
+    /// A mismatch indicates that the list of builds in this [`Update`] is out of sync with the
+    /// server state (for example, because the update was edited after it was loaded), which
+    /// should be checked before submitting edits based on this data.
+    pub fn verify_version_hash(&self) -> bool {
+        self.version_hash.eq_ignore_ascii_case(&self.compute_version_hash())
+    }
+}
+
 
 /// data type that represents an update summary
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct UpdateSummary {
     /// update alias that uniquely identifies the update
@@ -651,8 +1227,55 @@ impl Display for UpdateSummary {
 }
 
 
+/// data type that represents a subset of the fields of an [`Update`]
+///
+/// This type is returned by queries for "lightweight" update listings (see
+/// [`UpdateQuery::lite`](crate::UpdateQuery::lite)). Only the fields listed here are deserialized
+/// from server responses, and all other fields (including nested builds, bugs, comments, and test
+/// cases) are skipped without allocating memory for them, which is considerably cheaper for large
+/// result sets.
+#[derive(Debug, Deserialize)]
+#[non_exhaustive]
+pub struct UpdateLite {
+    /// user-visible, human-readable update alias (`FEDORA-2019-1A2BB23E`)
+    pub alias: String,
+    /// title of this update
+    pub title: String,
+    /// current state of this update
+    pub status: UpdateStatus,
+    /// current total of feedback karma values
+    pub karma: Option<i32>,
+    /// release that this update was submitted for
+    #[serde(rename = "release", deserialize_with = "release_name")]
+    pub release: FedoraRelease,
+    // date & time when this update was pushed to stable (used for client-side filtering)
+    #[serde(default, with = "option_bodhi_date_format")]
+    pub(crate) date_stable: Option<BodhiDate>,
+}
+
+impl Display for UpdateLite {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}: {} ({}, {})", self.alias, self.title, self.release, self.status)
+    }
+}
+
+// only deserialize the name of the release that is nested inside updates
+fn release_name<'de, D>(deserializer: D) -> Result<FedoraRelease, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct ReleaseName {
+        name: FedoraRelease,
+    }
+
+    Ok(ReleaseName::deserialize(deserializer)?.name)
+}
+
+
 /// data type that represents a user in the Fedora Accounts System (FAS) who is known to bodhi
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub struct User {
     /// URL of the [libravatar](https://www.libravatar.org/) avatar for this user
@@ -662,15 +1285,16 @@ pub struct User {
     /// list of groups this user is a member of
     pub groups: Vec<Group>,
     /// user ID that is associated with this user
-    pub id: u32,
+    pub id: UserId,
     /// unique FAS username of this user
     pub name: String,
     /// OpenID identity that is associated with the user
     pub openid: Option<String>,
 
     /// catch-all for fields that are not explicitly deserialized
-    #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    #[serde(flatten, with = "extra_fields")]
+    #[cfg_attr(feature = "schemars", schemars(with = "ExtraFields"))]
+    pub(crate) extra: ExtraFields,
 }
 
 impl Display for User {
@@ -694,3 +1318,175 @@ impl Display for User {
         Ok(())
     }
 }
+
+impl User {
+    /// flag to indicate whether this user is a well-known bot account (see [`KNOWN_BOTS`])
+    ///
+    /// Accounts of CI systems that follow the `{name}-ci` naming convention are also considered to
+    /// be bots. For matching against a list of bot accounts that can be modified at runtime, use
+    /// [`BotList::is_bot`].
+    pub fn is_known_bot(&self) -> bool {
+        self.matches_any(KNOWN_BOTS) || classify_user(&self.name, self.openid.as_deref()) != CommentKind::User
+    }
+
+    // check whether the user name or the name of the OpenID identity is in the given list
+    pub(crate) fn matches_any<S: AsRef<str>>(&self, names: &[S]) -> bool {
+        let openid_name = self.openid.as_deref().and_then(openid_name);
+
+        names
+            .iter()
+            .any(|name| name.as_ref() == self.name || Some(name.as_ref()) == openid_name)
+    }
+
+    /// construct a [libravatar](https://www.libravatar.org/) URL for this [`User`] with the
+    /// specified image size (in pixels)
+    ///
+    /// If the server returned an avatar URL for this user, its size parameter is replaced with the
+    /// specified value. Otherwise, a new URL is generated in the same way as bodhi does it: the
+    /// SHA-256 hash of the user's OpenID identity (or, if that is not available, their lowercase
+    /// E-Mail address) is used as the avatar identifier, with "retro" style images as fallback.
+    pub fn avatar_url(&self, size: u32) -> Url {
+        if let Some(avatar) = &self.avatar {
+            if let Ok(mut url) = Url::parse(avatar) {
+                let pairs: Vec<(String, String)> = url
+                    .query_pairs()
+                    .filter(|(key, _)| key != "s")
+                    .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                    .collect();
+
+                url.query_pairs_mut()
+                    .clear()
+                    .extend_pairs(pairs)
+                    .append_pair("s", &size.to_string());
+
+                return url;
+            }
+        }
+
+        let identity = match (&self.openid, &self.email) {
+            (Some(openid), _) => {
+                let openid = openid
+                    .trim_start_matches("https://")
+                    .trim_start_matches("http://")
+                    .trim_end_matches('/');
+                format!("http://{openid}/")
+            },
+            (None, Some(email)) => email.trim().to_lowercase(),
+            (None, None) => format!("http://{}.id.fedoraproject.org/", self.name),
+        };
+
+        let hash = Sha256::digest(identity.as_bytes());
+
+        Url::parse(&format!(
+            "https://seccdn.libravatar.org/avatar/{hash:x}?s={size}&d=retro"
+        ))
+        .expect("Failed to parse the hard-coded URL, this should not happen.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compose_eta() {
+        let durations = ComposeDurations::default();
+        let date = |s: &str| s.parse::<BodhiDate>().expect("Failed to parse date.");
+
+        // punging started 30 minutes ago: 60 minutes left, plus signing, syncing, notifying, cleaning
+        let eta = estimate_completion(
+            ComposeState::Punging,
+            &date("2024-05-01 12:00:00"),
+            &durations,
+            &date("2024-05-01 12:30:00"),
+        );
+        assert_eq!(eta, Some(date("2024-05-01 14:20:00")));
+
+        // punging is taking longer than average: assume it finishes now
+        let eta = estimate_completion(
+            ComposeState::Punging,
+            &date("2024-05-01 12:00:00"),
+            &durations,
+            &date("2024-05-01 14:00:00"),
+        );
+        assert_eq!(eta, Some(date("2024-05-01 14:50:00")));
+
+        let finished = estimate_completion(
+            ComposeState::Success,
+            &date("2024-05-01 12:00:00"),
+            &durations,
+            &date("2024-05-01 12:30:00"),
+        );
+        assert_eq!(finished, None);
+    }
+
+    #[test]
+    fn obsoletion_comment() {
+        let text = "This update has been obsoleted by [rust-bodhi-2.1.0-1.fc40](https://bodhi.fedoraproject.org/updates/FEDORA-2024-1a2b3c4d5e).";
+
+        let captures = OBSOLETED_RE
+            .captures(text)
+            .expect("Failed to match obsoletion comment.");
+        assert_eq!(&captures["alias"], "FEDORA-2024-1a2b3c4d5e");
+    }
+
+    #[test]
+    fn comment_kinds() {
+        assert_eq!(classify_user("bodhi", None), CommentKind::BodhiBot);
+        assert_eq!(classify_user("zuul", None), CommentKind::CISystem);
+        assert_eq!(classify_user("example-ci", None), CommentKind::CISystem);
+        assert_eq!(
+            classify_user("service", Some("http://packit.id.fedoraproject.org/")),
+            CommentKind::CISystem
+        );
+        assert_eq!(
+            classify_user("decathorpe", Some("decathorpe.id.fedoraproject.org")),
+            CommentKind::User
+        );
+    }
+}
+
+
+// accessors for the catch-all maps of fields that are not explicitly deserialized
+macro_rules! extra_accessors {
+    ($($name:ident),*) => {
+        $(
+            impl $name {
+                /// catch-all map for fields that are not explicitly deserialized
+                pub fn extra(&self) -> &ExtraFields {
+                    &self.extra
+                }
+
+                /// mutable reference to the catch-all map for fields that are not explicitly
+                /// deserialized
+                pub fn extra_mut(&mut self) -> &mut ExtraFields {
+                    &mut self.extra
+                }
+
+                /// typed view of fields that are not explicitly deserialized (see [`ExtraExtension`])
+                pub fn extra_typed<T: ExtraExtension<Entity = Self>>(&self) -> Result<T, serde_json::Error> {
+                    self.extra.parse()
+                }
+            }
+        )*
+    };
+}
+
+extra_accessors!(
+    Bug,
+    BugFeedback,
+    Build,
+    Comment,
+    Compose,
+    GatingDecision,
+    GatingRequirement,
+    Group,
+    Override,
+    Package,
+    Release,
+    TestCase,
+    TestCaseFeedback,
+    TestResults,
+    Update,
+    User
+);
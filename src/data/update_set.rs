@@ -0,0 +1,149 @@
+use std::collections::BTreeMap;
+
+use super::{TestGatingStatus, Update, UpdateType};
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::query::UpdateQuery;
+
+
+/// collection of updates with chainable filtering, sorting, and grouping methods
+///
+/// This is a thin wrapper around the results of update queries (for example, [`UpdateQuery`]),
+/// which provides shortcuts for common post-processing steps that would otherwise need to be
+/// implemented with manual iterator chains:
+///
+/// ```
+/// # use bodhi::mock::MockServer;
+/// use bodhi::{UpdateQuery, UpdateSet};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// let updates: UpdateSet = bodhi
+///     .paginated_request(&UpdateQuery::new())
+///     .await
+///     .unwrap()
+///     .into();
+///
+/// for (release, updates) in updates.security_only().sort_by_karma().group_by_release() {
+///     println!("{}: {} security update(s)", release, updates.len());
+/// }
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct UpdateSet {
+    updates: Vec<Update>,
+}
+
+impl UpdateSet {
+    /// constructor for [`UpdateSet`] from a list of updates
+    pub fn new(updates: Vec<Update>) -> Self {
+        UpdateSet { updates }
+    }
+
+    /// number of updates in this set
+    pub fn len(&self) -> usize {
+        self.updates.len()
+    }
+
+    /// check whether this set contains no updates
+    pub fn is_empty(&self) -> bool {
+        self.updates.is_empty()
+    }
+
+    /// iterator over the updates in this set (in their current order)
+    pub fn iter(&self) -> std::slice::Iter<'_, Update> {
+        self.updates.iter()
+    }
+
+    /// unwrap the list of updates in this set (in their current order)
+    pub fn into_inner(self) -> Vec<Update> {
+        self.updates
+    }
+
+    /// only keep updates for which the given predicate returns `true`
+    #[must_use]
+    pub fn filter(mut self, predicate: impl FnMut(&Update) -> bool) -> Self {
+        self.updates.retain(predicate);
+        self
+    }
+
+    /// only keep security updates
+    #[must_use]
+    pub fn security_only(self) -> Self {
+        self.filter(|update| update.update_type == UpdateType::Security)
+    }
+
+    /// only keep updates with failed gating tests
+    ///
+    /// This includes updates with the [`TestGatingStatus::Failed`] and
+    /// [`TestGatingStatus::GreenwaveFailed`] states.
+    #[must_use]
+    pub fn with_failed_gating(self) -> Self {
+        self.filter(|update| {
+            matches!(
+                update.test_gating_status,
+                Some(TestGatingStatus::Failed | TestGatingStatus::GreenwaveFailed)
+            )
+        })
+    }
+
+    /// sort updates by their karma (highest karma first)
+    ///
+    /// Updates without karma are treated as if they had zero karma. Sorting is stable, so updates
+    /// with the same karma keep their previous relative order.
+    #[must_use]
+    pub fn sort_by_karma(mut self) -> Self {
+        self.updates
+            .sort_by_key(|update| std::cmp::Reverse(update.karma.unwrap_or(0)));
+        self
+    }
+
+    /// group updates by the name of their release
+    ///
+    /// Updates keep their previous relative order within their groups.
+    pub fn group_by_release(self) -> BTreeMap<String, UpdateSet> {
+        let mut groups: BTreeMap<String, UpdateSet> = BTreeMap::new();
+
+        for update in self.updates {
+            groups
+                .entry(update.release.name.to_string())
+                .or_default()
+                .updates
+                .push(update);
+        }
+
+        groups
+    }
+}
+
+impl From<Vec<Update>> for UpdateSet {
+    fn from(updates: Vec<Update>) -> Self {
+        UpdateSet::new(updates)
+    }
+}
+
+impl FromIterator<Update> for UpdateSet {
+    fn from_iter<T: IntoIterator<Item = Update>>(iter: T) -> Self {
+        UpdateSet::new(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for UpdateSet {
+    type Item = Update;
+    type IntoIter = std::vec::IntoIter<Update>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.updates.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a UpdateSet {
+    type Item = &'a Update;
+    type IntoIter = std::slice::Iter<'a, Update>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.updates.iter()
+    }
+}
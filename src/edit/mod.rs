@@ -4,7 +4,14 @@
 //! overrides and updates on a bodhi instance.
 
 mod overrides;
-pub use overrides::{EditedOverride, OverrideEditor};
+pub use overrides::{EditedOverride, OverrideEditor, OverrideExpirer};
 
 mod updates;
-pub use updates::{EditedUpdate, UpdateEditor, UpdateStatusRequester, UpdateTestResultWaiver};
+pub use updates::{
+    EditedUpdate,
+    UpdateEditor,
+    UpdateRequestCaveat,
+    UpdateStatusRequester,
+    UpdateTestResultWaiver,
+    UpdateUntagger,
+};
@@ -6,6 +6,10 @@ use crate::data::{BodhiDate, Override, OverrideData};
 use crate::error::QueryError;
 use crate::request::{RequestMethod, SingleRequest};
 
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::client::BodhiClient;
+
 /// data of this type is returned after successfully editing a buildroot [`Override`]
 #[derive(Debug, Deserialize)]
 pub struct EditedOverride {
@@ -24,11 +28,25 @@ pub struct EditedOverride {
 
 /// data type wrapping all mandatory and optional parameters for editing a buildroot override
 ///
+/// An [`OverrideEditor`] can either be constructed from an existing [`Override`] value, or only
+/// from the NVR of the build of an existing buildroot override. In the latter case, the editor
+/// needs to be passed to [`BodhiClient::edit_override`], which fetches the current values of all
+/// parameters that were not changed explicitly, before submitting the edit request.
+///
+/// ```
+/// use bodhi::{BodhiDate, OverrideEditor};
+///
+/// let expiration_date = BodhiDate::try_from("2030-01-01").unwrap();
+/// let editor =
+///     OverrideEditor::from_nvr("wingpanel-2.2.1-1.fc28").expiration_date(&expiration_date);
+/// // let edited = bodhi.edit_override(&editor).await.unwrap();
+/// ```
+///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/overrides.html#service-1-POST>
 #[derive(Debug)]
 pub struct OverrideEditor<'a> {
-    notes: &'a str,
-    expiration_date: &'a BodhiDate,
+    notes: Option<&'a str>,
+    expiration_date: Option<&'a BodhiDate>,
     expired: Option<bool>,
     // NVR of the existing buildroot override to edit
     edited: &'a str,
@@ -38,24 +56,38 @@ impl<'a> OverrideEditor<'a> {
     /// constructor for [`OverrideEditor`] from an existing [`Override`] value
     pub fn from_override(over_ride: &'a Override) -> Self {
         OverrideEditor {
-            notes: &over_ride.notes,
-            expiration_date: &over_ride.expiration_date,
+            notes: Some(&over_ride.notes),
+            expiration_date: Some(&over_ride.expiration_date),
             expired: None,
             edited: &over_ride.nvr,
         }
     }
 
+    /// constructor for [`OverrideEditor`] from the NVR of an existing buildroot override
+    ///
+    /// Unless both notes and expiration date are set explicitly, editors constructed with this
+    /// method need to be submitted with [`BodhiClient::edit_override`], which resolves the missing
+    /// values from the existing buildroot override.
+    pub fn from_nvr(nvr: &'a str) -> Self {
+        OverrideEditor {
+            notes: None,
+            expiration_date: None,
+            expired: None,
+            edited: nvr,
+        }
+    }
+
     /// method for changing the override notes
     #[must_use]
     pub fn notes(mut self, notes: &'a str) -> Self {
-        self.notes = notes;
+        self.notes = Some(notes);
         self
     }
 
     /// method for changing the expiration date of the override
     #[must_use]
     pub fn expiration_date(mut self, expiration_date: &'a BodhiDate) -> Self {
-        self.expiration_date = expiration_date;
+        self.expiration_date = Some(expiration_date);
         self
     }
 
@@ -65,6 +97,26 @@ impl<'a> OverrideEditor<'a> {
         self.expired = Some(expired);
         self
     }
+
+    // NVR of the buildroot override that is edited
+    pub(crate) fn nvr(&self) -> &'a str {
+        self.edited
+    }
+
+    // check whether values for all mandatory parameters are available
+    pub(crate) fn is_resolved(&self) -> bool {
+        self.notes.is_some() && self.expiration_date.is_some()
+    }
+
+    // fill in values for parameters that were not set explicitly from an existing override
+    pub(crate) fn resolve<'b>(&'b self, over_ride: &'b Override) -> OverrideEditor<'b> {
+        OverrideEditor {
+            notes: Some(self.notes.unwrap_or(&over_ride.notes)),
+            expiration_date: Some(self.expiration_date.unwrap_or(&over_ride.expiration_date)),
+            expired: self.expired,
+            edited: self.edited,
+        }
+    }
 }
 
 impl<'a> SingleRequest<EditedOverride, EditedOverride> for OverrideEditor<'a> {
@@ -77,10 +129,19 @@ impl<'a> SingleRequest<EditedOverride, EditedOverride> for OverrideEditor<'a> {
     }
 
     fn body(&self, csrf_token: Option<String>) -> Result<Option<String>, QueryError> {
+        let (notes, expiration_date) = match (self.notes, self.expiration_date) {
+            (Some(notes), Some(expiration_date)) => (notes, expiration_date),
+            _ => return Err(QueryError::InvalidDataError {
+                error: String::from(
+                    "Notes and expiration date of the override are unknown, use BodhiClient::edit_override instead.",
+                ),
+            }),
+        };
+
         let override_edit = OverrideData {
             nvr: self.edited,
-            notes: self.notes,
-            expiration_date: self.expiration_date,
+            notes,
+            expiration_date,
             expired: self.expired,
             edited: Some(self.edited),
             csrf_token: csrf_token.as_ref().unwrap_or_else(|| unreachable!()),
@@ -102,9 +163,88 @@ impl<'a> SingleRequest<EditedOverride, EditedOverride> for OverrideEditor<'a> {
 }
 
 
+/// data type wrapping all mandatory parameters for expiring a buildroot override
+///
+/// The bodhi server does not provide a separate endpoint for expiring buildroot overrides, so this
+/// sends an edit request that leaves notes and expiration date unchanged and marks the override as
+/// expired. Expirers that were constructed with [`OverrideExpirer::from_nvr`] need to be passed to
+/// [`BodhiClient::expire_override`], which fetches the current values of these parameters first.
+///
+/// ```
+/// use bodhi::OverrideExpirer;
+///
+/// let expirer = OverrideExpirer::from_nvr("wingpanel-2.2.1-1.fc28");
+/// // let expired = bodhi.expire_override(&expirer).await.unwrap();
+/// ```
+#[derive(Debug)]
+pub struct OverrideExpirer<'a> {
+    editor: OverrideEditor<'a>,
+}
+
+impl<'a> OverrideExpirer<'a> {
+    /// constructor for [`OverrideExpirer`] from an existing [`Override`] value
+    pub fn from_override(over_ride: &'a Override) -> Self {
+        OverrideExpirer {
+            editor: OverrideEditor::from_override(over_ride).expired(true),
+        }
+    }
+
+    /// constructor for [`OverrideExpirer`] from the NVR of an existing buildroot override
+    ///
+    /// Expirers constructed with this method need to be submitted with
+    /// [`BodhiClient::expire_override`], which resolves notes and expiration date from the
+    /// existing buildroot override.
+    pub fn from_nvr(nvr: &'a str) -> Self {
+        OverrideExpirer {
+            editor: OverrideEditor::from_nvr(nvr).expired(true),
+        }
+    }
+
+    // editor for the edit request that expires the buildroot override
+    pub(crate) fn editor(&self) -> &OverrideEditor<'a> {
+        &self.editor
+    }
+}
+
+impl<'a> SingleRequest<EditedOverride, Override> for OverrideExpirer<'a> {
+    fn method(&self) -> RequestMethod {
+        RequestMethod::POST
+    }
+
+    fn path(&self) -> Result<String, QueryError> {
+        self.editor.path()
+    }
+
+    fn body(&self, csrf_token: Option<String>) -> Result<Option<String>, QueryError> {
+        if !self.editor.is_resolved() {
+            return Err(QueryError::InvalidDataError {
+                error: String::from(
+                    "Notes and expiration date of the override are unknown, use BodhiClient::expire_override instead.",
+                ),
+            });
+        }
+
+        self.editor.body(csrf_token)
+    }
+
+    fn parse(&self, string: &str) -> Result<EditedOverride, QueryError> {
+        self.editor.parse(string)
+    }
+
+    fn extract(&self, page: EditedOverride) -> Override {
+        page.over_ride
+    }
+}
+
+
 impl Override {
     /// constructor for [`OverrideEditor`] which takes the NVR from an existing [`Override`]
     pub fn edit(&self) -> OverrideEditor {
         OverrideEditor::from_override(self)
     }
+
+    /// constructor for [`OverrideExpirer`] which takes the NVR from an existing [`Override`]
+    pub fn expire(&self) -> OverrideExpirer<'_> {
+        OverrideExpirer::from_override(self)
+    }
 }
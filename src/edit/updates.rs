@@ -1,10 +1,21 @@
 use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
 
 use serde::{Deserialize, Serialize};
 
-use crate::data::{Update, UpdateData, UpdateRequest, UpdateSeverity, UpdateSuggestion, UpdateType};
+use crate::data::{
+    FedoraRelease,
+    Update,
+    UpdateData,
+    UpdateRequest,
+    UpdateSeverity,
+    UpdateStatus,
+    UpdateSuggestion,
+    UpdateType,
+};
 use crate::error::QueryError;
-use crate::request::{RequestMethod, SingleRequest};
+use crate::query::encode_segment;
+use crate::request::{ConflictCheck, RequestMethod, SingleRequest};
 
 /// data of this type is returned after successfully editing an [`Update`]
 #[derive(Debug, Deserialize)]
@@ -22,17 +33,29 @@ pub struct EditedUpdate {
 }
 
 
+// check whether a string is a syntactically valid NVR (name-version-release)
+fn is_valid_nvr(nvr: &str) -> bool {
+    let mut parts = nvr.rsplitn(3, '-');
+    !nvr.chars().any(char::is_whitespace) && parts.all(|part| !part.is_empty()) && nvr.matches('-').count() >= 2
+}
+
+
 /// data type wrapping all mandatory and optional parameters for editing an update
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/updates.html#service-2-POST>
 #[derive(Debug)]
 pub struct UpdateEditor<'a> {
+    // original update and flag for checking for conflicting changes before submitting the edit
+    update: &'a Update,
+    check_conflicts: bool,
+
     // mandatory fields
     builds: Vec<&'a str>,
     notes: &'a str,
 
     // optional fields
     bugs: Vec<u32>,
+    from_tag: Option<&'a str>,
     display_name: Option<&'a str>,
     close_bugs: Option<bool>,
     update_type: Option<UpdateType>,
@@ -51,13 +74,23 @@ pub struct UpdateEditor<'a> {
 }
 
 impl<'a> UpdateEditor<'a> {
-    /// constructor for [`UpdateEditor`] from an existing [`Update`]] value
+    /// constructor for [`UpdateEditor`] from an existing [`Update`] value
+    ///
+    /// All editable fields of the update are carried over, so only fields that are explicitly
+    /// changed with the methods of the editor are modified when the edit is submitted. For updates
+    /// that were created from a side tag, the side tag is submitted instead of the list of builds.
+    /// The [`TryFrom<&Update>`] implementation additionally checks whether the update can be edited
+    /// at all.
     pub fn from_update(update: &'a Update) -> Self {
         UpdateEditor {
+            update,
+            check_conflicts: false,
+
             builds: update.builds.iter().map(|b| b.nvr.as_str()).collect(),
             notes: &update.notes,
 
             bugs: update.bugs.iter().map(|bug| bug.bug_id).collect(),
+            from_tag: update.from_tag.as_deref(),
             display_name: Some(&update.display_name),
             close_bugs: Some(update.close_bugs),
             update_type: Some(update.update_type),
@@ -93,6 +126,67 @@ impl<'a> UpdateEditor<'a> {
         self
     }
 
+    /// method for adding multiple builds to the update
+    ///
+    /// Builds that are already part of the update are not added again. The NVRs of all builds are
+    /// validated when the edit is submitted, and invalid NVRs result in a
+    /// [`QueryError::InvalidDataError`].
+    #[must_use]
+    pub fn add_builds(mut self, builds: &[&'a str]) -> Self {
+        for build in builds {
+            if !self.builds.contains(build) {
+                self.builds.push(build);
+            }
+        }
+        self
+    }
+
+    /// method for removing multiple builds from the update
+    #[must_use]
+    pub fn remove_builds(mut self, builds: &[&str]) -> Self {
+        self.builds.retain(|b| !builds.contains(b));
+        self
+    }
+
+    /// method for replacing a build of the update with a different build
+    ///
+    /// This is intended for replacing builds with newer builds of the same package. The new build
+    /// takes the position of the old build in the list of builds. If the old build is not part of
+    /// the update, the new build is added at the end of the list instead.
+    #[must_use]
+    pub fn replace_build(mut self, old: &str, new: &'a str) -> Self {
+        match self.builds.iter().position(|b| *b == old) {
+            Some(pos) => self.builds[pos] = new,
+            None => self.builds.push(new),
+        }
+
+        // remove any other occurrences of the new build
+        let mut seen = false;
+        self.builds.retain(|b| *b != new || !std::mem::replace(&mut seen, true));
+        self
+    }
+
+    /// method for checking whether submitting this edit will reset the karma of the update
+    ///
+    /// The bodhi server resets the karma of updates when their list of builds is changed. This
+    /// method compares the list of builds of the edited update with the builds of the original
+    /// update. A warning is logged when an edit that resets non-zero karma is submitted.
+    pub fn resets_karma(&self) -> bool {
+        if self.from_tag.is_some() {
+            return false;
+        }
+
+        let mut original: Vec<&str> = self.update.builds.iter().map(|b| b.nvr.as_str()).collect();
+        original.sort_unstable();
+        original.dedup();
+
+        let mut edited = self.builds.clone();
+        edited.sort_unstable();
+        edited.dedup();
+
+        original != edited
+    }
+
     /// method for changing the update notes
     #[must_use]
     pub fn notes(mut self, notes: &'a str) -> Self {
@@ -207,6 +301,18 @@ impl<'a> UpdateEditor<'a> {
         self.stable_days = Some(stable_days);
         self
     }
+
+    /// method for opting into checking for conflicting changes before submitting the edit
+    ///
+    /// If enabled, the update is queried again before the edit is submitted, and its
+    /// `date_modified` and `version_hash` values are compared with those of the [`Update`] this
+    /// editor was constructed from. If the update was modified on the server in the meantime, the
+    /// edit is not submitted, and a [`QueryError::ConflictError`] is returned instead.
+    #[must_use]
+    pub fn check_conflicts(mut self, check_conflicts: bool) -> Self {
+        self.check_conflicts = check_conflicts;
+        self
+    }
 }
 
 impl<'a> SingleRequest<EditedUpdate, EditedUpdate> for UpdateEditor<'a> {
@@ -241,12 +347,48 @@ impl<'a> SingleRequest<EditedUpdate, EditedUpdate> for UpdateEditor<'a> {
             });
         }
 
+        // builds that were added more than once are only submitted once
+        let mut builds: Vec<&str> = Vec::with_capacity(self.builds.len());
+        for build in &self.builds {
+            if !builds.contains(build) {
+                builds.push(build);
+            }
+        }
+
+        if self.from_tag.is_none() {
+            if builds.is_empty() {
+                return Err(QueryError::InvalidDataError {
+                    error: String::from("Updates must contain at least one build."),
+                });
+            }
+
+            if let Some(invalid) = builds.iter().find(|build| !is_valid_nvr(build)) {
+                return Err(QueryError::InvalidDataError {
+                    error: format!("Invalid build NVR: '{invalid}'."),
+                });
+            }
+        }
+
+        if self.resets_karma() {
+            if let Some(karma) = self.update.karma.filter(|karma| *karma != 0) {
+                log::warn!(
+                    "Update {}: Changing the list of builds will reset the current karma ({}).",
+                    self.update.alias,
+                    karma
+                );
+            }
+        }
+
         let bugs: Vec<String> = self.bugs.iter().map(|b| format!("{b}")).collect();
         let bug_refs: Vec<&str> = bugs.iter().map(|s| s.as_str()).collect();
 
         let update_edit = UpdateData {
-            builds: Some(&self.builds),
-            from_tag: None,
+            // builds of updates from side tags are determined by the tag
+            builds: match self.from_tag {
+                Some(_) => None,
+                None => Some(&builds),
+            },
+            from_tag: self.from_tag,
             bugs: Some(bug_refs.as_slice()),
             display_name: self.display_name,
             close_bugs: self.close_bugs,
@@ -286,6 +428,32 @@ impl<'a> SingleRequest<EditedUpdate, EditedUpdate> for UpdateEditor<'a> {
     fn extract(&self, page: EditedUpdate) -> EditedUpdate {
         page
     }
+
+    fn conflict_check(&self) -> Option<ConflictCheck<'_>> {
+        if self.check_conflicts {
+            Some(ConflictCheck::from_update(self.update))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Update> for UpdateEditor<'a> {
+    type Error = QueryError;
+
+    /// constructor for [`UpdateEditor`] from an existing [`Update`] value
+    ///
+    /// This fails with a [`QueryError::InvalidDataError`] for updates that are currently locked
+    /// (i.e. while they are being pushed), since they cannot be edited.
+    fn try_from(update: &'a Update) -> Result<Self, Self::Error> {
+        if update.locked {
+            return Err(QueryError::InvalidDataError {
+                error: format!("Update {} is locked and cannot be edited.", update.alias),
+            });
+        }
+
+        Ok(UpdateEditor::from_update(update))
+    }
 }
 
 
@@ -295,11 +463,50 @@ pub struct RequestedUpdate {
 }
 
 
+/// caveats for update status requests which are valid, but might not have the expected effect
+///
+/// Values of this type are returned by [`UpdateStatusRequester::caveats`], and are also logged as
+/// warnings when the request is submitted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum UpdateRequestCaveat {
+    /// stable request for an update for a frozen release
+    ///
+    /// The update will only be pushed to stable after the freeze has ended, unless it was granted
+    /// a freeze exception.
+    FrozenRelease {
+        /// release of the update
+        release: FedoraRelease,
+    },
+}
+
+impl Display for UpdateRequestCaveat {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            UpdateRequestCaveat::FrozenRelease { release } => write!(
+                f,
+                "Release {release} is frozen, the update will only be pushed to stable after the freeze has ended."
+            ),
+        }
+    }
+}
+
+
 /// data type wrapping all mandatory arguments for creating a request to change an update status
+///
+/// Before the request is sent, it is checked whether the requested change is valid for the
+/// current status of the update (see [`Update::allowed_requests`]). Invalid requests result in a
+/// [`QueryError::InvalidDataError`] that lists the allowed requests. Valid requests that might
+/// not have the expected effect (for example, stable requests for updates for frozen releases)
+/// are listed by [`UpdateStatusRequester::caveats`].
 #[derive(Debug)]
 pub struct UpdateStatusRequester<'a> {
     alias: &'a str,
+    status: UpdateStatus,
+    allowed: Vec<UpdateRequest>,
     request: UpdateRequest,
+    release: &'a FedoraRelease,
+    frozen: bool,
 }
 
 impl<'a> UpdateStatusRequester<'a> {
@@ -307,9 +514,26 @@ impl<'a> UpdateStatusRequester<'a> {
     pub fn from_update(update: &'a Update, request: UpdateRequest) -> Self {
         UpdateStatusRequester {
             alias: &update.alias,
+            status: update.status,
+            allowed: update.allowed_requests(),
             request,
+            release: &update.release.name,
+            frozen: update.release.is_frozen(),
         }
     }
+
+    /// method for checking whether this request might not have the expected effect
+    pub fn caveats(&self) -> Vec<UpdateRequestCaveat> {
+        let mut caveats = Vec::new();
+
+        if self.request == UpdateRequest::Stable && self.frozen {
+            caveats.push(UpdateRequestCaveat::FrozenRelease {
+                release: self.release.clone(),
+            });
+        }
+
+        caveats
+    }
 }
 
 impl<'a> SingleRequest<RequestedUpdate, Update> for UpdateStatusRequester<'a> {
@@ -318,7 +542,7 @@ impl<'a> SingleRequest<RequestedUpdate, Update> for UpdateStatusRequester<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
-        Ok(format!("/updates/{}/request", &self.alias))
+        Ok(format!("/updates/{}/request", encode_segment(self.alias)?))
     }
 
     fn body(&self, csrf_token: Option<String>) -> Result<Option<String>, QueryError> {
@@ -328,6 +552,26 @@ impl<'a> SingleRequest<RequestedUpdate, Update> for UpdateStatusRequester<'a> {
             csrf_token: &'a str,
         }
 
+        if !self.allowed.contains(&self.request) {
+            let allowed = if self.allowed.is_empty() {
+                String::from("none")
+            } else {
+                let allowed: Vec<String> = self.allowed.iter().map(|r| r.to_string()).collect();
+                allowed.join(", ")
+            };
+
+            return Err(QueryError::InvalidDataError {
+                error: format!(
+                    "Cannot request '{}' for update {} with status '{}' (allowed requests: {}).",
+                    self.request, self.alias, self.status, allowed
+                ),
+            });
+        }
+
+        for caveat in self.caveats() {
+            log::warn!("Update {}: {}", self.alias, caveat);
+        }
+
         let request_edit = RequestEdit {
             request: self.request,
             csrf_token: csrf_token.as_ref().unwrap_or_else(|| unreachable!()),
@@ -349,6 +593,87 @@ impl<'a> SingleRequest<RequestedUpdate, Update> for UpdateStatusRequester<'a> {
 }
 
 
+/// data type wrapping all mandatory arguments for creating a request to unpush an update
+///
+/// The bodhi server does not provide an endpoint for deleting updates. Instead, updates that are
+/// currently in testing can be unpushed: bodhi removes the builds of the update from the testing
+/// tags, and sets the status of the update to [`UpdateStatus::Unpushed`]. Unpushed updates can be
+/// edited and pushed to testing again later. Updates that were not pushed to testing yet can be
+/// obsoleted with an [`UpdateStatusRequester`] instead.
+///
+/// Before the request is sent, it is checked whether the update can be unpushed. Updates that are
+/// not in testing, or that are currently locked (i.e. while they are being pushed), result in a
+/// [`QueryError::InvalidDataError`].
+#[derive(Debug)]
+pub struct UpdateUntagger<'a> {
+    alias: &'a str,
+    status: UpdateStatus,
+    locked: bool,
+}
+
+impl<'a> UpdateUntagger<'a> {
+    /// constructor for [`UpdateUntagger`] from an existing [`Update`] value
+    pub fn from_update(update: &'a Update) -> Self {
+        UpdateUntagger {
+            alias: &update.alias,
+            status: update.status,
+            locked: update.locked,
+        }
+    }
+}
+
+impl<'a> SingleRequest<RequestedUpdate, Update> for UpdateUntagger<'a> {
+    fn method(&self) -> RequestMethod {
+        RequestMethod::POST
+    }
+
+    fn path(&self) -> Result<String, QueryError> {
+        Ok(format!("/updates/{}/request", encode_segment(self.alias)?))
+    }
+
+    fn body(&self, csrf_token: Option<String>) -> Result<Option<String>, QueryError> {
+        #[derive(Serialize)]
+        struct RequestEdit<'a> {
+            request: UpdateRequest,
+            csrf_token: &'a str,
+        }
+
+        if self.locked {
+            return Err(QueryError::InvalidDataError {
+                error: format!("Update {} is locked and cannot be unpushed.", self.alias),
+            });
+        }
+
+        if !self.status.allowed_requests().contains(&UpdateRequest::Unpush) {
+            return Err(QueryError::InvalidDataError {
+                error: format!(
+                    "Cannot unpush update {} with status '{}' (only updates in testing can be unpushed).",
+                    self.alias, self.status
+                ),
+            });
+        }
+
+        let request_edit = RequestEdit {
+            request: UpdateRequest::Unpush,
+            csrf_token: csrf_token.as_ref().unwrap_or_else(|| unreachable!()),
+        };
+
+        Ok(Some(
+            serde_json::to_string(&request_edit).map_err(|error| QueryError::SerializationError { error })?,
+        ))
+    }
+
+    fn parse(&self, string: &str) -> Result<RequestedUpdate, QueryError> {
+        let requested_update: RequestedUpdate = serde_json::from_str(string)?;
+        Ok(requested_update)
+    }
+
+    fn extract(&self, page: RequestedUpdate) -> Update {
+        page.update
+    }
+}
+
+
 #[derive(Debug, Deserialize)]
 pub struct WaivedUpdate {
     update: Update,
@@ -389,7 +714,7 @@ impl<'a> SingleRequest<WaivedUpdate, Update> for UpdateTestResultWaiver<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
-        Ok(format!("/updates/{}/waive-test-results", &self.alias))
+        Ok(format!("/updates/{}/waive-test-results", encode_segment(self.alias)?))
     }
 
     fn body(&self, csrf_token: Option<String>) -> Result<Option<String>, QueryError> {
@@ -439,4 +764,9 @@ impl Update {
     pub fn waive<'a>(&'a self, comment: &'a str) -> UpdateTestResultWaiver<'a> {
         UpdateTestResultWaiver::from_update(self, comment)
     }
+
+    /// constructor for [`UpdateUntagger`] which takes parameters from an existing [`Update`]
+    pub fn untag(&self) -> UpdateUntagger<'_> {
+        UpdateUntagger::from_update(self)
+    }
 }
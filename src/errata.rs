@@ -0,0 +1,182 @@
+//! # errata emails for updates
+//!
+//! This module contains functions for rendering updates into the format of the errata emails that
+//! are sent to the Fedora mailing lists when updates are pushed to testing or stable. This makes
+//! it possible to generate the same notifications locally, for example, for notification systems
+//! that do not subscribe to the mailing lists.
+//!
+//! The format corresponds to the `fedora_errata_template` email template of the bodhi server (see
+//! [`Release::mail_template`]). Some information that is included in the emails sent by bodhi is
+//! read from the RPM headers of the included packages (package summaries, descriptions, upstream
+//! URLs, and changelogs), which is not available from the bodhi API. These fields are omitted from
+//! rendered errata.
+//!
+//! ```
+//! use bodhi::errata::render;
+//! # use bodhi::mock::MockServer;
+//! use bodhi::Update;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+//! let update = Update::fetch(&bodhi, "FEDORA-2024-1a2b3c4d5e")
+//!     .await
+//!     .unwrap();
+//!
+//! let errata = render(&update);
+//! assert_eq!(errata.subject, "Fedora 38 Test Update: foo-1.2.3-1.fc38");
+//! # }
+//! ```
+
+use std::fmt::{Display, Formatter, Write};
+
+use crate::data::{split_nvr, PackageManager, Update, UpdateStatus, UpdateType};
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::data::Release;
+
+// separator between sections of errata emails
+const SEPARATOR: &str = "--------------------------------------------------------------------------------";
+
+
+/// data type that represents a rendered errata email
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Errata {
+    /// subject line of the email
+    pub subject: String,
+    /// plain-text body of the email
+    pub body: String,
+}
+
+impl Display for Errata {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        writeln!(f, "Subject: {}", self.subject)?;
+        writeln!(f)?;
+        write!(f, "{}", self.body)
+    }
+}
+
+
+/// render the errata email for the given update
+///
+/// Updates that are currently in testing are announced as "Test Update", and security updates are
+/// marked with a `[SECURITY]` prefix in the subject line. Instructions for installing the update
+/// are based on the package manager of the release of the update.
+pub fn render(update: &Update) -> Errata {
+    Errata {
+        subject: subject(update),
+        body: body(update).expect("Failed to format errata, this should not happen."),
+    }
+}
+
+fn subject(update: &Update) -> String {
+    let security = if update.update_type == UpdateType::Security {
+        "[SECURITY] "
+    } else {
+        ""
+    };
+
+    format!(
+        "{}{}{} Update: {}",
+        security,
+        update.release.long_name,
+        testing(update),
+        update.title
+    )
+}
+
+fn testing(update: &Update) -> &'static str {
+    if update.status == UpdateStatus::Testing {
+        " Test"
+    } else {
+        ""
+    }
+}
+
+fn body(update: &Update) -> Result<String, std::fmt::Error> {
+    let mut body = String::new();
+
+    writeln!(body, "{SEPARATOR}")?;
+    writeln!(body, "Fedora{} Update Notification", testing(update))?;
+    writeln!(body, "{}", update.alias)?;
+    if let Some(date) = update.date_pushed.as_ref().or(update.date_submitted.as_ref()) {
+        writeln!(body, "{date}")?;
+    }
+    writeln!(body, "{SEPARATOR}")?;
+    writeln!(body)?;
+
+    for build in &update.builds {
+        match split_nvr(&build.nvr) {
+            Some((name, version, release)) => {
+                writeln!(body, "Name        : {name}")?;
+                writeln!(body, "Product     : {}", update.release.long_name)?;
+                writeln!(body, "Version     : {version}")?;
+                writeln!(body, "Release     : {release}")?;
+            },
+            None => {
+                writeln!(body, "Name        : {}", build.nvr)?;
+                writeln!(body, "Product     : {}", update.release.long_name)?;
+            },
+        }
+        writeln!(body)?;
+    }
+
+    writeln!(body, "{SEPARATOR}")?;
+
+    if !update.notes.trim().is_empty() {
+        writeln!(body, "Update Information:")?;
+        writeln!(body)?;
+        writeln!(body, "{}", update.notes.trim())?;
+        writeln!(body, "{SEPARATOR}")?;
+    }
+
+    if !update.bugs.is_empty() {
+        writeln!(body, "References:")?;
+        writeln!(body)?;
+        for (index, bug) in update.bugs.iter().enumerate() {
+            writeln!(
+                body,
+                "  [ {} ] Bug #{} - {}",
+                index + 1,
+                bug.bug_id,
+                bug.title.as_deref().unwrap_or_default()
+            )?;
+            writeln!(body, "        {}", bug.url())?;
+        }
+        writeln!(body, "{SEPARATOR}")?;
+    }
+
+    writeln!(body)?;
+    write!(body, "{}", instructions(update))?;
+    writeln!(body)?;
+    writeln!(
+        body,
+        "All packages are signed with the Fedora Project GPG key. More details on the"
+    )?;
+    writeln!(body, "GPG keys used by the Fedora Project can be found at")?;
+    writeln!(body, "https://fedoraproject.org/keys")?;
+    writeln!(body, "{SEPARATOR}")?;
+
+    Ok(body)
+}
+
+fn instructions(update: &Update) -> String {
+    match update.release.package_manager {
+        PackageManager::YUM => format!(
+            "This update can be installed with the \"yum\" update program. Use\n\
+             su -c 'yum update --advisory {}' at the command line.\n\
+             For more information, refer to \"Managing Software with yum\",\n\
+             available at https://docs.fedoraproject.org/yum/.\n",
+            update.alias
+        ),
+        _ => format!(
+            "This update can be installed with the \"dnf\" update program. Use\n\
+             su -c 'dnf upgrade --advisory {}' at the command line.\n\
+             For more information, refer to the dnf documentation available at\n\
+             http://dnf.readthedocs.io/en/latest/command_ref.html#upgrade-command-label\n",
+            update.alias
+        ),
+    }
+}
@@ -4,10 +4,25 @@
 //! server-side issues, and client-side issues (including JSON deserialization problems).
 
 use std::collections::HashMap;
+use std::time::Duration;
 
-use fedora::reqwest;
-use fedora::url;
+use reqwest::Response;
 use serde::Deserialize;
+use url::Url;
+
+use crate::client::RequestId;
+use crate::data::{BodhiDate, UpdateStatus};
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::client::{BodhiClient, BodhiClientBuilder};
+#[cfg(doc)]
+use crate::create::{CommentCreator, DuplicateKarma};
+#[cfg(doc)]
+use crate::edit::UpdateEditor;
+
+// maximum length of response body excerpts that are included in errors (in bytes)
+const BODY_EXCERPT_LENGTH: usize = 1024;
 
 /// error type representing an error message that was returned from a bodhi server
 ///
@@ -28,15 +43,169 @@ impl std::fmt::Display for BodhiError {
 }
 
 
+/// data type containing information about the server response that caused an error
+///
+/// This includes the final URL of the request (after following redirects), the HTTP status code,
+/// values of selected response headers, and a (truncated) excerpt of the response body, which are
+/// useful for diagnosing failures.
+#[derive(Clone, Debug)]
+pub struct ResponseInfo {
+    url: Url,
+    status: u16,
+    retry_after: Option<String>,
+    request_id: Option<String>,
+    client_request_id: Option<String>,
+    app_identity: Option<String>,
+    body_excerpt: Option<String>,
+}
+
+impl ResponseInfo {
+    pub(crate) fn from_response(response: &Response) -> Self {
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from)
+        };
+
+        let request_id = response.extensions().get::<RequestId>();
+
+        ResponseInfo {
+            url: response.url().clone(),
+            status: response.status().as_u16(),
+            retry_after: header("Retry-After"),
+            request_id: header("X-Request-Id"),
+            client_request_id: request_id.map(|request_id| request_id.as_str().to_owned()),
+            app_identity: request_id.and_then(RequestId::app_identity).map(String::from),
+            body_excerpt: None,
+        }
+    }
+
+    pub(crate) fn with_body(mut self, body: &str) -> Self {
+        let mut end = body.len().min(BODY_EXCERPT_LENGTH);
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        self.body_excerpt = Some(body[..end].to_string());
+        self
+    }
+
+    /// final URL of the request (after following redirects)
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// HTTP status code of the server response
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// value of the `Retry-After` header of the server response (if present)
+    pub fn retry_after(&self) -> Option<&str> {
+        self.retry_after.as_deref()
+    }
+
+    /// value of the `X-Request-Id` header of the server response (if present)
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    /// ID that was generated for the request and sent to the server (if available)
+    ///
+    /// See [`BodhiClientBuilder::request_id_header`] for details.
+    pub fn client_request_id(&self) -> Option<&str> {
+        self.client_request_id.as_deref()
+    }
+
+    /// identity of the application that sent the request (if set)
+    ///
+    /// See [`BodhiClientBuilder::app_identity`] for details.
+    pub fn app_identity(&self) -> Option<&str> {
+        self.app_identity.as_deref()
+    }
+
+    /// excerpt of the response body (truncated to at most 1024 bytes, if available)
+    pub fn body_excerpt(&self) -> Option<&str> {
+        self.body_excerpt.as_deref()
+    }
+}
+
+
 /// error type representing an error that happened during the execution of a request
+///
+/// For errors that were caused by a server response, information about the response (final URL,
+/// HTTP status code, selected headers, and an excerpt of the response body) is available via the
+/// [`QueryError::response`] method and its shorthand variants.
 #[derive(Debug, thiserror::Error)]
 pub enum QueryError {
     /// request returned an HTTP 404 responses
-    #[error("Not found")]
-    NotFound,
+    #[error("Not found: {}", .response.url)]
+    NotFound {
+        /// information about the server response
+        response: Box<ResponseInfo>,
+    },
+    /// `POST` request failed, but was not retried because its changes were already applied
+    ///
+    /// This error is only returned if retrying `POST` requests was enabled with
+    /// [`BodhiClientBuilder::post_retries`], and if checking whether the request had already been
+    /// applied on the server before retrying it found a matching item.
+    #[error("Request failed, but its changes were already applied on the server: {error}")]
+    AlreadyApplied {
+        /// error that occurred during the last attempt of the request
+        error: Box<QueryError>,
+    },
+    /// edit was not submitted because the item was modified on the server in the meantime
+    ///
+    /// This error is only returned for edits that opted into checking for conflicting changes,
+    /// for example, with [`UpdateEditor::check_conflicts`].
+    #[error("{error}")]
+    ConflictError {
+        /// details about the conflicting changes
+        error: Box<ConflictError>,
+    },
+    /// comment was not submitted because the user already submitted karma for the update
+    ///
+    /// This error is only returned for comments that opted into checking for existing karma with
+    /// [`CommentCreator::duplicate_karma`] and [`DuplicateKarma::Reject`].
+    #[error("User {user} already submitted karma for update {update}")]
+    AlreadyCommented {
+        /// alias of the update
+        update: String,
+        /// name of the user who already submitted karma
+        user: String,
+    },
     /// request returned an invalid / empty response
-    #[error("Invalid / empty server response")]
-    EmptyResponse,
+    #[error("Invalid / empty server response: {}", .response.url)]
+    EmptyResponse {
+        /// information about the server response
+        response: Box<ResponseInfo>,
+    },
+    /// server response exceeded the maximum allowed size
+    ///
+    /// The maximum sizes of responses can be configured with
+    /// [`BodhiClientBuilder::max_response_size`] and
+    /// [`BodhiClientBuilder::max_paginated_response_size`].
+    #[error("Server response exceeded the maximum size of {limit} bytes: {}", .response.url)]
+    ResponseTooLarge {
+        /// maximum allowed size of the response (in bytes)
+        limit: usize,
+        /// information about the server response
+        response: Box<ResponseInfo>,
+    },
+    /// server is temporarily unavailable (for example, during maintenance windows or outages)
+    ///
+    /// This error is returned for HTTP 502 and 503 responses, and for responses that are not
+    /// JSON (for example, HTML error pages that are returned by proxies). Failed requests are
+    /// retried after the duration requested by the server (if any).
+    #[error("Service is temporarily unavailable: {}", .response.url)]
+    ServiceUnavailable {
+        /// duration after which the request can be retried (parsed from the `Retry-After` header)
+        retry_after: Option<Duration>,
+        /// information about the server response
+        response: Box<ResponseInfo>,
+    },
     /// request failed due to networking issues
     #[error("Failed to query bodhi service: {error}")]
     RequestError {
@@ -51,6 +220,8 @@ pub enum QueryError {
     DeserializationError {
         /// error returned by [`serde_json`]
         error: serde_json::Error,
+        /// information about the server response (if available)
+        response: Option<Box<ResponseInfo>>,
     },
     /// failure to serialize JSON request data
     ///
@@ -72,6 +243,8 @@ pub enum QueryError {
     BodhiError {
         /// error returned by the remove server
         error: BodhiError,
+        /// information about the server response
+        response: Box<ResponseInfo>,
     },
     /// failure to serialize x-www-urlencoded request string
     #[error("Failed to construct `x-www-urlencoded` query string: {error}")]
@@ -80,18 +253,399 @@ pub enum QueryError {
         #[from]
         error: serde_url_params::Error,
     },
+    /// query parameter value outside the range of values that is accepted by the server
+    #[error("Invalid value for query parameter '{name}': {value} (valid range: {min}..={max})")]
+    InvalidParameterError {
+        /// name of the invalid query parameter
+        name: &'static str,
+        /// invalid value of the query parameter
+        value: u32,
+        /// minimum value that is accepted by the server
+        min: u32,
+        /// maximum value that is accepted by the server
+        max: u32,
+    },
     /// failure to validate input data
     #[error("Invalid data: {error}")]
     InvalidDataError {
         /// reason why data was considered invalid
         error: String,
     },
+    /// request requires an authenticated client, but the client is not authenticated
+    #[error("Client is not authenticated")]
+    NotAuthenticated,
+    /// failure to obtain an access token for authenticating the request
+    ///
+    /// This error is only returned by clients that authenticate with OpenID Connect (see
+    /// [`BodhiClientBuilder::oidc_auth`]), if the access token expired and requesting a new one
+    /// failed.
+    #[error("Failed to obtain access token: {error}")]
+    AuthenticationError {
+        /// error that occurred while requesting the access token
+        #[from]
+        error: LoginError,
+    },
 }
 
 // The #[from] attribute for thiserror::Error can not be used for serde_json::Error, as there's two
 // errors with this same inner error type.
 impl From<serde_json::Error> for QueryError {
     fn from(error: serde_json::Error) -> Self {
-        QueryError::DeserializationError { error }
+        QueryError::DeserializationError { error, response: None }
+    }
+}
+
+impl QueryError {
+    // attach information about the server response to errors that do not contain it yet
+    pub(crate) fn with_response(self, info: ResponseInfo) -> Self {
+        match self {
+            QueryError::DeserializationError { error, response: None } => QueryError::DeserializationError {
+                error,
+                response: Some(Box::new(info)),
+            },
+            other => other,
+        }
+    }
+
+    /// information about the server response that caused this error (if available)
+    pub fn response(&self) -> Option<&ResponseInfo> {
+        match self {
+            QueryError::NotFound { response } => Some(response),
+            QueryError::EmptyResponse { response } => Some(response),
+            QueryError::ResponseTooLarge { response, .. } => Some(response),
+            QueryError::ServiceUnavailable { response, .. } => Some(response),
+            QueryError::DeserializationError { response, .. } => response.as_deref(),
+            QueryError::BodhiError { response, .. } => Some(response),
+            QueryError::AlreadyApplied { error } => error.response(),
+            _ => None,
+        }
+    }
+
+    /// URL of the request that caused this error (if available)
+    pub fn url(&self) -> Option<&Url> {
+        match self {
+            QueryError::RequestError { error } => error.url(),
+            QueryError::AlreadyApplied { error } => error.url(),
+            _ => self.response().map(|response| response.url()),
+        }
+    }
+
+    /// HTTP status code of the server response that caused this error (if available)
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            QueryError::RequestError { error } => error.status().map(|status| status.as_u16()),
+            QueryError::AlreadyApplied { error } => error.status(),
+            _ => self.response().map(|response| response.status()),
+        }
+    }
+
+    /// value of the `Retry-After` header of the server response (if available)
+    pub fn retry_after(&self) -> Option<&str> {
+        self.response().and_then(|response| response.retry_after())
+    }
+
+    /// value of the `X-Request-Id` header of the server response (if available)
+    pub fn request_id(&self) -> Option<&str> {
+        self.response().and_then(|response| response.request_id())
+    }
+
+    /// ID that was generated for the request and sent to the server (if available)
+    pub fn client_request_id(&self) -> Option<&str> {
+        self.response().and_then(|response| response.client_request_id())
+    }
+
+    /// identity of the application that sent the request (if set)
+    pub fn app_identity(&self) -> Option<&str> {
+        self.response().and_then(|response| response.app_identity())
+    }
+
+    /// truncated excerpt of the body of the server response (if available)
+    pub fn body_excerpt(&self) -> Option<&str> {
+        self.response().and_then(|response| response.body_excerpt())
+    }
+}
+
+
+/// error type representing conflicting server-side changes to an update that was being edited
+///
+/// The values of the `date_modified` and `version_hash` fields of the update at the time the edit
+/// was prepared are compared with their current values on the server before the edit is
+/// submitted. If they differ, the edit is aborted to avoid overwriting changes by others.
+#[derive(Debug, thiserror::Error)]
+#[error("Update {alias} was modified on the server since it was loaded")]
+pub struct ConflictError {
+    /// alias of the update that was modified
+    pub alias: String,
+    /// time of the last modification when the edit was prepared
+    pub expected_date_modified: Option<BodhiDate>,
+    /// time of the last modification according to the server
+    pub actual_date_modified: Option<BodhiDate>,
+    /// version hash when the edit was prepared
+    pub expected_version_hash: String,
+    /// version hash according to the server
+    pub actual_version_hash: String,
+}
+
+/// error type representing an error that happened while writing query results into a sink
+#[derive(Debug, thiserror::Error)]
+pub enum DownloadError<E> {
+    /// error that occurred while querying results from the server
+    #[error("Failed to query results: {error}")]
+    QueryError {
+        /// error that occurred during the query
+        #[from]
+        error: QueryError,
+    },
+    /// error that occurred while writing results into the sink
+    #[error("Failed to write results: {error}")]
+    SinkError {
+        /// error returned by the sink
+        error: E,
+    },
+}
+
+
+/// error type representing an error that happened while waiting for a change on the server
+#[derive(Debug, thiserror::Error)]
+pub enum WaitError {
+    /// error that occurred while querying the current state
+    #[error("Failed to query current state: {error}")]
+    QueryError {
+        /// error that occurred during the query
+        #[from]
+        error: QueryError,
+    },
+    /// error that occurred because the expected change did not happen in time
+    #[error("Timed out after {} seconds", .timeout.as_secs())]
+    Timeout {
+        /// duration after which waiting was stopped
+        timeout: Duration,
+    },
+    /// error that occurred because the update reached a state from which the expected state can
+    /// no longer be reached
+    #[error("Update {alias} reached unexpected status: {status}")]
+    UnexpectedStatus {
+        /// alias of the update
+        alias: String,
+        /// current status of the update
+        status: UpdateStatus,
+    },
+    /// error that occurred because the buildroot override expired while waiting
+    #[error("Buildroot override for {nvr} has expired")]
+    OverrideExpired {
+        /// NVR of the build for which the buildroot override was created
+        nvr: String,
+    },
+}
+
+
+/// error type representing a failed check of the credentials of an authenticated client
+///
+/// This error is returned by [`BodhiClient::ping_authenticated`].
+#[derive(Debug, thiserror::Error)]
+pub enum PingError {
+    /// client was built without credentials or without a non-expired session
+    #[error("Client is not authenticated")]
+    NotAuthenticated,
+    /// session cookies of the client have expired since the client was built
+    #[error("Session of user {username} has expired")]
+    SessionExpired {
+        /// name of the user the session was authenticated for
+        username: String,
+    },
+    /// server rejected the credentials of the client (or the user does not exist)
+    #[error("Server rejected credentials of user {username}: {error}")]
+    InvalidCredentials {
+        /// name of the user the client is authenticated as
+        username: String,
+        /// error that was returned by the server
+        error: Box<QueryError>,
+    },
+    /// server could not be reached or is temporarily unavailable
+    #[error("Failed to contact bodhi server: {error}")]
+    NetworkError {
+        /// error that occurred during the request
+        error: Box<QueryError>,
+    },
+    /// other error that occurred while checking the credentials
+    #[error("Failed to check credentials: {error}")]
+    QueryError {
+        /// error that occurred during the request
+        #[from]
+        error: QueryError,
+    },
+}
+
+
+/// error type representing an error that happened while parsing query filters from a URL
+#[cfg(feature = "saved-queries")]
+#[derive(Debug, thiserror::Error)]
+pub enum QueryUrlError {
+    /// error that occurred while parsing the URL
+    #[error("Failed to parse URL: {error}")]
+    UrlParsingError {
+        /// error returned by [`url`]
+        #[from]
+        error: url::ParseError,
+    },
+    /// error that occurred because the URL does not point to a list of known entities
+    #[error("Unknown query endpoint: {path}")]
+    UnknownEndpoint {
+        /// path of the URL
+        path: String,
+    },
+    /// error that occurred because a query parameter has an invalid value
+    #[error("Invalid query parameter value: {error}")]
+    InvalidValue {
+        /// error returned by [`serde_json`]
+        #[from]
+        error: serde_json::Error,
+    },
+}
+
+
+/// error type representing an error that happened while exporting or importing data
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    /// error that occurred while reading or writing data
+    #[error("Failed to read or write data: {error}")]
+    IOError {
+        /// error returned by the reader or writer
+        #[from]
+        error: std::io::Error,
+    },
+    /// error that occurred while (de)serializing data
+    #[error("Failed to (de)serialize data: {error}")]
+    JSONError {
+        /// error returned by [`serde_json`]
+        #[from]
+        error: serde_json::Error,
+    },
+    /// error that occurred while writing data into an SQLite database
+    #[cfg(feature = "sqlite")]
+    #[error("Failed to write into database: {error}")]
+    SQLiteError {
+        /// error returned by [`rusqlite`]
+        #[from]
+        error: rusqlite::Error,
+    },
+}
+
+
+/// error type representing an error that happened while logging in with an OpenID provider
+#[derive(Debug, thiserror::Error)]
+pub enum LoginError {
+    /// error that occurred while contacting the OpenID provider or the bodhi server
+    #[error("Failed to contact OpenID provider: {error}")]
+    RequestError {
+        /// error returned by [`reqwest`]
+        #[from]
+        error: reqwest::Error,
+    },
+    /// error that occurred while parsing a URL that was returned by the OpenID provider
+    #[error("Failed to parse redirection URL: {error}")]
+    UrlParsingError {
+        /// error returned by [`url`]
+        #[from]
+        error: url::ParseError,
+    },
+    /// error that occurred due to an invalid HTTP redirect
+    #[error("Invalid HTTP redirect: {error}")]
+    RedirectionError {
+        /// explanation why the redirect was invalid
+        error: String,
+    },
+    /// error that occurred during the authentication flow
+    #[error("Failed to authenticate with OpenID provider: {error}")]
+    AuthenticationError {
+        /// explanation why the authentication request failed
+        error: String,
+    },
+    /// error that occurred because the OpenID provider rejected the supplied credentials
+    #[error("Authentication failed, possibly due to wrong username / password.")]
+    InvalidCredentials,
+}
+
+
+/// error type representing an error that happened while reading or writing session state
+#[derive(Debug, thiserror::Error)]
+pub enum SessionError {
+    /// error that occurred while reading or writing a file
+    #[error("Failed to read or write session state: {error}")]
+    IOError {
+        /// error returned by [`std::io`]
+        #[from]
+        error: std::io::Error,
+    },
+    /// error that occurred while (de)serializing session state
+    #[error("Failed to (de)serialize session state: {error}")]
+    SerializationError {
+        /// error returned by [`serde_json`]
+        #[from]
+        error: serde_json::Error,
+    },
+}
+
+
+/// error type representing an error that happened while starting a mock server
+#[cfg(feature = "mock")]
+#[derive(Debug, thiserror::Error)]
+pub enum MockError {
+    /// error that occurred while binding the server to a local port
+    #[error("Failed to start mock server: {error}")]
+    IOError {
+        /// error returned by [`std::io`]
+        #[from]
+        error: std::io::Error,
+    },
+    /// error that occurred while building a client for the mock server
+    #[error("Failed to build client for mock server: {error}")]
+    BuilderError {
+        /// error returned by [`BodhiClientBuilder::build`]
+        #[from]
+        error: crate::client::BuilderError,
+    },
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn info() -> ResponseInfo {
+        ResponseInfo {
+            url: Url::parse("https://bodhi.fedoraproject.org/updates/FOO").unwrap(),
+            status: 404,
+            retry_after: None,
+            request_id: Some(String::from("1234")),
+            client_request_id: Some(String::from("5678")),
+            app_identity: None,
+            body_excerpt: None,
+        }
+    }
+
+    #[test]
+    fn body_excerpt_truncated() {
+        let body = "ä".repeat(BODY_EXCERPT_LENGTH);
+        let info = info().with_body(&body);
+        let excerpt = info.body_excerpt().unwrap();
+
+        assert_eq!(excerpt.len(), BODY_EXCERPT_LENGTH);
+        assert!(body.starts_with(excerpt));
+    }
+
+    #[test]
+    fn error_accessors() {
+        let error = QueryError::NotFound {
+            response: Box::new(info().with_body("{}")),
+        };
+
+        assert_eq!(error.status(), Some(404));
+        assert_eq!(error.url().unwrap().path(), "/updates/FOO");
+        assert_eq!(error.request_id(), Some("1234"));
+        assert_eq!(error.client_request_id(), Some("5678"));
+        assert_eq!(error.retry_after(), None);
+        assert_eq!(error.body_excerpt(), Some("{}"));
     }
 }
@@ -0,0 +1,47 @@
+//! # helpers for exporting and importing data
+//!
+//! This module contains helpers for writing data in the [ND-JSON](https://github.com/ndjson/ndjson-spec)
+//! / [JSON Lines](https://jsonlines.org/) format (one JSON-encoded item per line), and for reading
+//! it again. The [`ndjson_writer`] function returns a [`Sink`] that can be passed to
+//! [`BodhiClient::download_all`], which makes it possible to write the results of large queries
+//! to disk as they arrive, without keeping them all in memory.
+//!
+//! Results can also be written into other storage backends (for example, databases) in batches by
+//! implementing the [`ResultSink`] trait and passing the sink to [`BodhiClient::export_all`]. With
+//! the `sqlite` feature, this module also contains `SqliteSink`, a reference implementation that
+//! writes results into a table of an SQLite database.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! use std::fs::File;
+//! use std::io::BufWriter;
+//!
+//! use bodhi::{ndjson_writer, BodhiClientBuilder, Update, UpdateQuery};
+//!
+//! let bodhi = BodhiClientBuilder::default().build().await?;
+//!
+//! let file = BufWriter::new(File::create("updates.ndjson")?);
+//! let query = UpdateQuery::new().users(&["decathorpe"]);
+//! bodhi
+//!     .download_all(&query, ndjson_writer::<Update, _>(file))
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::client::BodhiClient;
+#[cfg(doc)]
+use futures_util::Sink;
+
+mod ndjson;
+pub use ndjson::{ndjson_reader, ndjson_writer, NDJSONReader, NDJSONWriter};
+
+mod sink;
+pub use sink::ResultSink;
+
+#[cfg(feature = "sqlite")]
+mod sqlite;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteSink;
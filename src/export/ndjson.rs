@@ -0,0 +1,151 @@
+use std::io::{BufRead, Write};
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::Sink;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::ExportError;
+
+/// sink that writes items into a writer in the ND-JSON format (one JSON-encoded item per line)
+///
+/// Values of this type are constructed with the [`ndjson_writer`] function. Items are serialized
+/// and written as soon as they are sent into the sink. Writes are not buffered, so wrapping the
+/// writer in a [`std::io::BufWriter`] is recommended (for example, for writing into files).
+#[derive(Debug)]
+pub struct NDJSONWriter<T, W> {
+    writer: W,
+    item: PhantomData<fn(T)>,
+}
+
+/// function for constructing a [`NDJSONWriter`] sink that writes into the given writer
+pub fn ndjson_writer<T, W>(writer: W) -> NDJSONWriter<T, W>
+where
+    T: Serialize,
+    W: Write,
+{
+    NDJSONWriter {
+        writer,
+        item: PhantomData,
+    }
+}
+
+impl<T, W> NDJSONWriter<T, W> {
+    /// method for getting the underlying writer back from the sink
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<T, W> Sink<T> for NDJSONWriter<T, W>
+where
+    T: Serialize,
+    W: Write + Unpin,
+{
+    type Error = ExportError;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        serde_json::to_writer(&mut this.writer, &item)?;
+        this.writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(self.get_mut().writer.flush().map_err(ExportError::from))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.poll_flush(cx)
+    }
+}
+
+
+/// iterator that reads items from a reader in the ND-JSON format (one JSON-encoded item per line)
+///
+/// Values of this type are constructed with the [`ndjson_reader`] function. Empty lines are
+/// skipped, and every other line is deserialized into one item.
+#[derive(Debug)]
+pub struct NDJSONReader<T, R> {
+    reader: R,
+    line: String,
+    item: PhantomData<fn() -> T>,
+}
+
+/// function for constructing a [`NDJSONReader`] iterator that reads from the given reader
+pub fn ndjson_reader<T, R>(reader: R) -> NDJSONReader<T, R>
+where
+    T: DeserializeOwned,
+    R: BufRead,
+{
+    NDJSONReader {
+        reader,
+        line: String::new(),
+        item: PhantomData,
+    }
+}
+
+impl<T, R> Iterator for NDJSONReader<T, R>
+where
+    T: DeserializeOwned,
+    R: BufRead,
+{
+    type Item = Result<T, ExportError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+
+            match self.reader.read_line(&mut self.line) {
+                // end of input
+                Ok(0) => return None,
+                Ok(_) => {
+                    if self.line.trim().is_empty() {
+                        continue;
+                    }
+                    return Some(serde_json::from_str(&self.line).map_err(ExportError::from));
+                },
+                Err(error) => return Some(Err(error.into())),
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use futures_util::SinkExt;
+
+    use super::*;
+    use crate::data::{Karma, UpdateStatus};
+
+    #[tokio::test]
+    async fn roundtrip() {
+        let items = vec![
+            (UpdateStatus::Stable, Karma::Positive, String::from("first")),
+            (UpdateStatus::Testing, Karma::Negative, String::from("second\nline")),
+        ];
+
+        let mut writer = ndjson_writer(Vec::new());
+        for item in &items {
+            writer.feed(item).await.unwrap();
+        }
+        writer.close().await.unwrap();
+
+        let output = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(output.lines().count(), 2);
+
+        // empty lines are skipped
+        let input = format!("\n{output}\n");
+        let read: Vec<(UpdateStatus, Karma, String)> =
+            ndjson_reader(input.as_bytes()).collect::<Result<_, _>>().unwrap();
+        assert_eq!(read, items);
+    }
+}
@@ -0,0 +1,45 @@
+use futures_util::future::BoxFuture;
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::client::BodhiClient;
+
+/// trait for alternative output sinks that results of paginated queries are written into in batches
+///
+/// This is the extension point for writing the results of large queries into other storage
+/// backends (for example, databases), and it is used by [`BodhiClient::export_all`]. Every result
+/// page is passed to [`ResultSink::insert_batch`] as one batch, which makes it possible to write
+/// each batch in a single database transaction. After all batches have been inserted successfully,
+/// [`ResultSink::finish`] is called once.
+///
+/// The methods return boxed futures (instead of being `async fn`s), which keeps the trait object
+/// safe and compatible with all supported Rust versions. This trait is considered part of the
+/// stable API of this crate, and new methods will only be added with default implementations.
+///
+/// ```
+/// use bodhi::ResultSink;
+/// use futures_util::future::BoxFuture;
+///
+/// struct Counter(usize);
+///
+/// impl<T: Send + 'static> ResultSink<T> for Counter {
+///     type Error = std::convert::Infallible;
+///
+///     fn insert_batch(&mut self, items: Vec<T>) -> BoxFuture<'_, Result<(), Self::Error>> {
+///         self.0 += items.len();
+///         Box::pin(async { Ok(()) })
+///     }
+/// }
+/// ```
+pub trait ResultSink<T> {
+    /// type of errors that can occur while writing results
+    type Error;
+
+    /// insert one batch of results (usually, all items from one result page)
+    fn insert_batch(&mut self, items: Vec<T>) -> BoxFuture<'_, Result<(), Self::Error>>;
+
+    /// finish writing after all batches have been inserted (does nothing by default)
+    fn finish(&mut self) -> BoxFuture<'_, Result<(), Self::Error>> {
+        Box::pin(async { Ok(()) })
+    }
+}
@@ -0,0 +1,144 @@
+use std::marker::PhantomData;
+
+use futures_util::future::BoxFuture;
+use rusqlite::Connection;
+use serde::Serialize;
+
+use super::ResultSink;
+use crate::error::ExportError;
+
+/// reference implementation of a [`ResultSink`] that writes results into an SQLite table
+///
+/// Every item is stored as one row with a unique key (computed by the function that is passed to
+/// the constructor) and its JSON representation. Rows with an existing key are replaced, so
+/// running the same export repeatedly updates a local mirror of the data instead of duplicating
+/// it. Every batch is written in a single transaction.
+///
+/// Writes are performed synchronously, since SQLite does not provide an asynchronous API. This is
+/// usually not an issue, because writing one batch of results into a local database takes much
+/// less time than fetching it from the server.
+///
+/// ```no_run
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// use bodhi::{BodhiClientBuilder, SqliteSink, Update, UpdateQuery};
+///
+/// let bodhi = BodhiClientBuilder::default().build().await?;
+///
+/// let connection = rusqlite::Connection::open("bodhi.sqlite")?;
+/// let mut sink = SqliteSink::new(connection, "updates", |update: &Update| {
+///     update.alias.clone()
+/// })?;
+///
+/// let query = UpdateQuery::new().users(&["decathorpe"]);
+/// bodhi.export_all(&query, &mut sink).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct SqliteSink<T, K> {
+    connection: Connection,
+    insert: String,
+    key: K,
+    item: PhantomData<fn(T)>,
+}
+
+impl<T, K> std::fmt::Debug for SqliteSink<T, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("SqliteSink")
+            .field("connection", &self.connection)
+            .field("insert", &self.insert)
+            .finish()
+    }
+}
+
+impl<T, K> SqliteSink<T, K>
+where
+    T: Serialize,
+    K: Fn(&T) -> String,
+{
+    /// constructor for [`SqliteSink`] that writes into the table with the given name
+    ///
+    /// The table is created with `key` and `json` columns if it does not exist yet. Table names
+    /// can only contain ASCII letters, digits, and underscores.
+    pub fn new(connection: Connection, table: &str, key: K) -> Result<Self, ExportError> {
+        if table.is_empty() || !table.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(rusqlite::Error::InvalidParameterName(table.to_owned()).into());
+        }
+
+        connection.execute(
+            &format!("CREATE TABLE IF NOT EXISTS {table} (key TEXT PRIMARY KEY NOT NULL, json TEXT NOT NULL)"),
+            (),
+        )?;
+
+        Ok(SqliteSink {
+            connection,
+            insert: format!("INSERT OR REPLACE INTO {table} (key, json) VALUES (?1, ?2)"),
+            key,
+            item: PhantomData,
+        })
+    }
+
+    /// method for getting the underlying database connection back from the sink
+    pub fn into_inner(self) -> Connection {
+        self.connection
+    }
+
+    // write all items in a single transaction
+    fn write(&mut self, items: &[T]) -> Result<(), ExportError> {
+        let transaction = self.connection.transaction()?;
+
+        for item in items {
+            let json = serde_json::to_string(item)?;
+            transaction.execute(&self.insert, ((self.key)(item), json))?;
+        }
+
+        transaction.commit()?;
+        Ok(())
+    }
+}
+
+impl<T, K> ResultSink<T> for SqliteSink<T, K>
+where
+    T: Serialize + Send,
+    K: Fn(&T) -> String + Send,
+{
+    type Error = ExportError;
+
+    fn insert_batch(&mut self, items: Vec<T>) -> BoxFuture<'_, Result<(), Self::Error>> {
+        let result = self.write(&items);
+        Box::pin(async move { result })
+    }
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn insert_batches() {
+        let connection = Connection::open_in_memory().unwrap();
+        let mut sink = SqliteSink::new(connection, "items", |item: &(u32, &str)| item.0.to_string()).unwrap();
+
+        sink.insert_batch(vec![(1, "foo"), (2, "bar")]).await.unwrap();
+        sink.insert_batch(vec![(2, "baz")]).await.unwrap();
+
+        let connection = sink.into_inner();
+        let count: i64 = connection
+            .query_row("SELECT COUNT(*) FROM items", (), |row| row.get(0))
+            .unwrap();
+        let json: String = connection
+            .query_row("SELECT json FROM items WHERE key = ?1", ("2",), |row| row.get(0))
+            .unwrap();
+
+        assert_eq!(count, 2);
+        assert_eq!(json, r#"[2,"baz"]"#);
+    }
+
+    #[test]
+    fn invalid_table_name() {
+        let connection = Connection::open_in_memory().unwrap();
+        let result = SqliteSink::new(connection, "items; DROP TABLE updates", |item: &u32| item.to_string());
+        assert!(matches!(result, Err(ExportError::SQLiteError { .. })));
+    }
+}
@@ -0,0 +1,64 @@
+use crate::client::BodhiClient;
+use crate::data::{Build, FedoraRelease, Override, ReleaseTag, Update, UpdateRequest, UpdateStatus};
+use crate::error::QueryError;
+use crate::query::{BuildQuery, OverrideQuery, UpdateQuery};
+
+impl BodhiClient {
+    /// async method for querying all builds for the given release which have not been signed yet
+    ///
+    /// The bodhi server does not support filtering builds by their signature status, so this
+    /// queries all builds for the release, and filters the results locally. Depending on the
+    /// release, this can result in a large number of requests.
+    pub async fn unsigned_builds_for_release(&self, release: &FedoraRelease) -> Result<Vec<Build>, QueryError> {
+        let releases = [release.clone()];
+        let builds: Vec<Build> = self.paginated_request(&BuildQuery::new().releases(&releases)).await?;
+
+        Ok(builds.into_iter().filter(|build| !build.signed).collect())
+    }
+
+    /// async method for querying all builds for the given release which are expected to be
+    /// tagged into the koji tag of the given kind
+    ///
+    /// The bodhi server does not support filtering builds by koji tags, so the tag is mapped to
+    /// the corresponding query for builds, overrides, or updates (see [`ReleaseTag`] for details).
+    /// The result reflects the state that is known to bodhi, which can briefly differ from the
+    /// actual state of koji tags (for example, while a compose is running).
+    ///
+    /// Builds in the [`ReleaseTag::Candidate`] and [`ReleaseTag::Stable`] tags include all past
+    /// builds for the release, which can result in a large number of requests.
+    pub async fn builds_in_tag(&self, release: &FedoraRelease, tag: ReleaseTag) -> Result<Vec<Build>, QueryError> {
+        let releases = [release.clone()];
+
+        match tag {
+            ReleaseTag::Candidate => self.paginated_request(&BuildQuery::new().releases(&releases)).await,
+            ReleaseTag::Override => {
+                let query = OverrideQuery::new().releases(&releases).expired(false);
+                let overrides: Vec<Override> = self.paginated_request(&query).await?;
+                Ok(overrides.into_iter().map(|o| o.build).collect())
+            },
+            ReleaseTag::PendingSigning => self.unsigned_builds_for_release(release).await,
+            ReleaseTag::PendingStable => {
+                let query = UpdateQuery::new().releases(&releases).request(UpdateRequest::Stable);
+                self.update_builds(&query).await
+            },
+            ReleaseTag::PendingTesting => {
+                let query = UpdateQuery::new().releases(&releases).request(UpdateRequest::Testing);
+                self.update_builds(&query).await
+            },
+            ReleaseTag::Stable => {
+                let query = UpdateQuery::new().releases(&releases).status(UpdateStatus::Stable);
+                self.update_builds(&query).await
+            },
+            ReleaseTag::Testing => {
+                let query = UpdateQuery::new().releases(&releases).status(UpdateStatus::Testing);
+                self.update_builds(&query).await
+            },
+        }
+    }
+
+    // run the query for updates and collect the builds of all results
+    async fn update_builds(&self, query: &UpdateQuery<'_>) -> Result<Vec<Build>, QueryError> {
+        let updates: Vec<Update> = self.paginated_request(query).await?;
+        Ok(updates.into_iter().flat_map(|update| update.builds).collect())
+    }
+}
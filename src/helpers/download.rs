@@ -0,0 +1,173 @@
+use futures_util::future::join;
+use futures_util::{Sink, SinkExt};
+use serde::de::DeserializeOwned;
+
+use crate::client::BodhiClient;
+use crate::error::DownloadError;
+use crate::export::ResultSink;
+use crate::request::{PaginatedRequest, Pagination};
+
+// write all items of one result page into the sink, and wait until the sink has accepted them
+async fn feed_page<S, T>(sink: &mut S, items: impl IntoIterator<Item = T>) -> Result<u64, S::Error>
+where
+    S: Sink<T> + Unpin,
+{
+    let mut count = 0;
+
+    for item in items {
+        sink.feed(item).await?;
+        count += 1;
+    }
+
+    sink.flush().await?;
+    Ok(count)
+}
+
+impl BodhiClient {
+    /// async method for streaming the results of multi-page / paginated `GET` requests into a sink
+    ///
+    /// In contrast to [`BodhiClient::paginated_request`], this method does not collect all results
+    /// in memory before returning them. Instead, the items from every result page are written into
+    /// the given sink as soon as they are available. The next result page is fetched while the
+    /// items of the current page are being written, but no further pages are requested until the
+    /// sink has accepted all items of the current page. This means that at most two result pages
+    /// (in addition to whatever the sink buffers internally) are held in memory at any time.
+    ///
+    /// After all results have been written, the sink is closed, and the total number of items that
+    /// were written into the sink is returned.
+    pub async fn download_all<P, V, T, S>(
+        &self,
+        request: &dyn PaginatedRequest<P, V>,
+        mut sink: S,
+    ) -> Result<u64, DownloadError<S::Error>>
+    where
+        P: Pagination,
+        V: IntoIterator<Item = T> + DeserializeOwned,
+        T: DeserializeOwned,
+        S: Sink<T> + Unpin,
+    {
+        let mut count = 0;
+
+        // initialize progress callback with "zero progress"
+        request.callback(0, 1);
+
+        let mut page = 1u32;
+        let mut page_request = request.page_request(page);
+        let mut current = self.page_request_get(page_request.as_ref()).await?;
+        let mut pages = current.pages();
+
+        // update progress callback with actual total pages
+        request.callback(page, pages);
+
+        while page < pages {
+            let next_request = request.page_request(page + 1);
+
+            // fetch the next page while the items from the current page are written
+            let (next, written) = join(
+                self.page_request_get(next_request.as_ref()),
+                feed_page(&mut sink, page_request.extract(current)),
+            )
+            .await;
+
+            count += written.map_err(|error| DownloadError::SinkError { error })?;
+            current = next?;
+
+            page += 1;
+            pages = current.pages();
+            page_request = next_request;
+
+            request.callback(page, pages);
+        }
+
+        count += feed_page(&mut sink, page_request.extract(current))
+            .await
+            .map_err(|error| DownloadError::SinkError { error })?;
+
+        sink.close().await.map_err(|error| DownloadError::SinkError { error })?;
+
+        Ok(count)
+    }
+
+    /// async method for writing the results of multi-page / paginated `GET` requests into a
+    /// [`ResultSink`] in batches
+    ///
+    /// This works like [`BodhiClient::download_all`], except that all items from every result page
+    /// are passed to [`ResultSink::insert_batch`] as one batch. The next result page is fetched
+    /// while the current batch is being inserted. After all batches have been inserted,
+    /// [`ResultSink::finish`] is called, and the total number of items that were inserted is
+    /// returned.
+    pub async fn export_all<P, V, T, S>(
+        &self,
+        request: &dyn PaginatedRequest<P, V>,
+        sink: &mut S,
+    ) -> Result<u64, DownloadError<S::Error>>
+    where
+        P: Pagination,
+        V: IntoIterator<Item = T> + DeserializeOwned,
+        T: DeserializeOwned,
+        S: ResultSink<T> + ?Sized,
+    {
+        let mut count = 0;
+
+        // initialize progress callback with "zero progress"
+        request.callback(0, 1);
+
+        let mut page = 1u32;
+        let mut page_request = request.page_request(page);
+        let mut current = self.page_request_get(page_request.as_ref()).await?;
+        let mut pages = current.pages();
+
+        // update progress callback with actual total pages
+        request.callback(page, pages);
+
+        while page < pages {
+            let next_request = request.page_request(page + 1);
+
+            let batch: Vec<T> = page_request.extract(current).into_iter().collect();
+            let size = batch.len() as u64;
+
+            // fetch the next page while the current batch is inserted
+            let (next, inserted) = join(self.page_request_get(next_request.as_ref()), sink.insert_batch(batch)).await;
+
+            inserted.map_err(|error| DownloadError::SinkError { error })?;
+            count += size;
+            current = next?;
+
+            page += 1;
+            pages = current.pages();
+            page_request = next_request;
+
+            request.callback(page, pages);
+        }
+
+        let batch: Vec<T> = page_request.extract(current).into_iter().collect();
+        let size = batch.len() as u64;
+
+        sink.insert_batch(batch)
+            .await
+            .map_err(|error| DownloadError::SinkError { error })?;
+        count += size;
+
+        sink.finish()
+            .await
+            .map_err(|error| DownloadError::SinkError { error })?;
+
+        Ok(count)
+    }
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn feed_items() {
+        let mut sink: Vec<u32> = Vec::new();
+
+        assert_eq!(feed_page(&mut sink, vec![1, 2, 3]).await.unwrap(), 3);
+        assert_eq!(feed_page(&mut sink, vec![4]).await.unwrap(), 1);
+        assert_eq!(sink, vec![1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,22 @@
+//! # higher-level helpers built on top of requests
+//!
+//! This module contains convenience methods for [`BodhiClient`] which combine the results of
+//! multiple queries, for common tasks that would otherwise require manually constructing and
+//! running several requests, and joining their results.
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::client::BodhiClient;
+
+mod builds;
+
+mod download;
+
+mod overrides;
+pub use overrides::OverrideContext;
+
+mod updates;
+pub use updates::{QuerySummary, UpdateBundle, UpdateChangeSet};
+
+mod workflows;
+pub use workflows::WaitOptions;
@@ -0,0 +1,99 @@
+use futures_util::future::try_join;
+
+use crate::client::BodhiClient;
+use crate::create::{NewOverride, OverrideCreator};
+use crate::data::{Override, Update, UpdateStatus};
+use crate::edit::{EditedOverride, OverrideEditor, OverrideExpirer};
+use crate::error::QueryError;
+use crate::query::{OverrideNVRQuery, UpdateQuery};
+
+/// data type that contains a buildroot override and the updates that contain its build
+///
+/// Values of this type are returned by [`BodhiClient::override_context`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct OverrideContext {
+    /// buildroot override for the build
+    pub over_ride: Override,
+    /// updates that contain the build of the buildroot override
+    pub updates: Vec<Update>,
+}
+
+impl OverrideContext {
+    /// update that contains the build and has not been pushed to stable yet (if there is one)
+    ///
+    /// This returns the most recently submitted update with status [`UpdateStatus::Pending`] or
+    /// [`UpdateStatus::Testing`].
+    pub fn pending_update(&self) -> Option<&Update> {
+        self.updates
+            .iter()
+            .filter(|update| matches!(update.status, UpdateStatus::Pending | UpdateStatus::Testing))
+            .max_by(|a, b| a.date_submitted.cmp(&b.date_submitted))
+    }
+}
+
+impl BodhiClient {
+    /// async method for creating multiple buildroot overrides
+    ///
+    /// The requests are submitted one after another (which allows reusing the same CSRF token for
+    /// all of them), and failed requests do not prevent the remaining requests from being
+    /// submitted. The result of every request is returned together with the NVR of its build, in
+    /// the same order as the requests. This can be used together with
+    /// [`OverrideCreator::for_update`] for creating buildroot overrides for all builds of an
+    /// update.
+    pub async fn create_overrides<'a>(
+        &self,
+        creators: &'a [OverrideCreator<'a>],
+    ) -> Vec<(&'a str, Result<NewOverride, QueryError>)> {
+        let mut results = Vec::with_capacity(creators.len());
+
+        for creator in creators {
+            results.push((creator.nvr(), self.request(creator).await));
+        }
+
+        results
+    }
+
+    /// async method for editing a buildroot override
+    ///
+    /// If the [`OverrideEditor`] was constructed with [`OverrideEditor::from_nvr`] and not all
+    /// parameters were set explicitly, the existing buildroot override is fetched first, and the
+    /// current values are used for all parameters that were not changed. Otherwise, this is
+    /// equivalent to passing the editor to [`BodhiClient::request`] directly.
+    pub async fn edit_override(&self, editor: &OverrideEditor<'_>) -> Result<EditedOverride, QueryError> {
+        if editor.is_resolved() {
+            return self.request(editor).await;
+        }
+
+        let existing = self.request(&OverrideNVRQuery::new(editor.nvr())).await?;
+        self.request(&editor.resolve(&existing)).await
+    }
+
+    /// async method for expiring a buildroot override
+    ///
+    /// If the [`OverrideExpirer`] was constructed with [`OverrideExpirer::from_nvr`], the existing
+    /// buildroot override is fetched first, so its notes and expiration date are left unchanged.
+    /// Otherwise, this is equivalent to passing the expirer to [`BodhiClient::request`] directly.
+    pub async fn expire_override(&self, expirer: &OverrideExpirer<'_>) -> Result<Override, QueryError> {
+        let edited = self.edit_override(expirer.editor()).await?;
+        Ok(edited.over_ride)
+    }
+
+    /// async method for fetching a buildroot override together with the updates that contain its
+    /// build
+    ///
+    /// The buildroot override and the updates are queried concurrently. If there is no buildroot
+    /// override for the build with the given NVR, a [`QueryError::NotFound`] error is returned.
+    pub async fn override_context(&self, nvr: &str) -> Result<OverrideContext, QueryError> {
+        let builds = [nvr];
+        let query = UpdateQuery::new().builds(&builds);
+
+        let (over_ride, updates) = try_join(
+            self.request(&OverrideNVRQuery::new(nvr)),
+            self.paginated_request(&query),
+        )
+        .await?;
+
+        Ok(OverrideContext { over_ride, updates })
+    }
+}
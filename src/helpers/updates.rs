@@ -0,0 +1,242 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use futures_util::future::{try_join, try_join3};
+use serde::{Deserialize, Serialize};
+
+use crate::client::BodhiClient;
+use crate::data::{split_nvr, BodhiDate, Comment, Override, Release, Update, UpdateStatus};
+use crate::error::QueryError;
+use crate::query::{
+    CommentQuery,
+    OverrideQuery,
+    ReleaseNameQuery,
+    UpdateIDQuery,
+    UpdatePageQuery,
+    UpdateQuery,
+    FIRST_PAGE,
+    MIN_ROWS_PER_PAGE,
+};
+use crate::request::Pagination;
+
+/// data type that contains the number of updates matching a query, grouped by update status
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct QuerySummary {
+    /// number of matching updates with status [`UpdateStatus::Pending`]
+    pub pending: u32,
+    /// number of matching updates with status [`UpdateStatus::Testing`]
+    pub testing: u32,
+    /// number of matching updates with status [`UpdateStatus::Stable`]
+    pub stable: u32,
+}
+
+impl QuerySummary {
+    /// total number of matching updates with any of the summarized statuses
+    pub fn total(&self) -> u32 {
+        self.pending + self.testing + self.stable
+    }
+}
+
+impl Display for QuerySummary {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "pending: {}, testing: {}, stable: {}",
+            self.pending, self.testing, self.stable
+        )
+    }
+}
+
+/// data type that contains all changes to updates since a specific date & time
+///
+/// Values of this type are returned by [`BodhiClient::update_changes_since`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct UpdateChangeSet {
+    /// updates that have been modified since the specified date & time
+    ///
+    /// New comments on these updates are also included in their list of comments.
+    pub updates: Vec<Update>,
+    /// new comments on updates that have not been modified, grouped by update alias
+    pub comments: BTreeMap<String, Vec<Comment>>,
+}
+
+impl UpdateChangeSet {
+    /// flag to indicate whether there have not been any changes
+    pub fn is_empty(&self) -> bool {
+        self.updates.is_empty() && self.comments.is_empty()
+    }
+}
+
+/// data type that contains an update together with all related entities
+///
+/// Values of this type are returned by [`BodhiClient::update_full`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct UpdateBundle {
+    /// the update itself
+    pub update: Update,
+    /// all comments on the update (without the embedded update, see
+    /// [`CommentQuery::embedded_updates`])
+    pub comments: Vec<Comment>,
+    /// all buildroot overrides for builds that are included in the update (including expired
+    /// overrides)
+    pub overrides: Vec<Override>,
+    /// full details of the release of the update
+    pub release: Release,
+}
+
+
+impl BodhiClient {
+    /// async method for counting the updates that match a query, grouped by their status
+    ///
+    /// Instead of fetching all matching updates, this method runs one request for each of the
+    /// [`UpdateStatus::Pending`], [`UpdateStatus::Testing`], and [`UpdateStatus::Stable`] states
+    /// concurrently, and only reads the total number of results from the first page of each
+    /// response. Any status filter that was set on the original query is overridden.
+    pub async fn summarize(&self, query: &UpdateQuery<'_>) -> Result<QuerySummary, QueryError> {
+        let count = |status: UpdateStatus| async move {
+            let request = UpdatePageQuery::from_query(query, FIRST_PAGE)
+                .with_status(status)
+                .with_rows_per_page(MIN_ROWS_PER_PAGE);
+            let page = self.page_request_get(&request).await?;
+            Ok::<u32, QueryError>(page.total())
+        };
+
+        let (pending, testing, stable) = try_join3(
+            count(UpdateStatus::Pending),
+            count(UpdateStatus::Testing),
+            count(UpdateStatus::Stable),
+        )
+        .await?;
+
+        Ok(QuerySummary {
+            pending,
+            testing,
+            stable,
+        })
+    }
+
+    /// async method for finding the update that (most likely) superseded the given update
+    ///
+    /// If the [`Update`] contains the comment that bodhi posts when an update is obsoleted by a
+    /// newer one, the update that is linked in this comment is returned. Otherwise, this method
+    /// queries for updates for the same packages and release that were submitted after the given
+    /// update, and returns the earliest one of them. If no such update exists, `None` is returned.
+    pub async fn superseding_update(&self, update: &Update) -> Result<Option<Update>, QueryError> {
+        if let Some(alias) = update.obsoleted_by() {
+            // fall back to searching for newer updates if the linked update does not exist
+            if let Some(superseding) = self.request_opt(&UpdateIDQuery::new(alias)).await? {
+                return Ok(Some(superseding));
+            }
+        }
+
+        let packages: Vec<&str> = update
+            .builds
+            .iter()
+            .filter_map(|build| split_nvr(&build.nvr))
+            .map(|(name, _, _)| name)
+            .collect();
+
+        if packages.is_empty() {
+            return Ok(None);
+        }
+
+        let releases = [update.release.name.clone()];
+        let mut query = UpdateQuery::new().packages(&packages).releases(&releases);
+
+        if let Some(submitted) = &update.date_submitted {
+            query = query.submitted_since(submitted);
+        }
+
+        let candidates: Vec<Update> = self.paginated_request(&query).await?;
+
+        Ok(candidates
+            .into_iter()
+            .filter(|candidate| candidate.alias != update.alias)
+            .filter(|candidate| candidate.date_submitted >= update.date_submitted)
+            .min_by(|a, b| a.date_submitted.cmp(&b.date_submitted)))
+    }
+
+    /// async method for querying an update together with all related entities
+    ///
+    /// The update and all comments on it are queried concurrently. After the update has been
+    /// received, the buildroot overrides for its builds and the details of its release are
+    /// queried concurrently as well. If any of these queries fails, the first error is returned.
+    pub async fn update_full(&self, alias: &str) -> Result<UpdateBundle, QueryError> {
+        let aliases = [alias];
+        let comment_query = CommentQuery::new().updates(&aliases).embedded_updates(false);
+
+        let (update, comments) = try_join(
+            self.request(&UpdateIDQuery::new(alias)),
+            self.paginated_request(&comment_query),
+        )
+        .await?;
+
+        let nvrs: Vec<&str> = update.builds.iter().map(|build| build.nvr.as_str()).collect();
+        let release = update.release.name.to_string();
+
+        let overrides = async {
+            // an empty list of builds would not restrict the query at all
+            if nvrs.is_empty() {
+                Ok(Vec::new())
+            } else {
+                self.paginated_request(&OverrideQuery::new().builds(&nvrs)).await
+            }
+        };
+
+        let (overrides, release) = try_join(overrides, self.request(&ReleaseNameQuery::new(&release))).await?;
+
+        Ok(UpdateBundle {
+            update,
+            comments,
+            overrides,
+            release,
+        })
+    }
+
+    /// async method for querying all changes to updates since the given date & time
+    ///
+    /// Posting a comment does not change the modification date of an update, so this method
+    /// queries both updates that have been modified since the given date & time, and comments that
+    /// have been posted since then (concurrently). New comments are attached to the modified
+    /// updates they belong to, if they are not already included, and returned separately for all
+    /// other updates. Comments that are not associated with any update alias are skipped.
+    ///
+    /// Applying the result of this method to a local mirror of updates that was synchronized at
+    /// the given date & time brings it up to date.
+    pub async fn update_changes_since(&self, since: &BodhiDate) -> Result<UpdateChangeSet, QueryError> {
+        let (mut updates, comments): (Vec<Update>, Vec<Comment>) = try_join(
+            self.paginated_request(&UpdateQuery::new().modified_since(since)),
+            self.paginated_request(&CommentQuery::new().since(since)),
+        )
+        .await?;
+
+        let mut other: BTreeMap<String, Vec<Comment>> = BTreeMap::new();
+
+        for comment in comments {
+            let alias = match comment.update_alias() {
+                Some(alias) => alias.to_owned(),
+                None => {
+                    log::debug!("Skipping comment {} without update alias.", comment.id);
+                    continue;
+                },
+            };
+
+            match updates.iter_mut().find(|update| update.alias == alias) {
+                Some(update) => {
+                    let existing = update.comments.get_or_insert_with(Vec::new);
+                    if !existing.iter().any(|c| c.id == comment.id) {
+                        existing.push(comment);
+                    }
+                },
+                None => other.entry(alias).or_default().push(comment),
+            }
+        }
+
+        Ok(UpdateChangeSet {
+            updates,
+            comments: other,
+        })
+    }
+}
@@ -0,0 +1,154 @@
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::client::BodhiClient;
+use crate::create::{OverrideCreator, UpdateCreator};
+use crate::data::{Override, Update, UpdateStatus};
+use crate::error::WaitError;
+use crate::query::{OverrideNVRQuery, UpdateIDQuery};
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::data::Release;
+
+// default interval between polling requests
+const WAIT_INTERVAL: Duration = Duration::from_secs(60);
+
+// default duration after which waiting is stopped
+const WAIT_TIMEOUT: Duration = Duration::from_secs(4 * 60 * 60);
+
+/// data type that contains settings for helpers that wait for changes on the server
+///
+/// The default settings poll the server every minute, and stop waiting after four hours (which is
+/// enough for pushes to complete under normal circumstances).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WaitOptions {
+    /// interval between polling requests
+    pub interval: Duration,
+    /// duration after which waiting is stopped with a [`WaitError::Timeout`] error
+    pub timeout: Duration,
+}
+
+impl Default for WaitOptions {
+    fn default() -> Self {
+        WaitOptions {
+            interval: WAIT_INTERVAL,
+            timeout: WAIT_TIMEOUT,
+        }
+    }
+}
+
+
+impl BodhiClient {
+    /// async method for waiting until the update with the given alias has been pushed to testing
+    ///
+    /// The update is queried repeatedly (at the interval specified in the [`WaitOptions`]), and
+    /// the `progress` callback is called with every result. The compose that is currently pushing
+    /// the update (if any) is available as [`Update::compose`].
+    ///
+    /// Waiting finishes successfully when the update has reached the [`UpdateStatus::Testing`]
+    /// state. Updates for releases that are not composed by bodhi (i.e. Rawhide, see
+    /// [`Release::is_rawhide`]) skip the testing state, so reaching the [`UpdateStatus::Stable`]
+    /// state also counts as success. If the update reaches any other state except
+    /// [`UpdateStatus::Pending`], or if its request is revoked, a [`WaitError::UnexpectedStatus`]
+    /// error is returned.
+    pub async fn wait_for_testing(
+        &self,
+        alias: &str,
+        options: &WaitOptions,
+        progress: impl Fn(&Update),
+    ) -> Result<Update, WaitError> {
+        let start = Instant::now();
+
+        loop {
+            let update = self.request(&UpdateIDQuery::new(alias)).await?;
+            progress(&update);
+
+            match update.status {
+                UpdateStatus::Testing | UpdateStatus::Stable => return Ok(update),
+                UpdateStatus::Pending if update.request.is_some() => {},
+                status => {
+                    return Err(WaitError::UnexpectedStatus {
+                        alias: update.alias,
+                        status,
+                    })
+                },
+            }
+
+            if start.elapsed() + options.interval > options.timeout {
+                return Err(WaitError::Timeout {
+                    timeout: options.timeout,
+                });
+            }
+
+            tokio::time::sleep(options.interval).await;
+        }
+    }
+
+    /// async method for creating a new update and waiting until it has been pushed to testing
+    ///
+    /// This is a combination of creating the update with the given [`UpdateCreator`] and
+    /// [`BodhiClient::wait_for_testing`]. The `progress` callback is also called with the newly
+    /// created update.
+    pub async fn create_update_and_wait(
+        &self,
+        creator: &UpdateCreator<'_>,
+        options: &WaitOptions,
+        progress: impl Fn(&Update),
+    ) -> Result<Update, WaitError> {
+        let created = self.request(creator).await?;
+        progress(&created.update);
+
+        self.wait_for_testing(&created.update.alias, options, progress).await
+    }
+
+    /// async method for creating a new buildroot override and waiting until the build is available
+    ///
+    /// Builds that are added to the buildroot with an override are not available until the
+    /// override tag has been included in a newly generated buildroot repository. There is no
+    /// reliable way to determine this from bodhi itself, so the `ready` predicate is used to check
+    /// whether the build is available (for example, by querying koji for the latest buildroot repo
+    /// or by running `koji wait-repo`).
+    ///
+    /// The override is queried repeatedly (at the interval specified in the [`WaitOptions`]), and
+    /// the `ready` predicate is called with every result. Waiting finishes successfully as soon as
+    /// the predicate returns `true`. If the override expires in the meantime, a
+    /// [`WaitError::OverrideExpired`] error is returned.
+    pub async fn override_and_wait_for_repo<F, Fut>(
+        &self,
+        creator: &OverrideCreator<'_>,
+        options: &WaitOptions,
+        mut ready: F,
+    ) -> Result<Override, WaitError>
+    where
+        F: FnMut(&Override) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        let start = Instant::now();
+
+        let created = self.request(creator).await?;
+        let mut over_ride = created.over_ride;
+
+        loop {
+            if over_ride.expired_date.is_some() {
+                return Err(WaitError::OverrideExpired { nvr: over_ride.nvr });
+            }
+
+            if ready(&over_ride).await {
+                return Ok(over_ride);
+            }
+
+            if start.elapsed() + options.interval > options.timeout {
+                return Err(WaitError::Timeout {
+                    timeout: options.timeout,
+                });
+            }
+
+            tokio::time::sleep(options.interval).await;
+
+            over_ride = self.request(&OverrideNVRQuery::new(&over_ride.nvr)).await?;
+        }
+    }
+}
@@ -99,6 +99,7 @@
 //!
 //! - request timeout duration (default: 60 seconds)
 //! - retry count for failed requests (default: 3)
+//! - retry count for failed requests that modify server state (default: 0)
 //! - `User-Agent` header in HTTP requests (default: `bodhi-rs v$(CARGO_PKG_VERSION)`)
 //! - username and password for authenticated requests (default: unauthenticated)
 //!
@@ -114,14 +115,17 @@
 //!     .await
 //!     .unwrap();
 //! ```
+//!
+//! Most of these parameters can also be read from environment variables (and, with the `config`
+//! feature, from a configuration file) with [`BodhiClientBuilder::from_env`].
 
-// FIXME: the "fedora" crate is deprecated and needs to be replaced
-#![allow(deprecated)]
 #![warn(missing_docs)]
 #![warn(missing_debug_implementations)]
 #![warn(clippy::unwrap_used)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+pub mod api_map;
+
 pub mod data;
 pub use data::*;
 
@@ -137,11 +141,47 @@ pub use create::*;
 pub mod edit;
 pub use edit::*;
 
+pub mod export;
+pub use export::*;
+
 pub mod query;
 pub use query::*;
 
+pub mod helpers;
+pub use helpers::*;
+
+pub mod reports;
+pub use reports::*;
+
+pub mod policy;
+
+pub mod analysis;
+
+pub mod errata;
+
+pub mod badge;
+
+pub mod validate;
+
+#[cfg(feature = "saved-queries")]
+pub mod saved_queries;
+
+pub mod watch;
+
+#[cfg(feature = "compat")]
+pub mod compat;
+
+#[cfg(feature = "mock")]
+pub mod mock;
+
 pub(crate) mod request;
 
+/// URL type that is used in the public API of this crate (re-exported from the [`url`] crate)
+///
+/// Previous versions of this crate returned the `Url` type that was re-exported by the deprecated
+/// `fedora` crate. This is the same type, so existing code that used it continues to work.
+pub use url::Url;
+
 #[cfg(test)]
 mod tests;
 
@@ -0,0 +1,483 @@
+//! # mock bodhi server for examples and tests
+//!
+//! This module contains a minimal HTTP server that answers requests with canned JSON responses,
+//! and a set of bundled fixtures that contain JSON responses in the format of a real bodhi server.
+//! This makes it possible to run queries without network access, for example, in documentation
+//! examples and in tests of programs that use this crate.
+//!
+//! This module is only available if the `mock` feature is enabled. Programs that use it in their
+//! tests can enable the feature for their `dev-dependencies` only.
+//!
+//! ```
+//! use bodhi::mock::MockServer;
+//! use bodhi::UpdateQuery;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let bodhi = MockServer::with_fixtures().client().await.unwrap();
+//!
+//! let updates = bodhi.paginated_request(&UpdateQuery::new()).await.unwrap();
+//! assert_eq!(updates[0].title, "foo-1.2.3-1.fc38");
+//! # }
+//! ```
+//!
+//! Responses for specific request paths can also be registered manually, either with bundled
+//! fixtures (see [`fixture`]) or with arbitrary strings:
+//!
+//! ```
+//! use bodhi::mock::{fixture, MockServer};
+//! use bodhi::ReleaseQuery;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let bodhi = MockServer::new()
+//!     .route("/releases/", fixture("releases.json").unwrap())
+//!     .client()
+//!     .await
+//!     .unwrap();
+//!
+//! let releases = bodhi.paginated_request(&ReleaseQuery::new()).await.unwrap();
+//! assert_eq!(releases[0].name.to_string(), "F38");
+//! # }
+//! ```
+//!
+//! Requests that were received by the server are recorded, so tests can check which requests
+//! were sent by the code under test (see [`MockServer::requests`]):
+//!
+//! ```
+//! use bodhi::mock::{fixture, MockServer};
+//! use bodhi::CommentCreator;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let server = MockServer::with_fixtures();
+//! let requests = server.requests();
+//! let bodhi = server.client().await.unwrap();
+//!
+//! let creator = CommentCreator::new("FEDORA-2024-1a2b3c4d5e").text("Works for me.");
+//! let _ = bodhi.request(&creator).await;
+//!
+//! let posted = requests.find("POST", "/comments/").unwrap();
+//! assert_eq!(posted.json().unwrap()["text"], "Works for me.");
+//! # }
+//! ```
+//!
+//! The [`MockServer`] answers requests over a local TCP connection. Tests that should not open any
+//! network sockets can use a [`MockTransport`] instead, which serves the same responses and
+//! records requests in the same way, but answers requests directly (see [`Transport`]).
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+
+use futures_util::future::{self, BoxFuture};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Body, Request, Response, ResponseBuilderExt, StatusCode};
+use url::Url;
+
+use crate::client::{BodhiClient, BodhiClientBuilder, BuilderError, Transport};
+use crate::error::MockError;
+
+// base URL and OpenID URL of clients that are built with `MockTransport::client`
+const MOCK_URL: &str = "http://bodhi.mock/";
+const MOCK_OPENID_URL: &str = "http://bodhi.mock/openid/";
+
+// JSON server responses that are bundled with this crate
+const FIXTURES: &[(&str, &str)] = &[
+    ("build.json", include_str!("fixtures/build.json")),
+    ("builds.json", include_str!("fixtures/builds.json")),
+    ("comment.json", include_str!("fixtures/comment.json")),
+    ("comments.json", include_str!("fixtures/comments.json")),
+    ("compose.json", include_str!("fixtures/compose.json")),
+    ("composes.json", include_str!("fixtures/composes.json")),
+    ("csrf.json", include_str!("fixtures/csrf.json")),
+    ("override.json", include_str!("fixtures/override.json")),
+    ("overrides.json", include_str!("fixtures/overrides.json")),
+    ("packages.json", include_str!("fixtures/packages.json")),
+    ("release.json", include_str!("fixtures/release.json")),
+    ("releases.json", include_str!("fixtures/releases.json")),
+    ("test_results.json", include_str!("fixtures/test_results.json")),
+    ("update.json", include_str!("fixtures/update.json")),
+    ("updates_f38.json", include_str!("fixtures/updates_f38.json")),
+    ("user.json", include_str!("fixtures/user.json")),
+    ("users.json", include_str!("fixtures/users.json")),
+];
+
+// request paths that are served by the bundled fixtures (see `MockServer::with_fixtures`)
+const FIXTURE_ROUTES: &[(&str, &str)] = &[
+    ("/builds/foo-1.2.3-1.fc38", "build.json"),
+    ("/builds/", "builds.json"),
+    ("/comments/3000003", "comment.json"),
+    ("/comments/", "comments.json"),
+    ("/composes/F38/testing", "compose.json"),
+    ("/composes/", "composes.json"),
+    ("/csrf", "csrf.json"),
+    ("/overrides/foo-1.2.3-1.fc38", "override.json"),
+    ("/overrides/", "overrides.json"),
+    ("/packages/", "packages.json"),
+    ("/releases/F38", "release.json"),
+    ("/releases/", "releases.json"),
+    ("/updates/FEDORA-2024-1a2b3c4d5e", "update.json"),
+    ("/updates/FEDORA-2024-1a2b3c4d5e/get-test-results", "test_results.json"),
+    ("/updates/", "updates_f38.json"),
+    ("/users/packager", "user.json"),
+    ("/users/", "users.json"),
+];
+
+
+/// bundled JSON server response with the given file name
+///
+/// The bundled fixtures are based on a single update for Fedora 38 (`FEDORA-2024-1a2b3c4d5e`)
+/// that contains one build (`foo-1.2.3-1.fc38`), was submitted by the `packager` user, and has
+/// three comments:
+///
+/// - `build.json`, `builds.json`: single build, and first page of a build query
+/// - `comment.json`, `comments.json`: single comment, and first page of a comment query
+/// - `compose.json`, `composes.json`: single compose, and list of running composes
+/// - `csrf.json`: CSRF token
+/// - `override.json`, `overrides.json`: single override, and first page of an override query
+/// - `packages.json`: first page of a package query
+/// - `release.json`, `releases.json`: single release, and first page of a release query
+/// - `test_results.json`: gating decisions for the update (one required test is missing)
+/// - `update.json`, `updates_f38.json`: single update, and first page of an update query
+/// - `user.json`, `users.json`: single user, and first page of a user query
+///
+/// If there is no bundled fixture with the given name, [`None`] is returned.
+pub fn fixture(name: &str) -> Option<&'static str> {
+    FIXTURES
+        .iter()
+        .find(|(fixture, _)| *fixture == name)
+        .map(|(_, contents)| *contents)
+}
+
+
+/// request that was received by a [`MockServer`] or a [`MockTransport`]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RecordedRequest {
+    /// HTTP method of the request (for example, `GET` or `POST`)
+    pub method: String,
+    /// request path (without query string)
+    pub path: String,
+    /// query string of the request (if any)
+    pub query: Option<String>,
+    /// request body (empty for requests without body)
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// request body parsed as JSON (if it is valid JSON)
+    pub fn json(&self) -> Option<serde_json::Value> {
+        serde_json::from_str(&self.body).ok()
+    }
+}
+
+
+/// handle for accessing the requests that were received by a [`MockServer`] or a [`MockTransport`]
+///
+/// Handles are returned by [`MockServer::requests`] and [`MockTransport::requests`], and remain
+/// valid after the server has been started or the transport has been passed to a client.
+#[derive(Clone, Debug, Default)]
+pub struct MockRequests {
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockRequests {
+    /// all requests that were received so far, in the order in which they were received
+    pub fn all(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().expect("Poisoned lock!").clone()
+    }
+
+    /// most recent request with the given method and path (if there was one)
+    pub fn find(&self, method: &str, path: &str) -> Option<RecordedRequest> {
+        self.requests
+            .lock()
+            .expect("Poisoned lock!")
+            .iter()
+            .rev()
+            .find(|request| request.method.eq_ignore_ascii_case(method) && request.path == path)
+            .cloned()
+    }
+
+    /// remove all recorded requests
+    pub fn clear(&self) {
+        self.requests.lock().expect("Poisoned lock!").clear();
+    }
+
+    fn push(&self, request: RecordedRequest) {
+        self.requests.lock().expect("Poisoned lock!").push(request);
+    }
+}
+
+
+// registered responses and recorded requests of a `MockServer` or `MockTransport`
+#[derive(Debug, Default)]
+struct Routes {
+    responses: HashMap<String, Cow<'static, str>>,
+    requests: MockRequests,
+}
+
+impl Routes {
+    fn with_fixtures() -> Self {
+        let mut routes = Routes::default();
+        for (path, name) in FIXTURE_ROUTES {
+            if let Some(body) = fixture(name) {
+                routes.insert(path, body);
+            }
+        }
+        routes
+    }
+
+    fn insert(&mut self, path: &str, body: impl Into<Cow<'static, str>>) {
+        self.responses.insert(path.to_owned(), body.into());
+    }
+
+    // record the request and return the body of the registered response (if there is one)
+    fn respond(&self, request: RecordedRequest) -> Option<String> {
+        let response = self.responses.get(&request.path).map(|body| body.to_string());
+        self.requests.push(request);
+        response
+    }
+}
+
+// body of responses for requests to paths without a registered response
+fn not_found(path: &str) -> String {
+    serde_json::json!({"status": "error", "path": path}).to_string()
+}
+
+
+/// minimal HTTP server that answers requests with canned responses
+///
+/// Responses are registered for request paths (without query string). Requests for paths without
+/// a registered response are answered with a "404 Not Found" response, regardless of the request
+/// method. All requests are recorded (see [`MockServer::requests`]).
+///
+/// The server runs in a background thread until the process exits, so [`BodhiClient`] instances
+/// that are connected to it can be used independently of the lifetime of the [`MockServer`]
+/// value. For tests that should not open any network sockets, use [`MockTransport`] instead.
+#[derive(Debug, Default)]
+pub struct MockServer {
+    routes: Routes,
+}
+
+impl MockServer {
+    /// constructor for [`MockServer`] without any registered responses
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// constructor for [`MockServer`] that serves all bundled fixtures
+    ///
+    /// This registers responses for all query types, for example, `/updates/` for
+    /// [`UpdateQuery`](crate::UpdateQuery) and `/updates/FEDORA-2024-1a2b3c4d5e` for
+    /// [`UpdateIDQuery`](crate::UpdateIDQuery). See [`fixture`] for the contents of the bundled
+    /// fixtures.
+    pub fn with_fixtures() -> Self {
+        MockServer {
+            routes: Routes::with_fixtures(),
+        }
+    }
+
+    /// register the response body for requests to the given path
+    ///
+    /// Paths are matched without their query string, i.e. responses for paginated queries are
+    /// registered with paths like `/updates/`.
+    #[must_use]
+    pub fn route(mut self, path: &str, body: impl Into<Cow<'static, str>>) -> Self {
+        self.routes.insert(path, body);
+        self
+    }
+
+    /// handle for accessing the requests that are received by this server
+    ///
+    /// This needs to be called before the server is started with [`MockServer::start`] or
+    /// [`MockServer::client`].
+    pub fn requests(&self) -> MockRequests {
+        self.routes.requests.clone()
+    }
+
+    /// start the server in a background thread and return its base URL
+    ///
+    /// If the server cannot be bound to a local port, the I/O error is returned.
+    pub fn start(self) -> std::io::Result<Url> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                if let Err(error) = self.respond(stream) {
+                    log::warn!("Failed to answer request to mock server: {}", error);
+                }
+            }
+        });
+
+        Ok(Url::parse(&format!("http://{address}/")).expect("Failed to parse the local URL, this should not happen."))
+    }
+
+    /// start the server in a background thread and build an anonymous [`BodhiClient`] for it
+    ///
+    /// If the server cannot be bound to a local port, or if building the client fails, a
+    /// [`MockError`] is returned.
+    pub async fn client(self) -> Result<BodhiClient, MockError> {
+        let url = self.start()?;
+        let openid_url = url.join("/openid/").map_err(BuilderError::from)?;
+
+        Ok(BodhiClientBuilder::custom(url.to_string(), openid_url.to_string())
+            .build()
+            .await?)
+    }
+
+    // read a single request from the stream and write the registered response
+    fn respond(&self, stream: TcpStream) -> std::io::Result<()> {
+        let mut reader = BufReader::new(stream);
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+
+        // read headers until the empty line that separates them from the request body
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body)?;
+
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or("GET");
+        let target = parts.next().unwrap_or("/");
+        let (path, query) = match target.split_once('?') {
+            Some((path, query)) => (path, Some(query.to_owned())),
+            None => (target, None),
+        };
+
+        let request = RecordedRequest {
+            method: method.to_owned(),
+            path: path.to_owned(),
+            query,
+            body: String::from_utf8_lossy(&body).into_owned(),
+        };
+
+        let response = match self.routes.respond(request) {
+            Some(body) => format_response("200 OK", &body),
+            None => format_response("404 Not Found", &not_found(path)),
+        };
+
+        reader.into_inner().write_all(response.as_bytes())
+    }
+}
+
+fn format_response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+
+/// [`Transport`] that answers requests with canned responses without any network access
+///
+/// This works like [`MockServer`] (responses are registered for request paths, requests for paths
+/// without a registered response are answered with a "404 Not Found" response, and all requests
+/// are recorded), but requests are answered directly instead of being sent to a local HTTP server.
+/// Clients that use this transport can be built with [`MockTransport::client`], or with
+/// [`BodhiClientBuilder::transport`] for clients with custom settings.
+///
+/// ```
+/// use bodhi::mock::MockTransport;
+/// use bodhi::UpdateQuery;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = MockTransport::with_fixtures();
+/// let requests = transport.requests();
+/// let bodhi = transport.client().await.unwrap();
+///
+/// let updates = bodhi.paginated_request(&UpdateQuery::new()).await.unwrap();
+/// assert_eq!(updates[0].title, "foo-1.2.3-1.fc38");
+/// assert!(requests.find("GET", "/updates/").is_some());
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    routes: Routes,
+}
+
+impl MockTransport {
+    /// constructor for [`MockTransport`] without any registered responses
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// constructor for [`MockTransport`] that serves all bundled fixtures
+    ///
+    /// This registers the same responses as [`MockServer::with_fixtures`].
+    pub fn with_fixtures() -> Self {
+        MockTransport {
+            routes: Routes::with_fixtures(),
+        }
+    }
+
+    /// register the response body for requests to the given path
+    ///
+    /// Paths are matched without their query string, i.e. responses for paginated queries are
+    /// registered with paths like `/updates/`.
+    #[must_use]
+    pub fn route(mut self, path: &str, body: impl Into<Cow<'static, str>>) -> Self {
+        self.routes.insert(path, body);
+        self
+    }
+
+    /// handle for accessing the requests that are received by this transport
+    ///
+    /// This needs to be called before the transport is passed to a client.
+    pub fn requests(&self) -> MockRequests {
+        self.routes.requests.clone()
+    }
+
+    /// build an anonymous [`BodhiClient`] that sends all requests to this transport
+    pub async fn client(self) -> Result<BodhiClient, BuilderError> {
+        BodhiClientBuilder::custom(String::from(MOCK_URL), String::from(MOCK_OPENID_URL))
+            .transport(self)
+            .build()
+            .await
+    }
+}
+
+impl Transport for MockTransport {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response, reqwest::Error>> {
+        let url = request.url().clone();
+        let body = request.body().and_then(Body::as_bytes).unwrap_or_default();
+
+        let recorded = RecordedRequest {
+            method: request.method().to_string(),
+            path: url.path().to_owned(),
+            query: url.query().map(str::to_owned),
+            body: String::from_utf8_lossy(body).into_owned(),
+        };
+
+        let (status, body) = match self.routes.respond(recorded) {
+            Some(body) => (StatusCode::OK, body),
+            None => (StatusCode::NOT_FOUND, not_found(url.path())),
+        };
+
+        let response = http::Response::builder()
+            .status(status)
+            .header(CONTENT_TYPE, "application/json")
+            .url(url)
+            .body(body)
+            .expect("Failed to construct mock response, this should not happen.");
+
+        Box::pin(future::ready(Ok(Response::from(response))))
+    }
+}
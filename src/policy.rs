@@ -0,0 +1,210 @@
+//! # Fedora update policies
+//!
+//! This module contains an encoding of the requirements that updates need to meet before they can
+//! be pushed to stable (minimum number of days in testing, and minimum karma), and functions for
+//! calculating when an update will meet these requirements.
+//!
+//! The default values correspond to the policies of the Fedora bodhi instance:
+//!
+//! - updates for stable Fedora releases need to spend 7 days in testing
+//! - updates for branched Fedora releases (before their release) need to spend 3 days in testing
+//! - updates for EPEL releases need to spend 14 days in testing
+//! - updates for releases that are not composed by bodhi (i.e. Rawhide) can be pushed immediately
+//! - updates can be pushed to stable earlier if they receive enough positive karma (+2 for updates
+//!   that contain packages from the "critical path", +1 for all other updates)
+//!
+//! The [`Update::karma_remaining`] and [`Update::time_remaining_in_testing`] methods compute what
+//! is still missing before an update can be pushed to stable, based on these requirements and on
+//! the karma and testing time thresholds of the update itself.
+//!
+//! All calculations are done with UTC date & time values (which is what the bodhi server uses),
+//! and durations are counted in full 24-hour days, in the same way as the bodhi server does it.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::data::{BodhiDate, Release, ReleaseState, TestGatingStatus, Update, UpdateStatus};
+
+/// minimum number of days in testing for updates for stable Fedora releases
+pub const FEDORA_MIN_DAYS_IN_TESTING: u32 = 7;
+
+/// minimum number of days in testing for updates for branched Fedora releases
+pub const FEDORA_BRANCHED_MIN_DAYS_IN_TESTING: u32 = 3;
+
+/// minimum number of days in testing for updates for EPEL releases
+pub const EPEL_MIN_DAYS_IN_TESTING: u32 = 14;
+
+/// minimum karma for pushing updates with packages from the "critical path" to stable early
+pub const CRITPATH_MIN_KARMA: i32 = 2;
+
+/// minimum karma for pushing all other updates to stable early
+pub const MIN_KARMA: i32 = 1;
+
+
+/// data type that represents the requirements for pushing updates to stable
+///
+/// Updates meet the requirements if they have spent the minimum number of days in testing, or if
+/// they have received at least the minimum karma.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TestingPolicy {
+    /// minimum number of days updates need to spend in testing
+    pub min_days_in_testing: u32,
+    /// minimum karma for pushing updates to stable before the minimum number of days has passed
+    pub min_karma: i32,
+}
+
+impl TestingPolicy {
+    /// default policy for updates for the given release
+    pub fn for_release(release: &Release, critpath: bool) -> Self {
+        let min_days_in_testing = if !release.composed_by_bodhi {
+            0
+        } else if release.id_prefix.is_epel() {
+            EPEL_MIN_DAYS_IN_TESTING
+        } else if release.state == ReleaseState::Pending {
+            FEDORA_BRANCHED_MIN_DAYS_IN_TESTING
+        } else {
+            FEDORA_MIN_DAYS_IN_TESTING
+        };
+
+        let min_karma = if critpath { CRITPATH_MIN_KARMA } else { MIN_KARMA };
+
+        TestingPolicy {
+            min_days_in_testing,
+            min_karma,
+        }
+    }
+
+    /// policy for the given update
+    ///
+    /// This is the default policy for the release of the update, except that the minimum number
+    /// of days in testing is increased if the update itself requests a longer testing period
+    /// (with [`Update::stable_days`]).
+    pub fn for_update(update: &Update) -> Self {
+        let policy = TestingPolicy::for_release(&update.release, update.critpath);
+
+        TestingPolicy {
+            min_days_in_testing: policy.min_days_in_testing.max(update.stable_days.unwrap_or(0)),
+            ..policy
+        }
+    }
+}
+
+
+/// number of full days the update has spent in testing at the given date & time
+///
+/// This returns `None` if the update has not been pushed to testing yet.
+pub fn days_in_testing(update: &Update, now: &BodhiDate) -> Option<u32> {
+    let date_testing: DateTime<Utc> = update.date_testing.clone()?.into();
+    let now: DateTime<Utc> = now.clone().into();
+
+    let days = (now - date_testing).num_days();
+    Some(u32::try_from(days).unwrap_or(0))
+}
+
+/// earliest date & time when the update can be pushed to stable according to its
+/// [`TestingPolicy`]
+///
+/// For updates that already have enough karma, this is the date when the update was pushed to
+/// testing. This returns `None` if the update is not currently in testing.
+pub fn earliest_stable_date(update: &Update) -> Option<BodhiDate> {
+    if update.status != UpdateStatus::Testing {
+        return None;
+    }
+
+    let policy = TestingPolicy::for_update(update);
+    let date_testing: DateTime<Utc> = update.date_testing.clone()?.into();
+
+    if update.karma.unwrap_or(0) >= policy.min_karma {
+        return Some(date_testing.into());
+    }
+
+    Some((date_testing + Duration::days(i64::from(policy.min_days_in_testing))).into())
+}
+
+
+/// reason why an update cannot be pushed to stable (regardless of its karma or testing time)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum BlockedReason {
+    /// update is not in testing (it is either still pending, or was already pushed to stable,
+    /// obsoleted, revoked, or unpushed)
+    NotInTesting(UpdateStatus),
+    /// update is locked while it is being pushed
+    Locked,
+    /// required tests for the update have failed
+    GatingFailed(TestGatingStatus),
+}
+
+/// data type that represents what is still needed before an update can be pushed to stable
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum StableEligibility {
+    /// the requirement is already met
+    Now,
+    /// the update needs this much additional positive karma
+    NeedsKarma(i32),
+    /// the update needs to spend this many additional days in testing (rounded up to full days)
+    NeedsDays(u32),
+    /// the update cannot be pushed to stable at all
+    Blocked(BlockedReason),
+}
+
+// check whether the update is blocked from being pushed to stable
+fn blocked_reason(update: &Update) -> Option<BlockedReason> {
+    if update.status != UpdateStatus::Testing {
+        Some(BlockedReason::NotInTesting(update.status))
+    } else if update.locked {
+        Some(BlockedReason::Locked)
+    } else {
+        match update.test_gating_status {
+            Some(status @ (TestGatingStatus::Failed | TestGatingStatus::GreenwaveFailed)) => {
+                Some(BlockedReason::GatingFailed(status))
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Update {
+    /// additional karma that this update needs before it can be pushed to stable
+    ///
+    /// The threshold is the karma threshold of the update itself ([`Update::stable_karma`]), but
+    /// at least the minimum karma of its [`TestingPolicy`]. Updates can also be pushed to stable
+    /// after they have spent enough time in testing instead (see
+    /// [`Update::time_remaining_in_testing`]).
+    pub fn karma_remaining(&self) -> StableEligibility {
+        if let Some(reason) = blocked_reason(self) {
+            return StableEligibility::Blocked(reason);
+        }
+
+        let policy = TestingPolicy::for_update(self);
+        let threshold = self.stable_karma.unwrap_or(0).max(policy.min_karma);
+        let karma = self.karma.unwrap_or(0);
+
+        if karma >= threshold {
+            StableEligibility::Now
+        } else {
+            StableEligibility::NeedsKarma(threshold - karma)
+        }
+    }
+
+    /// additional time that this update needs to spend in testing at the given date & time
+    /// before it can be pushed to stable
+    ///
+    /// The threshold is the minimum number of days in testing of the [`TestingPolicy`] for this
+    /// update (which takes [`Update::stable_days`] into account). Updates can also be pushed to
+    /// stable after they have received enough karma instead (see [`Update::karma_remaining`]).
+    pub fn time_remaining_in_testing(&self, now: &BodhiDate) -> StableEligibility {
+        if let Some(reason) = blocked_reason(self) {
+            return StableEligibility::Blocked(reason);
+        }
+
+        let policy = TestingPolicy::for_update(self);
+        let days = days_in_testing(self, now).unwrap_or(0);
+
+        if days >= policy.min_days_in_testing {
+            StableEligibility::Now
+        } else {
+            StableEligibility::NeedsDays(policy.min_days_in_testing - days)
+        }
+    }
+}
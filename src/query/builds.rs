@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::client::DEFAULT_ROWS;
 use crate::data::{Build, FedoraRelease};
 use crate::error::QueryError;
+use crate::query::{encode_segment, validate_pagination};
 use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest};
 
 /// data type encapsulating parameters for querying for a [`Build`] by NVR
@@ -14,9 +15,14 @@ use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest}
 ///
 /// ```
 /// use bodhi::BuildNVRQuery;
+/// # use bodhi::mock::MockServer;
 ///
-/// let query = BuildNVRQuery::new("rust-1.34.1-1.fc29");
-/// // let build = bodhi.request(&query).unwrap();
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// let query = BuildNVRQuery::new("foo-1.2.3-1.fc38");
+/// let build = bodhi.request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/builds.html#service-0>
@@ -39,7 +45,7 @@ impl<'a> SingleRequest<Build, Build> for BuildNVRQuery<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
-        Ok(format!("/builds/{}", self.nvr))
+        Ok(format!("/builds/{}", encode_segment(self.nvr)?))
     }
 
     fn parse(&self, string: &str) -> Result<Build, QueryError> {
@@ -57,9 +63,14 @@ impl<'a> SingleRequest<Build, Build> for BuildNVRQuery<'a> {
 ///
 /// ```
 /// use bodhi::{BuildQuery, ContentType, FedoraRelease};
+/// # use bodhi::mock::MockServer;
 ///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
 /// let query = BuildQuery::new();
-/// // let builds = bodhi.paginated_request(&query).unwrap();
+/// let builds = bodhi.paginated_request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/builds.html#service-1>
@@ -99,6 +110,10 @@ impl<'a> BuildQuery<'a> {
     }
 
     /// override the default number of results per page
+    ///
+    /// The value must be between [`MIN_ROWS_PER_PAGE`](crate::MIN_ROWS_PER_PAGE) and
+    /// [`MAX_ROWS_PER_PAGE`](crate::MAX_ROWS_PER_PAGE), otherwise the query will fail with a
+    /// [`QueryError::InvalidParameterError`] before it is sent to the server.
     #[must_use]
     pub fn rows_per_page(mut self, rows_per_page: u32) -> Self {
         self.rows_per_page = rows_per_page;
@@ -179,6 +194,8 @@ impl<'a> SingleRequest<BuildListPage, Vec<Build>> for BuildPageQuery<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
+        validate_pagination(self.page, self.rows_per_page)?;
+
         Ok(format!("/builds/?{}", serde_url_params::to_string(self)?))
     }
 
@@ -207,6 +224,10 @@ impl Pagination for BuildListPage {
     fn pages(&self) -> u32 {
         self.pages
     }
+
+    fn total(&self) -> u32 {
+        self.total
+    }
 }
 
 impl<'a> PaginatedRequest<BuildListPage, Vec<Build>> for BuildQuery<'a> {
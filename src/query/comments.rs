@@ -3,8 +3,9 @@ use std::fmt::{Debug, Formatter};
 use serde::{Deserialize, Serialize};
 
 use crate::client::DEFAULT_ROWS;
-use crate::data::{BodhiDate, Comment};
+use crate::data::{BodhiDate, Comment, CommentId, ShallowComment, KNOWN_BOTS};
 use crate::error::QueryError;
+use crate::query::validate_pagination;
 use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest};
 
 /// data type encapsulating parameters for querying for a [`Comment`] by ID
@@ -14,15 +15,20 @@ use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest}
 ///
 /// ```
 /// use bodhi::CommentIDQuery;
+/// # use bodhi::mock::MockServer;
 ///
-/// let query = CommentIDQuery::new(19999);
-/// // let comment = bodhi.request(&query).unwrap();
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// let query = CommentIDQuery::new(3000003);
+/// let comment = bodhi.request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/comments.html#service-0>
 #[derive(Debug)]
 pub struct CommentIDQuery {
-    id: u32,
+    id: CommentId,
 }
 
 #[derive(Debug, Deserialize)]
@@ -32,8 +38,8 @@ pub struct CommentPage {
 
 impl CommentIDQuery {
     /// constructor for [`CommentIDQuery`] from a comment ID
-    pub fn new(id: u32) -> Self {
-        CommentIDQuery { id }
+    pub fn new(id: impl Into<CommentId>) -> Self {
+        CommentIDQuery { id: id.into() }
     }
 }
 
@@ -61,9 +67,14 @@ impl SingleRequest<CommentPage, Comment> for CommentIDQuery {
 ///
 /// ```
 /// use bodhi::CommentQuery;
+/// # use bodhi::mock::MockServer;
 ///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
 /// let query = CommentQuery::new().update_owners(&["decathorpe"]);
-/// // let comments = bodhi.paginated_request(&query).unwrap();
+/// let comments = bodhi.paginated_request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/comments.html#service-1>
@@ -79,6 +90,8 @@ pub struct CommentQuery<'a> {
     updates: Option<&'a [&'a str]>,
     users: Option<&'a [&'a str]>,
 
+    // whether updates that are embedded in comments are kept
+    embedded_updates: bool,
     // number of results per page
     rows_per_page: u32,
     // optional callback function for reporting progress
@@ -97,6 +110,7 @@ impl<'a> Debug for CommentQuery<'a> {
             .field("update_owners", &self.update_owners)
             .field("updates", &self.updates)
             .field("users", &self.users)
+            .field("embedded_updates", &self.embedded_updates)
             .field("rows_per_page", &self.rows_per_page)
             .field("callback", &"(function pointer)")
             .finish()
@@ -107,12 +121,17 @@ impl<'a> CommentQuery<'a> {
     /// constructor for [`CommentQuery`] without any filters
     pub fn new() -> Self {
         CommentQuery {
+            embedded_updates: true,
             rows_per_page: DEFAULT_ROWS,
             ..Default::default()
         }
     }
 
     /// override the default number of results per page
+    ///
+    /// The value must be between [`MIN_ROWS_PER_PAGE`](crate::MIN_ROWS_PER_PAGE) and
+    /// [`MAX_ROWS_PER_PAGE`](crate::MAX_ROWS_PER_PAGE), otherwise the query will fail with a
+    /// [`QueryError::InvalidParameterError`] before it is sent to the server.
     #[must_use]
     pub fn rows_per_page(mut self, rows_per_page: u32) -> Self {
         self.rows_per_page = rows_per_page;
@@ -129,13 +148,45 @@ impl<'a> CommentQuery<'a> {
         self
     }
 
+    /// keep or drop the updates that are embedded in comments
+    ///
+    /// The bodhi server includes the full associated update (including its builds, bugs, and test
+    /// cases) in every comment in query results, which makes up most of the memory that is used by
+    /// the results of large comment queries. Bodhi servers do not support omitting these updates,
+    /// so when this is set to `false`, they are skipped while parsing the received pages instead,
+    /// without being deserialized. The update ID and alias of the comment are always preserved.
+    #[must_use]
+    pub fn embedded_updates(mut self, embedded_updates: bool) -> Self {
+        self.embedded_updates = embedded_updates;
+        self
+    }
+
+    /// restrict query to anonymous or non-anonymous comments
+    #[must_use]
+    pub fn anonymous(mut self, anonymous: bool) -> Self {
+        self.anonymous = Some(anonymous);
+        self
+    }
+
     /// restrict query by excluding comments by certain users
+    ///
+    /// This can be used to exclude comments that were posted by automated systems (for example,
+    /// `bodhi` or `zuul`) from query results on the server side.
     #[must_use]
     pub fn ignore_users(mut self, ignore_users: &'a [&'a str]) -> Self {
         self.ignore_users = Some(ignore_users);
         self
     }
 
+    /// restrict query to comments that were not posted by any of the [`KNOWN_BOTS`]
+    ///
+    /// This is equivalent to passing [`KNOWN_BOTS`] to [`CommentQuery::ignore_users`], and
+    /// overrides any previously ignored users.
+    #[must_use]
+    pub fn ignore_known_bots(self) -> Self {
+        self.ignore_users(KNOWN_BOTS)
+    }
+
     /// restrict query to comments where the text is "like" the given string (in the SQL sense)
     #[must_use]
     pub fn like(mut self, like: &'a str) -> CommentQuery {
@@ -191,17 +242,23 @@ impl<'a> CommentQuery<'a> {
 #[derive(Debug, Serialize)]
 pub struct CommentPageQuery<'a> {
     anonymous: Option<bool>,
+    #[serde(rename = "ignore_user")]
     ignore_users: Option<&'a [&'a str]>,
     like: Option<&'a str>,
     packages: Option<&'a [&'a str]>,
     search: Option<&'a str>,
     #[serde(with = "crate::option_bodhi_date_format_ref")]
     since: Option<&'a BodhiDate>,
+    #[serde(rename = "update_owner")]
     update_owners: Option<&'a [&'a str]>,
     updates: Option<&'a [&'a str]>,
     #[serde(rename = "user")]
     users: Option<&'a [&'a str]>,
 
+    // option that is not supported by the server and is applied to received results instead
+    #[serde(skip)]
+    embedded_updates: bool,
+
     page: u32,
     rows_per_page: u32,
 }
@@ -219,6 +276,7 @@ impl<'a> CommentPageQuery<'a> {
             update_owners: query.update_owners,
             updates: query.updates,
             users: query.users,
+            embedded_updates: query.embedded_updates,
             page,
             rows_per_page: query.rows_per_page,
         }
@@ -231,12 +289,19 @@ impl<'a> SingleRequest<CommentListPage, Vec<Comment>> for CommentPageQuery<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
+        validate_pagination(self.page, self.rows_per_page)?;
+
         Ok(format!("/comments/?{}", serde_url_params::to_string(self)?))
     }
 
     fn parse(&self, string: &str) -> Result<CommentListPage, QueryError> {
-        let comment_page: CommentListPage = serde_json::from_str(string)?;
-        Ok(comment_page)
+        if self.embedded_updates {
+            let comment_page: CommentListPage = serde_json::from_str(string)?;
+            Ok(comment_page)
+        } else {
+            let comment_page: ShallowCommentListPage = serde_json::from_str(string)?;
+            Ok(comment_page.into())
+        }
     }
 
     fn extract(&self, page: CommentListPage) -> Vec<Comment> {
@@ -254,10 +319,36 @@ pub struct CommentListPage {
     total: u32,
 }
 
+// page of comments that is deserialized without the updates that are embedded in comments
+#[derive(Debug, Deserialize)]
+struct ShallowCommentListPage {
+    comments: Vec<ShallowComment>,
+    page: u32,
+    pages: u32,
+    rows_per_page: u32,
+    total: u32,
+}
+
+impl From<ShallowCommentListPage> for CommentListPage {
+    fn from(page: ShallowCommentListPage) -> Self {
+        CommentListPage {
+            comments: page.comments.into_iter().map(Comment::from).collect(),
+            page: page.page,
+            pages: page.pages,
+            rows_per_page: page.rows_per_page,
+            total: page.total,
+        }
+    }
+}
+
 impl Pagination for CommentListPage {
     fn pages(&self) -> u32 {
         self.pages
     }
+
+    fn total(&self) -> u32 {
+        self.total
+    }
 }
 
 impl<'a> PaginatedRequest<CommentListPage, Vec<Comment>> for CommentQuery<'a> {
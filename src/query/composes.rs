@@ -1,9 +1,16 @@
+use std::time::Duration;
+
+use futures_util::{stream, Stream};
 use serde::Deserialize;
 
-use crate::data::{Compose, ComposeRequest, FedoraRelease};
+use crate::client::BodhiClient;
+use crate::data::{Compose, ComposeRequest, ComposeState, FedoraRelease};
 use crate::error::QueryError;
 use crate::request::{RequestMethod, SingleRequest};
 
+// default interval between polling requests of a `ComposeMonitor`
+const MONITOR_INTERVAL: Duration = Duration::from_secs(60);
+
 /// data type encapsulating parameters for querying for a [`Compose`] by release and request type
 ///
 /// If no compose with these properties is currently running, a [`QueryError::NotFound`] error is
@@ -11,12 +18,15 @@ use crate::request::{RequestMethod, SingleRequest};
 ///
 /// ```
 /// use bodhi::{ComposeReleaseRequestQuery, ComposeRequest, ContentType, FedoraRelease};
+/// # use bodhi::mock::MockServer;
 ///
-/// let query = ComposeReleaseRequestQuery::new(
-///     &FedoraRelease::fedora(34, ContentType::RPM).unwrap(),
-///     ComposeRequest::Stable,
-/// );
-/// // let compose = bodhi.request(&query).unwrap();
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// let release = FedoraRelease::fedora(38, ContentType::RPM).unwrap();
+/// let query = ComposeReleaseRequestQuery::new(&release, ComposeRequest::Testing);
+/// let compose = bodhi.request(&query).await.unwrap();
+/// # }
 /// ```
 #[derive(Debug)]
 pub struct ComposeReleaseRequestQuery<'a> {
@@ -60,9 +70,14 @@ impl<'a> SingleRequest<ComposePage, Compose> for ComposeReleaseRequestQuery<'a>
 ///
 /// ```
 /// use bodhi::ComposeQuery;
+/// # use bodhi::mock::MockServer;
 ///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
 /// let query = ComposeQuery::new();
-/// // let composes = bodhi.request(&query).unwrap();
+/// let composes = bodhi.request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/composes.html>
@@ -99,3 +114,141 @@ impl SingleRequest<ComposeListPage, Vec<Compose>> for ComposeQuery {
         page.composes
     }
 }
+
+
+/// data type that represents a change of the state of a [`Compose`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ComposeStateChange {
+    /// previous state of the compose (`None` for the first observed state)
+    pub previous: Option<ComposeState>,
+    /// current state of the compose
+    pub compose: Compose,
+}
+
+impl ComposeStateChange {
+    /// flag to indicate whether the compose has finished (successfully or not)
+    pub fn is_finished(&self) -> bool {
+        matches!(self.compose.state, ComposeState::Success | ComposeState::Failed)
+    }
+}
+
+
+/// data type encapsulating parameters for watching a [`Compose`] until it has finished
+///
+/// The compose is queried repeatedly (with the same request as [`ComposeReleaseRequestQuery`]),
+/// and an item is emitted for every change of its state, starting with its state when it was
+/// first queried. The stream ends after the compose has reached the [`ComposeState::Success`] or
+/// [`ComposeState::Failed`] state.
+///
+/// Finished composes are eventually removed by the bodhi server, so the stream also ends if the
+/// compose can no longer be found. If the compose cannot be found when it is first queried, a
+/// [`QueryError::NotFound`] error is emitted before the stream ends. Other failed queries result
+/// in an error item, but do not end the stream, and the next query is made after the regular
+/// interval.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use bodhi::{ComposeMonitor, ComposeRequest, ContentType, FedoraRelease};
+/// use futures_util::StreamExt;
+/// # use bodhi::mock::MockServer;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// let release = FedoraRelease::fedora(38, ContentType::RPM).unwrap();
+/// let monitor =
+///     ComposeMonitor::new(&release, ComposeRequest::Testing).interval(Duration::from_secs(30));
+///
+/// let mut changes = Box::pin(monitor.watch(&bodhi));
+/// # let mut changes = changes.take(1);
+/// while let Some(change) = changes.next().await {
+///     let change = change.unwrap();
+///     println!("compose is now in state: {}", change.compose.state);
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ComposeMonitor<'a> {
+    release: &'a FedoraRelease,
+    request: ComposeRequest,
+    interval: Duration,
+}
+
+// internal state of the stream returned by `ComposeMonitor::watch`
+struct MonitorState<'a> {
+    client: &'a BodhiClient,
+    query: ComposeReleaseRequestQuery<'a>,
+    interval: Duration,
+    started: bool,
+    // state of the compose when it was last queried successfully
+    state: Option<ComposeState>,
+    finished: bool,
+}
+
+impl<'a> ComposeMonitor<'a> {
+    /// constructor for [`ComposeMonitor`] from Fedora release and request type
+    ///
+    /// By default, the compose is queried once per minute.
+    pub fn new(release: &'a FedoraRelease, request: ComposeRequest) -> Self {
+        ComposeMonitor {
+            release,
+            request,
+            interval: MONITOR_INTERVAL,
+        }
+    }
+
+    /// method for setting the interval between polling requests
+    #[must_use]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// method for starting to watch the compose
+    pub fn watch(self, client: &'a BodhiClient) -> impl Stream<Item = Result<ComposeStateChange, QueryError>> + 'a {
+        let state = MonitorState {
+            client,
+            query: ComposeReleaseRequestQuery::new(self.release, self.request),
+            interval: self.interval,
+            started: false,
+            state: None,
+            finished: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if state.finished {
+                    return None;
+                }
+
+                if state.started {
+                    tokio::time::sleep(state.interval).await;
+                }
+                state.started = true;
+
+                let compose = match state.client.request(&state.query).await {
+                    Ok(compose) => compose,
+                    Err(QueryError::NotFound { .. }) if state.state.is_some() => return None,
+                    Err(error @ QueryError::NotFound { .. }) => {
+                        state.finished = true;
+                        return Some((Err(error), state));
+                    },
+                    Err(error) => return Some((Err(error), state)),
+                };
+
+                if state.state == Some(compose.state) {
+                    continue;
+                }
+
+                let change = ComposeStateChange {
+                    previous: state.state.replace(compose.state),
+                    compose,
+                };
+                state.finished = change.is_finished();
+                return Some((Ok(change), state));
+            }
+        })
+    }
+}
@@ -7,9 +7,14 @@ use crate::request::{RequestMethod, SingleRequest};
 ///
 /// ```
 /// use bodhi::CSRFQuery;
+/// # use bodhi::mock::MockServer;
 ///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
 /// let query = CSRFQuery::new();
-/// // let token = bodhi.request(&query).unwrap();
+/// let token = bodhi.request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/csrf.html>
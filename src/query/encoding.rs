@@ -0,0 +1,63 @@
+use std::fmt::Write;
+
+use crate::error::QueryError;
+
+// percent-encode a string for use as a single URL path segment
+//
+// All characters except for "unreserved" characters (ASCII letters and digits, and `-`, `.`,
+// `_`, and `~`) are encoded, so user-supplied values (for example, NVRs or user names) can never
+// change the structure of the resulting request URL. Empty segments and segments that consist
+// only of dots (which would be interpreted as relative path segments) are rejected.
+pub(crate) fn encode_segment(segment: &str) -> Result<String, QueryError> {
+    if segment.is_empty() || segment == "." || segment == ".." {
+        return Err(QueryError::InvalidDataError {
+            error: format!("Invalid value for URL path segment: {segment:?}"),
+        });
+    }
+
+    let mut encoded = String::with_capacity(segment.len());
+
+    for byte in segment.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => encoded.push(byte as char),
+            _ => {
+                write!(encoded, "%{byte:02X}").expect("Failed to write to string, this should not happen.");
+            },
+        }
+    }
+
+    Ok(encoded)
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unreserved() {
+        assert_eq!(
+            encode_segment("rust-bodhi-2.2.0-1.fc40").unwrap(),
+            "rust-bodhi-2.2.0-1.fc40"
+        );
+        assert_eq!(
+            encode_segment("FEDORA-2024-1a2b3c4d5e").unwrap(),
+            "FEDORA-2024-1a2b3c4d5e"
+        );
+    }
+
+    #[test]
+    fn reserved() {
+        assert_eq!(encode_segment("libstdc++").unwrap(), "libstdc%2B%2B");
+        assert_eq!(encode_segment("foo/../bar?baz#").unwrap(), "foo%2F..%2Fbar%3Fbaz%23");
+        assert_eq!(encode_segment("ä").unwrap(), "%C3%A4");
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(encode_segment("").is_err());
+        assert!(encode_segment(".").is_err());
+        assert!(encode_segment("..").is_err());
+    }
+}
@@ -0,0 +1,34 @@
+use crate::error::QueryError;
+
+/// minimum number of results per page that is accepted by bodhi servers
+pub const MIN_ROWS_PER_PAGE: u32 = 1;
+
+/// maximum number of results per page that is accepted by bodhi servers
+pub const MAX_ROWS_PER_PAGE: u32 = 100;
+
+/// number of the first page of results for paginated queries
+pub const FIRST_PAGE: u32 = 1;
+
+// check that pagination parameters are within the limits that are enforced by the server, instead
+// of letting the server reject the request after a full round trip
+pub(crate) fn validate_pagination(page: u32, rows_per_page: u32) -> Result<(), QueryError> {
+    if !(MIN_ROWS_PER_PAGE..=MAX_ROWS_PER_PAGE).contains(&rows_per_page) {
+        return Err(QueryError::InvalidParameterError {
+            name: "rows_per_page",
+            value: rows_per_page,
+            min: MIN_ROWS_PER_PAGE,
+            max: MAX_ROWS_PER_PAGE,
+        });
+    }
+
+    if page < FIRST_PAGE {
+        return Err(QueryError::InvalidParameterError {
+            name: "page",
+            value: page,
+            min: FIRST_PAGE,
+            max: u32::MAX,
+        });
+    }
+
+    Ok(())
+}
@@ -7,22 +7,36 @@ mod comments;
 pub use comments::{CommentIDQuery, CommentPageQuery, CommentQuery};
 
 mod composes;
-pub use composes::{ComposeQuery, ComposeReleaseRequestQuery};
+pub use composes::{ComposeMonitor, ComposeQuery, ComposeReleaseRequestQuery, ComposeStateChange};
 
 mod csrf;
 pub use csrf::CSRFQuery;
 
+mod encoding;
+pub(crate) use encoding::encode_segment;
+
+mod limits;
+pub(crate) use limits::validate_pagination;
+pub use limits::{FIRST_PAGE, MAX_ROWS_PER_PAGE, MIN_ROWS_PER_PAGE};
+
 mod overrides;
-pub use overrides::{OverrideNVRQuery, OverridePageQuery, OverrideQuery};
+pub use overrides::{OverrideNVRQuery, OverridePageQuery, OverrideQuery, OverrideSort};
 
 mod packages;
 pub use packages::{PackagePageQuery, PackageQuery};
 
+mod queryable;
+pub use queryable::Queryable;
+
+mod raw;
+pub use raw::Paginated;
+pub(crate) use raw::RawQuery;
+
 mod releases;
 pub use releases::{ReleaseNameQuery, ReleasePageQuery, ReleaseQuery};
 
 mod updates;
-pub use updates::{UpdateIDQuery, UpdatePageQuery, UpdateQuery};
+pub use updates::{UpdateIDQuery, UpdateLiteQuery, UpdatePageQuery, UpdateQuery, UpdateTestResultsQuery};
 
 mod users;
 pub use users::{UserNameQuery, UserPageQuery, UserQuery};
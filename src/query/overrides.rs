@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::client::DEFAULT_ROWS;
 use crate::data::{FedoraRelease, Override};
 use crate::error::QueryError;
+use crate::query::{encode_segment, validate_pagination};
 use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest};
 
 /// data type encapsulating parameters for querying for a [`Override`] by NVR
@@ -14,9 +15,14 @@ use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest}
 ///
 /// ```
 /// use bodhi::OverrideNVRQuery;
+/// # use bodhi::mock::MockServer;
 ///
-/// let query = OverrideNVRQuery::new("wingpanel-2.2.1-1.fc28");
-/// // let override = bodhi.request(&query).unwrap();
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// let query = OverrideNVRQuery::new("foo-1.2.3-1.fc38");
+/// let over_ride = bodhi.request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/overrides.html#service-0>
@@ -44,7 +50,7 @@ impl<'a> SingleRequest<OverridePage, Override> for OverrideNVRQuery<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
-        Ok(format!("/overrides/{}", self.nvr))
+        Ok(format!("/overrides/{}", encode_segment(self.nvr)?))
     }
 
     fn parse(&self, string: &str) -> Result<OverridePage, QueryError> {
@@ -62,9 +68,14 @@ impl<'a> SingleRequest<OverridePage, Override> for OverrideNVRQuery<'a> {
 ///
 /// ```
 /// use bodhi::{ContentType, FedoraRelease, OverrideQuery};
+/// # use bodhi::mock::MockServer;
 ///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
 /// let query = OverrideQuery::new().users(&["decathorpe"]).expired(false);
-/// // let overrides = bodhi.paginated_request(&query).unwrap();
+/// let overrides = bodhi.paginated_request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/overrides.html#service-1>
@@ -78,6 +89,8 @@ pub struct OverrideQuery<'a> {
     search: Option<&'a str>,
     users: Option<&'a [&'a str]>,
 
+    // client-side sort order of the results
+    sort: Option<OverrideSort>,
     // number of results per page
     rows_per_page: u32,
     // optional callback function for reporting progress
@@ -94,6 +107,7 @@ impl<'a> Debug for OverrideQuery<'a> {
             .field("releases", &self.releases)
             .field("search", &self.search)
             .field("users", &self.users)
+            .field("sort", &self.sort)
             .field("rows_per_page", &self.rows_per_page)
             .field("callback", &"(function pointer)")
             .finish()
@@ -110,6 +124,10 @@ impl<'a> OverrideQuery<'a> {
     }
 
     /// override the default number of results per page
+    ///
+    /// The value must be between [`MIN_ROWS_PER_PAGE`](crate::MIN_ROWS_PER_PAGE) and
+    /// [`MAX_ROWS_PER_PAGE`](crate::MAX_ROWS_PER_PAGE), otherwise the query will fail with a
+    /// [`QueryError::InvalidParameterError`] before it is sent to the server.
     #[must_use]
     pub fn rows_per_page(mut self, rows_per_page: u32) -> Self {
         self.rows_per_page = rows_per_page;
@@ -127,6 +145,9 @@ impl<'a> OverrideQuery<'a> {
     }
 
     /// restrict query to overrides matching specific build NVRs
+    ///
+    /// This can be used for checking which builds of a given list already have an override with
+    /// a single query.
     #[must_use]
     pub fn builds(mut self, builds: &'a [&'a str]) -> Self {
         self.builds = Some(builds);
@@ -174,6 +195,38 @@ impl<'a> OverrideQuery<'a> {
         self.users = Some(users);
         self
     }
+
+    /// sort results in the specified order
+    ///
+    /// Bodhi servers do not support sorting overrides, so results are sorted after the results
+    /// from all pages have been received instead. Results of
+    /// [`BodhiClient::paginated_stream`](crate::BodhiClient::paginated_stream) are not sorted.
+    #[must_use]
+    pub fn sort(mut self, sort: OverrideSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+
+/// sort order for the results of an [`OverrideQuery`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum OverrideSort {
+    /// overrides that expire first are sorted first
+    ExpirationAscending,
+    /// overrides that expire last are sorted first
+    ExpirationDescending,
+}
+
+impl OverrideSort {
+    // sort overrides in this order (overrides with the same expiration date keep their order)
+    fn sort(self, overrides: &mut [Override]) {
+        match self {
+            OverrideSort::ExpirationAscending => overrides.sort_by(|a, b| a.expiration_date.cmp(&b.expiration_date)),
+            OverrideSort::ExpirationDescending => overrides.sort_by(|a, b| b.expiration_date.cmp(&a.expiration_date)),
+        }
+    }
 }
 
 
@@ -216,6 +269,8 @@ impl<'a> SingleRequest<OverrideListPage, Vec<Override>> for OverridePageQuery<'a
     }
 
     fn path(&self) -> Result<String, QueryError> {
+        validate_pagination(self.page, self.rows_per_page)?;
+
         Ok(format!("/overrides/?{}", serde_url_params::to_string(self)?))
     }
 
@@ -243,6 +298,10 @@ impl Pagination for OverrideListPage {
     fn pages(&self) -> u32 {
         self.pages
     }
+
+    fn total(&self) -> u32 {
+        self.total
+    }
 }
 
 impl<'a> PaginatedRequest<OverrideListPage, Vec<Override>> for OverrideQuery<'a> {
@@ -255,4 +314,10 @@ impl<'a> PaginatedRequest<OverrideListPage, Vec<Override>> for OverrideQuery<'a>
             callback(page, pages)
         }
     }
+
+    fn sort(&self, items: &mut [Override]) {
+        if let Some(sort) = self.sort {
+            sort.sort(items);
+        }
+    }
 }
@@ -5,15 +5,21 @@ use serde::{Deserialize, Serialize};
 use crate::client::DEFAULT_ROWS;
 use crate::data::Package;
 use crate::error::QueryError;
+use crate::query::validate_pagination;
 use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest};
 
 /// data type encapsulating parameters for querying [`Package`]s
 ///
 /// ```
 /// use bodhi::PackageQuery;
+/// # use bodhi::mock::MockServer;
 ///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
 /// let query = PackageQuery::new().search("rust*");
-/// // let packages = bodhi.paginated_request(&query).unwrap();
+/// let packages = bodhi.paginated_request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/packages.html#service-0>
@@ -51,6 +57,10 @@ impl<'a> PackageQuery<'a> {
     }
 
     /// override the default number of results per page
+    ///
+    /// The value must be between [`MIN_ROWS_PER_PAGE`](crate::MIN_ROWS_PER_PAGE) and
+    /// [`MAX_ROWS_PER_PAGE`](crate::MAX_ROWS_PER_PAGE), otherwise the query will fail with a
+    /// [`QueryError::InvalidParameterError`] before it is sent to the server.
     #[must_use]
     pub fn rows_per_page(mut self, rows_per_page: u32) -> Self {
         self.rows_per_page = rows_per_page;
@@ -120,6 +130,8 @@ impl<'a> SingleRequest<PackageListPage, Vec<Package>> for PackagePageQuery<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
+        validate_pagination(self.page, self.rows_per_page)?;
+
         Ok(format!("/packages/?{}", serde_url_params::to_string(self)?))
     }
 
@@ -147,6 +159,10 @@ impl Pagination for PackageListPage {
     fn pages(&self) -> u32 {
         self.pages
     }
+
+    fn total(&self) -> u32 {
+        self.total
+    }
 }
 
 impl<'a> PaginatedRequest<PackageListPage, Vec<Package>> for PackageQuery<'a> {
@@ -0,0 +1,154 @@
+use crate::client::BodhiClient;
+use crate::data::{Build, Comment, CommentId, Compose, Override, Package, Release, Update, User};
+use crate::error::QueryError;
+use crate::query::{
+    BuildNVRQuery,
+    BuildQuery,
+    CommentIDQuery,
+    CommentQuery,
+    ComposeQuery,
+    OverrideNVRQuery,
+    OverrideQuery,
+    PackageQuery,
+    ReleaseNameQuery,
+    ReleaseQuery,
+    UpdateIDQuery,
+    UpdateQuery,
+    UserNameQuery,
+    UserQuery,
+};
+
+/// trait that connects data types to the queries that return them
+///
+/// This provides a uniform entry point into the query layer when starting from the data types:
+///
+/// ```
+/// use bodhi::{Queryable, Update};
+/// # use bodhi::mock::MockServer;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// let query = Update::query().packages(&["rust-bodhi"]);
+/// let updates = bodhi.paginated_request(&query).await.unwrap();
+/// # }
+/// ```
+///
+/// Data types that can be identified by a unique key (i.e. an update alias, a build NVR, or a
+/// user name) also provide an associated `fetch` function (for example, [`Update::fetch`]), which
+/// queries the item with the given key.
+pub trait Queryable {
+    /// type of the query that returns values of this type
+    type Query<'a>;
+
+    /// constructor for a query for values of this type, without any filters
+    fn query<'a>() -> Self::Query<'a>;
+}
+
+impl Queryable for Build {
+    type Query<'a> = BuildQuery<'a>;
+
+    fn query<'a>() -> Self::Query<'a> {
+        BuildQuery::new()
+    }
+}
+
+impl Queryable for Comment {
+    type Query<'a> = CommentQuery<'a>;
+
+    fn query<'a>() -> Self::Query<'a> {
+        CommentQuery::new()
+    }
+}
+
+impl Queryable for Compose {
+    type Query<'a> = ComposeQuery;
+
+    fn query<'a>() -> Self::Query<'a> {
+        ComposeQuery::new()
+    }
+}
+
+impl Queryable for Override {
+    type Query<'a> = OverrideQuery<'a>;
+
+    fn query<'a>() -> Self::Query<'a> {
+        OverrideQuery::new()
+    }
+}
+
+impl Queryable for Package {
+    type Query<'a> = PackageQuery<'a>;
+
+    fn query<'a>() -> Self::Query<'a> {
+        PackageQuery::new()
+    }
+}
+
+impl Queryable for Release {
+    type Query<'a> = ReleaseQuery<'a>;
+
+    fn query<'a>() -> Self::Query<'a> {
+        ReleaseQuery::new()
+    }
+}
+
+impl Queryable for Update {
+    type Query<'a> = UpdateQuery<'a>;
+
+    fn query<'a>() -> Self::Query<'a> {
+        UpdateQuery::new()
+    }
+}
+
+impl Queryable for User {
+    type Query<'a> = UserQuery<'a>;
+
+    fn query<'a>() -> Self::Query<'a> {
+        UserQuery::new()
+    }
+}
+
+
+impl Build {
+    /// async method for fetching the build with the given NVR (see [`BuildNVRQuery`])
+    pub async fn fetch(client: &BodhiClient, nvr: &str) -> Result<Build, QueryError> {
+        client.request(&BuildNVRQuery::new(nvr)).await
+    }
+}
+
+impl Comment {
+    /// async method for fetching the comment with the given ID (see [`CommentIDQuery`])
+    pub async fn fetch(client: &BodhiClient, id: impl Into<CommentId>) -> Result<Comment, QueryError> {
+        client.request(&CommentIDQuery::new(id)).await
+    }
+}
+
+impl Override {
+    /// async method for fetching the buildroot override for the build with the given NVR (see
+    /// [`OverrideNVRQuery`])
+    pub async fn fetch(client: &BodhiClient, nvr: &str) -> Result<Override, QueryError> {
+        client.request(&OverrideNVRQuery::new(nvr)).await
+    }
+}
+
+impl Release {
+    /// async method for fetching the release with the given name (see [`ReleaseNameQuery`])
+    pub async fn fetch(client: &BodhiClient, name: &str) -> Result<Release, QueryError> {
+        client.request(&ReleaseNameQuery::new(name)).await
+    }
+}
+
+impl Update {
+    /// async method for fetching the update with the given alias (see [`UpdateIDQuery`])
+    pub async fn fetch(client: &BodhiClient, alias: &str) -> Result<Update, QueryError> {
+        client.request(&UpdateIDQuery::new(alias)).await
+    }
+}
+
+impl User {
+    /// async method for fetching the user with the given name (see [`UserNameQuery`])
+    pub async fn fetch(client: &BodhiClient, name: &str) -> Result<User, QueryError> {
+        client.request(&UserNameQuery::new(name)).await
+    }
+}
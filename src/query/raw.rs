@@ -0,0 +1,177 @@
+use serde::Deserialize;
+
+use crate::client::DEFAULT_ROWS;
+use crate::error::QueryError;
+use crate::query::validate_pagination;
+use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest};
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::client::BodhiClient;
+
+// names of the fields of paginated responses that contain pagination metadata
+const PAGINATION_FIELDS: [&str; 4] = ["page", "pages", "rows_per_page", "total"];
+
+
+/// data type that contains the combined results of all pages of a paginated request
+///
+/// Values of this type are returned by [`BodhiClient::get_paginated_raw`] and
+/// [`BodhiClient::paginated_request_with_metadata`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Paginated<T> {
+    /// items from all result pages
+    pub items: Vec<T>,
+    /// total number of result pages
+    pub pages: u32,
+    /// total number of items (as reported by the server)
+    pub total: u32,
+    /// number of retries that were needed for all result pages
+    pub retries: usize,
+    /// flag to indicate whether any retries were needed for getting all result pages
+    pub degraded: bool,
+}
+
+
+// data type encapsulating parameters for `GET` requests to arbitrary paginated endpoints
+#[derive(Debug)]
+pub(crate) struct RawQuery<'a> {
+    path: &'a str,
+    params: &'a [(&'a str, &'a str)],
+    rows_per_page: u32,
+}
+
+impl<'a> RawQuery<'a> {
+    pub(crate) fn new(path: &'a str, params: &'a [(&'a str, &'a str)]) -> Self {
+        RawQuery {
+            path,
+            params,
+            rows_per_page: DEFAULT_ROWS,
+        }
+    }
+}
+
+
+// data type encapsulating parameters for `GET` requests to specific result pages of arbitrary
+// paginated endpoints
+#[derive(Debug)]
+pub(crate) struct RawPageQuery<'a> {
+    query: &'a RawQuery<'a>,
+    page: u32,
+}
+
+impl<'a> SingleRequest<RawListPage, Vec<serde_json::Value>> for RawPageQuery<'a> {
+    fn method(&self) -> RequestMethod {
+        RequestMethod::GET
+    }
+
+    fn path(&self) -> Result<String, QueryError> {
+        validate_pagination(self.page, self.query.rows_per_page)?;
+
+        if self.query.path.contains('?') {
+            return Err(QueryError::InvalidDataError {
+                error: format!(
+                    "Query parameters must not be part of the request path: {}",
+                    self.query.path
+                ),
+            });
+        }
+
+        let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+
+        for (key, value) in self.query.params {
+            if PAGINATION_FIELDS.contains(key) {
+                return Err(QueryError::InvalidDataError {
+                    error: format!("Pagination parameters are set automatically: {key}"),
+                });
+            }
+            serializer.append_pair(key, value);
+        }
+
+        serializer.append_pair("page", &self.page.to_string());
+        serializer.append_pair("rows_per_page", &self.query.rows_per_page.to_string());
+
+        Ok(format!("{}?{}", self.query.path, serializer.finish()))
+    }
+
+    fn parse(&self, string: &str) -> Result<RawListPage, QueryError> {
+        let page: RawListPage = serde_json::from_str(string)?;
+        Ok(page)
+    }
+
+    fn extract(&self, page: RawListPage) -> Vec<serde_json::Value> {
+        page.items
+    }
+}
+
+// The name of the field that contains the list of items differs between endpoints (for example,
+// `updates` or `overrides`). Since the name is not known in advance, the only other field of the
+// response which contains a list is used.
+#[derive(Debug)]
+pub(crate) struct RawListPage {
+    items: Vec<serde_json::Value>,
+    pages: u32,
+    total: u32,
+}
+
+impl<'de> Deserialize<'de> for RawListPage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        #[derive(Deserialize)]
+        struct Fields {
+            pages: u32,
+            total: u32,
+            #[serde(flatten)]
+            other: serde_json::Map<String, serde_json::Value>,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+
+        let mut lists = fields
+            .other
+            .into_iter()
+            .filter(|(key, _)| !PAGINATION_FIELDS.contains(&key.as_str()))
+            .filter_map(|(key, value)| match value {
+                serde_json::Value::Array(items) => Some((key, items)),
+                _ => None,
+            });
+
+        let items = match (lists.next(), lists.next()) {
+            (Some((_, items)), None) => items,
+            (None, _) => return Err(D::Error::custom("paginated response does not contain a list of items")),
+            (Some((first, _)), Some((second, _))) => {
+                return Err(D::Error::custom(format!(
+                    "paginated response contains multiple lists of items: {first}, {second}"
+                )))
+            },
+        };
+
+        Ok(RawListPage {
+            items,
+            pages: fields.pages,
+            total: fields.total,
+        })
+    }
+}
+
+impl Pagination for RawListPage {
+    fn pages(&self) -> u32 {
+        self.pages
+    }
+
+    fn total(&self) -> u32 {
+        self.total
+    }
+}
+
+impl<'a> PaginatedRequest<RawListPage, Vec<serde_json::Value>> for RawQuery<'a> {
+    fn page_request<'b>(&'b self, page: u32) -> Box<dyn SingleRequest<RawListPage, Vec<serde_json::Value>> + 'b> {
+        Box::new(RawPageQuery { query: self, page })
+    }
+
+    fn callback(&self, _page: u32, _pages: u32) {}
+}
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::client::DEFAULT_ROWS;
 use crate::data::{FedoraRelease, Release};
 use crate::error::QueryError;
+use crate::query::{encode_segment, validate_pagination};
 use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest};
 
 /// data type encapsulating parameters for querying for a [`Release`] by name
@@ -15,9 +16,14 @@ use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest}
 ///
 /// ```
 /// use bodhi::ReleaseNameQuery;
+/// # use bodhi::mock::MockServer;
 ///
-/// let query = ReleaseNameQuery::new("F34");
-/// // let release = bodhi.request(&query).unwrap();
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// let query = ReleaseNameQuery::new("F38");
+/// let release = bodhi.request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/releases.html#service-0>
@@ -48,7 +54,7 @@ impl<'a> SingleRequest<Release, Release> for ReleaseNameQuery<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
-        Ok(format!("/releases/{}", self.name))
+        Ok(format!("/releases/{}", encode_segment(&self.name)?))
     }
 
     fn parse(&self, string: &str) -> Result<Release, QueryError> {
@@ -66,14 +72,21 @@ impl<'a> SingleRequest<Release, Release> for ReleaseNameQuery<'a> {
 ///
 /// ```
 /// use bodhi::ReleaseQuery;
+/// # use bodhi::mock::MockServer;
 ///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
 /// let query = ReleaseQuery::new().exclude_archived(true);
-/// // let releases = bodhi.paginated_request(&query).unwrap();
+/// let releases = bodhi.paginated_request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/releases.html#service-1>
 #[derive(Default)]
 pub struct ReleaseQuery<'a> {
+    composed_by_bodhi: Option<bool>,
+    create_automatic_updates: Option<bool>,
     exclude_archived: Option<bool>,
     ids: Option<&'a [&'a str]>,
     name: Option<&'a str>,
@@ -89,6 +102,8 @@ pub struct ReleaseQuery<'a> {
 impl<'a> Debug for ReleaseQuery<'a> {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         f.debug_struct("ReleaseQuery")
+            .field("composed_by_bodhi", &self.composed_by_bodhi)
+            .field("create_automatic_updates", &self.create_automatic_updates)
             .field("exclude_archived", &self.exclude_archived)
             .field("ids", &self.ids)
             .field("name", &self.name)
@@ -110,6 +125,10 @@ impl<'a> ReleaseQuery<'a> {
     }
 
     /// override the default number of results per page
+    ///
+    /// The value must be between [`MIN_ROWS_PER_PAGE`](crate::MIN_ROWS_PER_PAGE) and
+    /// [`MAX_ROWS_PER_PAGE`](crate::MAX_ROWS_PER_PAGE), otherwise the query will fail with a
+    /// [`QueryError::InvalidParameterError`] before it is sent to the server.
     #[must_use]
     pub fn rows_per_page(mut self, rows_per_page: u32) -> Self {
         self.rows_per_page = rows_per_page;
@@ -126,6 +145,28 @@ impl<'a> ReleaseQuery<'a> {
         self
     }
 
+    /// restrict query to releases that are (not) composed by bodhi
+    ///
+    /// Bodhi servers do not support filtering releases by this flag, so results are filtered
+    /// after they have been received instead.
+    #[must_use]
+    pub fn composed_by_bodhi(mut self, composed_by_bodhi: bool) -> Self {
+        self.composed_by_bodhi = Some(composed_by_bodhi);
+        self
+    }
+
+    /// restrict query to releases for which updates are (not) created automatically
+    ///
+    /// This is the case for releases like Rawhide and ELN, where updates are created for every
+    /// successful build. Bodhi servers do not support filtering releases by this flag, so results
+    /// are filtered after they have been received instead. Releases for which the flag is not
+    /// known are treated as if automatic updates were disabled.
+    #[must_use]
+    pub fn create_automatic_updates(mut self, create_automatic_updates: bool) -> Self {
+        self.create_automatic_updates = Some(create_automatic_updates);
+        self
+    }
+
     /// restrict query to releases that have (not) been archived
     #[must_use]
     pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
@@ -168,6 +209,12 @@ impl<'a> ReleaseQuery<'a> {
 /// data type encapsulating parameters for querying specific [`ReleaseQuery`] result pages
 #[derive(Debug, Serialize)]
 pub struct ReleasePageQuery<'a> {
+    // filters that are not supported by the server and are applied to received results instead
+    #[serde(skip)]
+    composed_by_bodhi: Option<bool>,
+    #[serde(skip)]
+    create_automatic_updates: Option<bool>,
+
     exclude_archived: Option<bool>,
     ids: Option<&'a [&'a str]>,
     name: Option<&'a str>,
@@ -182,6 +229,8 @@ impl<'a> ReleasePageQuery<'a> {
     /// constructor for [`ReleasePageQuery`] taking parameters from an existing [`ReleaseQuery`]
     pub fn from_query(query: &'a ReleaseQuery, page: u32) -> Self {
         ReleasePageQuery {
+            composed_by_bodhi: query.composed_by_bodhi,
+            create_automatic_updates: query.create_automatic_updates,
             exclude_archived: query.exclude_archived,
             ids: query.ids,
             name: query.name,
@@ -199,6 +248,8 @@ impl<'a> SingleRequest<ReleaseListPage, Vec<Release>> for ReleasePageQuery<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
+        validate_pagination(self.page, self.rows_per_page)?;
+
         Ok(format!("/releases/?{}", serde_url_params::to_string(self)?))
     }
 
@@ -209,6 +260,16 @@ impl<'a> SingleRequest<ReleaseListPage, Vec<Release>> for ReleasePageQuery<'a> {
 
     fn extract(&self, page: ReleaseListPage) -> Vec<Release> {
         page.releases
+            .into_iter()
+            .filter(|release| {
+                self.composed_by_bodhi
+                    .map_or(true, |value| release.composed_by_bodhi == value)
+            })
+            .filter(|release| {
+                self.create_automatic_updates
+                    .map_or(true, |value| release.create_automatic_updates.unwrap_or(false) == value)
+            })
+            .collect()
     }
 }
 
@@ -226,6 +287,10 @@ impl Pagination for ReleaseListPage {
     fn pages(&self) -> u32 {
         self.pages
     }
+
+    fn total(&self) -> u32 {
+        self.total
+    }
 }
 
 impl<'a> PaginatedRequest<ReleaseListPage, Vec<Release>> for ReleaseQuery<'a> {
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::client::DEFAULT_ROWS;
 use crate::data::*;
 use crate::error::QueryError;
+use crate::query::{encode_segment, validate_pagination};
 use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest};
 
 /// data type encapsulating parameters for querying for a [`Update`] by alias
@@ -14,9 +15,14 @@ use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest}
 ///
 /// ```
 /// use bodhi::UpdateIDQuery;
+/// # use bodhi::mock::MockServer;
 ///
-/// let query = UpdateIDQuery::new("FEDORA-2019-3dd0cf468e");
-/// // let update = bodhi.request(&query).unwrap();
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// let query = UpdateIDQuery::new("FEDORA-2024-1a2b3c4d5e");
+/// let update = bodhi.request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/updates.html#service-0>
@@ -45,7 +51,7 @@ impl<'a> SingleRequest<UpdatePage, Update> for UpdateIDQuery<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
-        Ok(format!("/updates/{}", self.id))
+        Ok(format!("/updates/{}", encode_segment(self.id)?))
     }
 
     fn parse(&self, string: &str) -> Result<UpdatePage, QueryError> {
@@ -59,15 +65,77 @@ impl<'a> SingleRequest<UpdatePage, Update> for UpdateIDQuery<'a> {
 }
 
 
+/// data type encapsulating parameters for querying the [`TestResults`] of an [`Update`] by alias
+///
+/// The bodhi server queries greenwave for the gating decisions for the update, so this query can
+/// take a while to complete. If no update with the specified alias is known to bodhi, a
+/// [`QueryError::NotFound`] error is returned for the query.
+///
+/// ```
+/// use bodhi::UpdateTestResultsQuery;
+/// # use bodhi::mock::MockServer;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// let query = UpdateTestResultsQuery::new("FEDORA-2024-1a2b3c4d5e");
+/// let results = bodhi.request(&query).await.unwrap();
+///
+/// if !results.is_satisfied() {
+///     for requirement in results.unsatisfied_requirements() {
+///         println!("{requirement}");
+///     }
+/// }
+/// # }
+/// ```
+///
+/// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/updates.html>
+#[derive(Debug)]
+pub struct UpdateTestResultsQuery<'a> {
+    alias: &'a str,
+}
+
+impl<'a> UpdateTestResultsQuery<'a> {
+    /// constructor for [`UpdateTestResultsQuery`] from an update alias
+    pub fn new(alias: &'a str) -> Self {
+        UpdateTestResultsQuery { alias }
+    }
+}
+
+impl<'a> SingleRequest<TestResults, TestResults> for UpdateTestResultsQuery<'a> {
+    fn method(&self) -> RequestMethod {
+        RequestMethod::GET
+    }
+
+    fn path(&self) -> Result<String, QueryError> {
+        Ok(format!("/updates/{}/get-test-results", encode_segment(self.alias)?))
+    }
+
+    fn parse(&self, string: &str) -> Result<TestResults, QueryError> {
+        let results: TestResults = serde_json::from_str(string)?;
+        Ok(results)
+    }
+
+    fn extract(&self, page: TestResults) -> TestResults {
+        page
+    }
+}
+
+
 /// data type encapsulating parameters for querying [`Update`]s
 ///
 /// ```
 /// use bodhi::{ContentType, FedoraRelease, UpdateQuery, UpdateRequest};
+/// # use bodhi::mock::MockServer;
 ///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
 /// let query = UpdateQuery::new()
 ///     .users(&["decathorpe"])
 ///     .request(UpdateRequest::Testing);
-/// // let updates = bodhi.paginated_request(&query).unwrap();
+/// let updates = bodhi.paginated_request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/updates.html#service-2>
@@ -94,6 +162,8 @@ pub struct UpdateQuery<'a> {
     request: Option<UpdateRequest>,
     search: Option<&'a str>,
     severity: Option<UpdateSeverity>,
+    stable_before: Option<&'a BodhiDate>,
+    stable_since: Option<&'a BodhiDate>,
     status: Option<UpdateStatus>,
     submitted_before: Option<&'a BodhiDate>,
     submitted_since: Option<&'a BodhiDate>,
@@ -132,6 +202,8 @@ impl<'a> Debug for UpdateQuery<'a> {
             .field("request", &self.request)
             .field("search", &self.search)
             .field("severity", &self.severity)
+            .field("stable_before", &self.stable_before)
+            .field("stable_since", &self.stable_since)
             .field("status", &self.status)
             .field("submitted_before", &self.submitted_before)
             .field("submitted_since", &self.submitted_since)
@@ -155,6 +227,10 @@ impl<'a> UpdateQuery<'a> {
     }
 
     /// override the default number of results per page
+    ///
+    /// The value must be between [`MIN_ROWS_PER_PAGE`](crate::MIN_ROWS_PER_PAGE) and
+    /// [`MAX_ROWS_PER_PAGE`](crate::MAX_ROWS_PER_PAGE), otherwise the query will fail with a
+    /// [`QueryError::InvalidParameterError`] before it is sent to the server.
     #[must_use]
     pub fn rows_per_page(mut self, rows_per_page: u32) -> Self {
         self.rows_per_page = rows_per_page;
@@ -171,6 +247,15 @@ impl<'a> UpdateQuery<'a> {
         self
     }
 
+    /// convert this query into a query for "lightweight" update listings
+    ///
+    /// The returned query uses the same filters, but only deserializes the fields of the
+    /// [`UpdateLite`] type from server responses (see [`UpdateLiteQuery`]).
+    #[must_use]
+    pub fn lite(self) -> UpdateLiteQuery<'a> {
+        UpdateLiteQuery { query: self }
+    }
+
     /// restrict query to updates from (in)active releases
     #[must_use]
     pub fn active_releases(mut self, active_releases: bool) -> Self {
@@ -215,12 +300,29 @@ impl<'a> UpdateQuery<'a> {
     }
 
     /// restrict query to updates that are associated with any of the specified builds
+    ///
+    /// Builds are matched by their exact NVR. Use [`UpdateQuery::builds_like`] for matching
+    /// partial NVRs instead.
     #[must_use]
     pub fn builds(mut self, builds: &'a [&'a str]) -> Self {
         self.builds = Some(builds);
         self
     }
 
+    /// restrict query to updates that contain a build with an NVR that contains the given string
+    ///
+    /// For example, `builds_like("openssl-3.0")` matches updates that contain builds like
+    /// `openssl-3.0.9-2.fc38`, but also builds like `compat-openssl-3.0.1-1.fc38`. The server
+    /// matches the string with SQL `LIKE` semantics (surrounded with `%` wildcards), so `%` and `_`
+    /// characters in the string are treated as wildcards as well.
+    ///
+    /// This is equivalent to [`UpdateQuery::like`] (the bodhi server matches its `like` parameter
+    /// against build NVRs), and overrides any previously set value.
+    #[must_use]
+    pub fn builds_like(self, nvr: &'a str) -> Self {
+        self.like(nvr)
+    }
+
     /// restrict query to updates of the given content type
     #[must_use]
     pub fn content_type(mut self, content_type: ContentType) -> Self {
@@ -243,6 +345,9 @@ impl<'a> UpdateQuery<'a> {
     }
 
     /// restrict query to updates where the text is "like" the given string (in the SQL sense)
+    ///
+    /// The bodhi server matches this string against the NVRs of the builds in updates (see
+    /// [`UpdateQuery::builds_like`]).
     #[must_use]
     pub fn like(mut self, like: &'a str) -> Self {
         self.like = Some(like);
@@ -326,6 +431,31 @@ impl<'a> UpdateQuery<'a> {
         self
     }
 
+    /// restrict query to updates that have been pushed to stable before the specified date & time
+    ///
+    /// Bodhi servers do not support filtering updates by this date, so results are filtered after
+    /// they have been received instead. Updates that have not been pushed to stable are excluded.
+    /// Combining this filter with server-side filters (for example, [`UpdateQuery::releases`]) can
+    /// considerably reduce the amount of downloaded data.
+    #[must_use]
+    pub fn stable_before(mut self, stable_before: &'a BodhiDate) -> Self {
+        self.stable_before = Some(stable_before);
+        self
+    }
+
+    /// restrict query to updates that have been pushed to stable since the specified date & time
+    ///
+    /// Bodhi servers do not support filtering updates by this date, so results are filtered after
+    /// they have been received instead. Updates that have not been pushed to stable are excluded.
+    /// Updates that were pushed to stable since a given date have also been pushed since then, so
+    /// combining this filter with [`UpdateQuery::pushed_since`] (using the same date) can
+    /// considerably reduce the amount of downloaded data.
+    #[must_use]
+    pub fn stable_since(mut self, stable_since: &'a BodhiDate) -> Self {
+        self.stable_since = Some(stable_since);
+        self
+    }
+
     /// restrict query to updates with the specified status
     #[must_use]
     pub fn status(mut self, status: UpdateStatus) -> Self {
@@ -408,6 +538,11 @@ pub struct UpdatePageQuery<'a> {
     request: Option<UpdateRequest>,
     search: Option<&'a str>,
     severity: Option<UpdateSeverity>,
+    // filters that are not supported by the server and are applied to received results instead
+    #[serde(skip)]
+    stable_before: Option<&'a BodhiDate>,
+    #[serde(skip)]
+    stable_since: Option<&'a BodhiDate>,
     status: Option<UpdateStatus>,
     #[serde(with = "crate::option_bodhi_date_format_ref")]
     submitted_before: Option<&'a BodhiDate>,
@@ -450,6 +585,8 @@ impl<'a> UpdatePageQuery<'a> {
             request: query.request,
             search: query.search,
             severity: query.severity,
+            stable_before: query.stable_before,
+            stable_since: query.stable_since,
             status: query.status,
             submitted_before: query.submitted_before,
             submitted_since: query.submitted_since,
@@ -461,6 +598,18 @@ impl<'a> UpdatePageQuery<'a> {
             rows_per_page: query.rows_per_page,
         }
     }
+
+    // internal method for overriding the status filter (used for count-only queries)
+    pub(crate) fn with_status(mut self, status: UpdateStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    // internal method for overriding the number of results per page (used for count-only queries)
+    pub(crate) fn with_rows_per_page(mut self, rows_per_page: u32) -> Self {
+        self.rows_per_page = rows_per_page;
+        self
+    }
 }
 
 impl<'a> SingleRequest<UpdateListPage, Vec<Update>> for UpdatePageQuery<'a> {
@@ -469,6 +618,8 @@ impl<'a> SingleRequest<UpdateListPage, Vec<Update>> for UpdatePageQuery<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
+        validate_pagination(self.page, self.rows_per_page)?;
+
         Ok(format!("/updates/?{}", serde_url_params::to_string(self)?))
     }
 
@@ -479,6 +630,18 @@ impl<'a> SingleRequest<UpdateListPage, Vec<Update>> for UpdatePageQuery<'a> {
 
     fn extract(&self, page: UpdateListPage) -> Vec<Update> {
         page.updates
+            .into_iter()
+            .filter(|update| {
+                self.stable_before.map_or(true, |before| {
+                    update.date_stable.as_ref().is_some_and(|date| date < before)
+                })
+            })
+            .filter(|update| {
+                self.stable_since.map_or(true, |since| {
+                    update.date_stable.as_ref().is_some_and(|date| date >= since)
+                })
+            })
+            .collect()
     }
 }
 
@@ -496,6 +659,10 @@ impl Pagination for UpdateListPage {
     fn pages(&self) -> u32 {
         self.pages
     }
+
+    fn total(&self) -> u32 {
+        self.total
+    }
 }
 
 impl<'a> PaginatedRequest<UpdateListPage, Vec<Update>> for UpdateQuery<'a> {
@@ -509,3 +676,96 @@ impl<'a> PaginatedRequest<UpdateListPage, Vec<Update>> for UpdateQuery<'a> {
         }
     }
 }
+
+
+/// data type encapsulating parameters for querying "lightweight" [`UpdateLite`] listings
+///
+/// Queries of this type accept the same filters as [`UpdateQuery`], and are constructed with
+/// [`UpdateQuery::lite`]. The server responses are the same, but only the fields that are part
+/// of [`UpdateLite`] are deserialized, which makes listing large numbers of updates considerably
+/// cheaper.
+///
+/// ```
+/// use bodhi::{UpdateQuery, UpdateStatus};
+/// # use bodhi::mock::MockServer;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// let query = UpdateQuery::new().status(UpdateStatus::Testing).lite();
+/// let updates = bodhi.paginated_request(&query).await.unwrap();
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct UpdateLiteQuery<'a> {
+    query: UpdateQuery<'a>,
+}
+
+// single-page query for lightweight update listings
+#[derive(Debug)]
+struct UpdateLitePageQuery<'a> {
+    query: UpdatePageQuery<'a>,
+}
+
+impl<'a> SingleRequest<UpdateLiteListPage, Vec<UpdateLite>> for UpdateLitePageQuery<'a> {
+    fn method(&self) -> RequestMethod {
+        RequestMethod::GET
+    }
+
+    fn path(&self) -> Result<String, QueryError> {
+        self.query.path()
+    }
+
+    fn parse(&self, string: &str) -> Result<UpdateLiteListPage, QueryError> {
+        let page: UpdateLiteListPage = serde_json::from_str(string)?;
+        Ok(page)
+    }
+
+    fn extract(&self, page: UpdateLiteListPage) -> Vec<UpdateLite> {
+        page.updates
+            .into_iter()
+            .filter(|update| {
+                self.query.stable_before.map_or(true, |before| {
+                    update.date_stable.as_ref().is_some_and(|date| date < before)
+                })
+            })
+            .filter(|update| {
+                self.query.stable_since.map_or(true, |since| {
+                    update.date_stable.as_ref().is_some_and(|date| date >= since)
+                })
+            })
+            .collect()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+pub struct UpdateLiteListPage {
+    updates: Vec<UpdateLite>,
+    page: u32,
+    pages: u32,
+    rows_per_page: u32,
+    total: u32,
+}
+
+impl Pagination for UpdateLiteListPage {
+    fn pages(&self) -> u32 {
+        self.pages
+    }
+
+    fn total(&self) -> u32 {
+        self.total
+    }
+}
+
+impl<'a> PaginatedRequest<UpdateLiteListPage, Vec<UpdateLite>> for UpdateLiteQuery<'a> {
+    fn page_request<'b>(&'b self, page: u32) -> Box<dyn SingleRequest<UpdateLiteListPage, Vec<UpdateLite>> + 'b> {
+        Box::new(UpdateLitePageQuery {
+            query: UpdatePageQuery::from_query(&self.query, page),
+        })
+    }
+
+    fn callback(&self, page: u32, pages: u32) {
+        PaginatedRequest::callback(&self.query, page, pages)
+    }
+}
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::client::DEFAULT_ROWS;
 use crate::data::User;
 use crate::error::QueryError;
+use crate::query::{encode_segment, validate_pagination};
 use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest};
 
 /// data type encapsulating parameters for querying for a [`User`] by name
@@ -14,9 +15,14 @@ use crate::request::{PaginatedRequest, Pagination, RequestMethod, SingleRequest}
 ///
 /// ```
 /// use bodhi::UserNameQuery;
+/// # use bodhi::mock::MockServer;
 ///
-/// let query = UserNameQuery::new("decathorpe");
-/// // let user = bodhi.request(&query).unwrap();
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+/// let query = UserNameQuery::new("packager");
+/// let user = bodhi.request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/users.html#service-0>
@@ -43,7 +49,7 @@ impl<'a> SingleRequest<UserPage, User> for UserNameQuery<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
-        Ok(format!("/users/{}", self.name))
+        Ok(format!("/users/{}", encode_segment(self.name)?))
     }
 
     fn parse(&self, string: &str) -> Result<UserPage, QueryError> {
@@ -61,9 +67,14 @@ impl<'a> SingleRequest<UserPage, User> for UserNameQuery<'a> {
 ///
 /// ```
 /// use bodhi::UserQuery;
+/// # use bodhi::mock::MockServer;
 ///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let bodhi = MockServer::with_fixtures().client().await.unwrap();
 /// let query = UserQuery::new().groups(&["provenpackager"]);
-/// // let users = bodhi.paginated_request(&query).unwrap();
+/// let users = bodhi.paginated_request(&query).await.unwrap();
+/// # }
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/users.html#service-1>
@@ -105,6 +116,10 @@ impl<'a> UserQuery<'a> {
     }
 
     /// override the default number of results per page
+    ///
+    /// The value must be between [`MIN_ROWS_PER_PAGE`](crate::MIN_ROWS_PER_PAGE) and
+    /// [`MAX_ROWS_PER_PAGE`](crate::MAX_ROWS_PER_PAGE), otherwise the query will fail with a
+    /// [`QueryError::InvalidParameterError`] before it is sent to the server.
     #[must_use]
     pub fn rows_per_page(mut self, rows_per_page: u32) -> Self {
         self.rows_per_page = rows_per_page;
@@ -194,6 +209,8 @@ impl<'a> SingleRequest<UserListPage, Vec<User>> for UserPageQuery<'a> {
     }
 
     fn path(&self) -> Result<String, QueryError> {
+        validate_pagination(self.page, self.rows_per_page)?;
+
         Ok(format!("/users/?{}", serde_url_params::to_string(self)?))
     }
 
@@ -221,6 +238,10 @@ impl Pagination for UserListPage {
     fn pages(&self) -> u32 {
         self.pages
     }
+
+    fn total(&self) -> u32 {
+        self.total
+    }
 }
 
 impl<'a> PaginatedRequest<UserListPage, Vec<User>> for UserQuery<'a> {
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::client::BodhiClient;
+use crate::data::{Build, Override, Update};
+use crate::error::QueryError;
+use crate::query::{BuildNVRQuery, OverrideNVRQuery, UpdateIDQuery};
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::client::BodhiClientBuilder;
+
+/// data type that represents a difference between the values of a field in two items
+///
+/// The `path` identifies the field, with nested fields separated by dots, and with array indices
+/// in square brackets (for example, `builds[0].nvr`). If a field is only present in one of the two
+/// items, the value for the other item is `None`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FieldDiff {
+    /// path of the field that differs between the two items
+    pub path: String,
+    /// value of the field in the first item (if present)
+    pub left: Option<Value>,
+    /// value of the field in the second item (if present)
+    pub right: Option<Value>,
+}
+
+
+/// function for computing field-level differences between two items
+///
+/// Both items are converted into their JSON representation, which is then compared recursively.
+/// Fields that are equal in both items are not included in the results.
+pub fn diff<T: Serialize>(left: &T, right: &T) -> Result<Vec<FieldDiff>, serde_json::Error> {
+    let left = serde_json::to_value(left)?;
+    let right = serde_json::to_value(right)?;
+
+    let mut diffs = Vec::new();
+    diff_values(String::new(), Some(&left), Some(&right), &mut diffs);
+    Ok(diffs)
+}
+
+fn diff_values(path: String, left: Option<&Value>, right: Option<&Value>, diffs: &mut Vec<FieldDiff>) {
+    match (left, right) {
+        (Some(Value::Object(l)), Some(Value::Object(r))) => {
+            let mut keys: Vec<&String> = l.keys().chain(r.keys()).collect();
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                let path = if path.is_empty() {
+                    key.to_owned()
+                } else {
+                    format!("{path}.{key}")
+                };
+                diff_values(path, l.get(key), r.get(key), diffs);
+            }
+        },
+        (Some(Value::Array(l)), Some(Value::Array(r))) => {
+            for index in 0..l.len().max(r.len()) {
+                diff_values(format!("{path}[{index}]"), l.get(index), r.get(index), diffs);
+            }
+        },
+        (l, r) => {
+            if l != r {
+                diffs.push(FieldDiff {
+                    path,
+                    left: l.cloned(),
+                    right: r.cloned(),
+                });
+            }
+        },
+    }
+}
+
+fn diff_items<T: Serialize>(left: &T, right: &T) -> Result<Vec<FieldDiff>, QueryError> {
+    diff(left, right).map_err(|error| QueryError::SerializationError { error })
+}
+
+
+/// async function for comparing an [`Update`] between two bodhi instances
+///
+/// This is intended to be used for validating deployments of bodhi, for example, by comparing
+/// data returned from clients for the staging and production instances (see
+/// [`BodhiClientBuilder::staging`] and [`BodhiClientBuilder::default`]).
+pub async fn compare_update(
+    left: &BodhiClient,
+    right: &BodhiClient,
+    alias: &str,
+) -> Result<Vec<FieldDiff>, QueryError> {
+    let query = UpdateIDQuery::new(alias);
+    let l: Update = left.request(&query).await?;
+    let r: Update = right.request(&query).await?;
+    diff_items(&l, &r)
+}
+
+/// async function for comparing a [`Build`] between two bodhi instances
+pub async fn compare_build(left: &BodhiClient, right: &BodhiClient, nvr: &str) -> Result<Vec<FieldDiff>, QueryError> {
+    let query = BuildNVRQuery::new(nvr);
+    let l: Build = left.request(&query).await?;
+    let r: Build = right.request(&query).await?;
+    diff_items(&l, &r)
+}
+
+/// async function for comparing an [`Override`] between two bodhi instances
+pub async fn compare_override(
+    left: &BodhiClient,
+    right: &BodhiClient,
+    nvr: &str,
+) -> Result<Vec<FieldDiff>, QueryError> {
+    let query = OverrideNVRQuery::new(nvr);
+    let l: Override = left.request(&query).await?;
+    let r: Override = right.request(&query).await?;
+    diff_items(&l, &r)
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn nested_diffs() {
+        let left = json!({
+            "alias": "FEDORA-2024-1",
+            "builds": [{"nvr": "foo-1.0-1.fc40"}],
+            "karma": 1,
+            "notes": "same",
+        });
+        let right = json!({
+            "alias": "FEDORA-2024-1",
+            "builds": [{"nvr": "foo-1.0-2.fc40"}, {"nvr": "bar-1.0-1.fc40"}],
+            "notes": "same",
+        });
+
+        let diffs = diff(&left, &right).unwrap();
+        let paths: Vec<&str> = diffs.iter().map(|diff| diff.path.as_str()).collect();
+
+        assert_eq!(paths, vec!["builds[0].nvr", "builds[1]", "karma"]);
+        assert_eq!(diffs[1].left, None);
+        assert_eq!(diffs[2].right, None);
+    }
+
+    #[test]
+    fn equal_items() {
+        let value = json!({"nvr": "foo-1.0-1.fc40", "signed": true});
+        assert!(diff(&value, &value).unwrap().is_empty());
+    }
+}
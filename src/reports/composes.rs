@@ -0,0 +1,90 @@
+use crate::client::BodhiClient;
+use crate::data::{BodhiDate, FedoraRelease, Update, UpdateStatus};
+use crate::error::QueryError;
+use crate::query::UpdateQuery;
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::data::Compose;
+
+/// async function for querying the updates for a release that were pushed to stable between two
+/// dates
+///
+/// This can be used for generating summaries of the changes between two composes, for example,
+/// with the creation dates of the composes ([`Compose::date_created`]). Since bodhi only keeps
+/// records of composes while they are running, the dates of past composes need to be recorded
+/// separately.
+///
+/// This function queries all stable updates for the given release that were pushed in the given
+/// date range (including `from`, excluding `to`), and only returns updates whose
+/// [`Update::date_stable`] is also within this range. The results are sorted by the date they were
+/// pushed to stable.
+pub async fn compose_delta(
+    bodhi: &BodhiClient,
+    release: &FedoraRelease,
+    from: &BodhiDate,
+    to: &BodhiDate,
+) -> Result<Vec<Update>, QueryError> {
+    let releases = [release.clone()];
+    let query = UpdateQuery::new()
+        .releases(&releases)
+        .status(UpdateStatus::Stable)
+        .pushed_since(from)
+        .pushed_before(to);
+
+    let updates: Vec<Update> = bodhi.paginated_request(&query).await?;
+
+    Ok(entered_stable(updates, from, to))
+}
+
+// filter updates that were pushed to stable in the given date range, and sort them by that date
+fn entered_stable(updates: Vec<Update>, from: &BodhiDate, to: &BodhiDate) -> Vec<Update> {
+    let mut updates: Vec<Update> = updates
+        .into_iter()
+        .filter(|update| {
+            update
+                .date_stable
+                .as_ref()
+                .is_some_and(|date| date >= from && date < to)
+        })
+        .collect();
+
+    updates.sort_by(|a, b| a.date_stable.cmp(&b.date_stable));
+    updates
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    const UPDATE_JSON: &str = include_str!("../tests/data/update.json");
+
+    fn update(alias: &str, date_stable: Option<&str>) -> Update {
+        let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+        update.alias = alias.to_owned();
+        update.date_stable = date_stable.map(|date| date.parse().unwrap());
+        update
+    }
+
+    #[test]
+    fn stable_date_range() {
+        let from: BodhiDate = "2024-05-01 00:00:00".parse().unwrap();
+        let to: BodhiDate = "2024-05-02 00:00:00".parse().unwrap();
+
+        let updates = vec![
+            update("late", Some("2024-05-01 18:00:00")),
+            update("early", Some("2024-05-01 00:00:00")),
+            update("after", Some("2024-05-02 00:00:00")),
+            update("before", Some("2024-04-30 23:59:59")),
+            update("testing", None),
+        ];
+
+        let aliases: Vec<String> = entered_stable(updates, &from, &to)
+            .into_iter()
+            .map(|update| update.alias)
+            .collect();
+        assert_eq!(aliases, vec!["early", "late"]);
+    }
+}
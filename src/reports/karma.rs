@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::BodhiClient;
+use crate::data::{BodhiDate, Comment, FedoraRelease, Karma};
+use crate::error::QueryError;
+use crate::query::CommentQuery;
+
+/// data type that contains the number of karma votes that were given by a user
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct KarmaCount {
+    /// name of the user who gave karma
+    pub user: String,
+    /// number of positive karma votes given by this user
+    pub positive: u32,
+    /// number of negative karma votes given by this user
+    pub negative: u32,
+}
+
+impl KarmaCount {
+    /// total number of (non-neutral) karma votes given by this user
+    pub fn total(&self) -> u32 {
+        self.positive + self.negative
+    }
+}
+
+
+/// async function for aggregating karma votes that were given on updates for a release
+///
+/// This function queries all comments that were posted since the given date & time, and counts
+/// positive and negative karma votes per user, for comments on updates for the given release.
+/// Comments with neutral karma and comments that were posted by automated systems (see
+/// [`Comment::is_bot`]) are ignored.
+///
+/// The results are sorted by the total number of karma votes (in descending order), then by the
+/// number of positive karma votes (in descending order), and then by user name.
+pub async fn karma_leaderboard(
+    bodhi: &BodhiClient,
+    release: &FedoraRelease,
+    since: &BodhiDate,
+) -> Result<Vec<KarmaCount>, QueryError> {
+    let comments: Vec<Comment> = bodhi.paginated_request(&CommentQuery::new().since(since)).await?;
+
+    let votes = comments
+        .iter()
+        .filter(|comment| !comment.is_bot())
+        .filter(|comment| {
+            comment
+                .update
+                .as_ref()
+                .is_some_and(|update| &update.release.name == release)
+        })
+        .map(|comment| (comment.user.name.as_str(), comment.karma));
+
+    Ok(leaderboard(votes))
+}
+
+// count karma votes per user and sort the results
+fn leaderboard<'a>(votes: impl IntoIterator<Item = (&'a str, Karma)>) -> Vec<KarmaCount> {
+    let mut counts: HashMap<&str, KarmaCount> = HashMap::new();
+
+    for (user, karma) in votes {
+        if karma == Karma::Neutral {
+            continue;
+        }
+
+        let count = counts.entry(user).or_insert_with(|| KarmaCount {
+            user: user.to_string(),
+            positive: 0,
+            negative: 0,
+        });
+
+        match karma {
+            Karma::Positive => count.positive += 1,
+            Karma::Negative => count.negative += 1,
+            Karma::Neutral => {},
+        }
+    }
+
+    let mut results: Vec<KarmaCount> = counts.into_values().collect();
+    results.sort_by(|a, b| {
+        b.total()
+            .cmp(&a.total())
+            .then(b.positive.cmp(&a.positive))
+            .then(a.user.cmp(&b.user))
+    });
+
+    results
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_counts() {
+        let votes = [
+            ("alice", Karma::Positive),
+            ("bob", Karma::Negative),
+            ("carol", Karma::Positive),
+            ("alice", Karma::Positive),
+            ("bob", Karma::Positive),
+            ("dave", Karma::Neutral),
+        ];
+
+        let results = leaderboard(votes);
+        let users: Vec<&str> = results.iter().map(|count| count.user.as_str()).collect();
+
+        assert_eq!(users, vec!["alice", "bob", "carol"]);
+        assert_eq!(results[0].positive, 2);
+        assert_eq!(results[1].negative, 1);
+    }
+}
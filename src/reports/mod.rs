@@ -0,0 +1,14 @@
+//! # reports that aggregate data from multiple queries
+//!
+//! This module contains functions that run queries against a bodhi instance and aggregate their
+//! results into summary data structures, for example for generating statistics about releases, or
+//! for comparing data between different bodhi instances.
+
+mod compare;
+pub use compare::{compare_build, compare_override, compare_update, diff, FieldDiff};
+
+mod composes;
+pub use composes::compose_delta;
+
+mod karma;
+pub use karma::{karma_leaderboard, KarmaCount};
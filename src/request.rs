@@ -1,14 +1,90 @@
-use crate::error::QueryError;
+use crate::data::{BodhiDate, Update};
+use crate::error::{ConflictError, QueryError};
 
 use serde::de::DeserializeOwned;
 
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum RequestMethod {
     GET,
     POST,
 }
 
+// Description of a query that can be used to check whether a `POST` request has already been
+// applied on the server before it is retried, which prevents creating duplicate items.
+#[derive(Debug)]
+pub enum RetryCheck<'a> {
+    Override {
+        nvr: &'a str,
+        notes: &'a str,
+        expiration_date: &'a BodhiDate,
+    },
+    Update {
+        builds: &'a [&'a str],
+    },
+}
+
+// Snapshot of the state of an update at the time an edit was prepared, which can be used to check
+// whether the update has been modified on the server in the meantime, before submitting the edit.
+#[derive(Debug)]
+pub struct ConflictCheck<'a> {
+    pub alias: &'a str,
+    pub date_modified: Option<&'a BodhiDate>,
+    pub version_hash: &'a str,
+}
+
+impl<'a> ConflictCheck<'a> {
+    pub fn from_update(update: &'a Update) -> Self {
+        ConflictCheck {
+            alias: &update.alias,
+            date_modified: update.date_modified.as_ref(),
+            version_hash: &update.version_hash,
+        }
+    }
+
+    // compare the snapshot with the current server state of the update
+    pub fn conflict(&self, current: &Update) -> Option<ConflictError> {
+        if self.date_modified == current.date_modified.as_ref() && self.version_hash == current.version_hash {
+            return None;
+        }
+
+        Some(ConflictError {
+            alias: self.alias.to_string(),
+            expected_date_modified: self.date_modified.cloned(),
+            actual_date_modified: current.date_modified.clone(),
+            expected_version_hash: self.version_hash.to_string(),
+            actual_version_hash: current.version_hash.clone(),
+        })
+    }
+}
+
+// Description of a check for existing karma from the authenticated user, which is performed before
+// posting a comment with karma for an update. Existing karma either results in the comment being
+// rejected, or in the karma of the new comment being replaced with neutral karma.
+#[derive(Debug)]
+pub struct KarmaCheck<'a> {
+    pub update: &'a str,
+    pub reject: bool,
+}
+
+impl<'a> KarmaCheck<'a> {
+    // replace the karma value in the serialized request body with neutral karma
+    pub fn neutralize(body: Option<String>) -> Result<Option<String>, QueryError> {
+        let body = match body {
+            Some(body) => body,
+            None => return Ok(None),
+        };
+
+        let mut value: serde_json::Value =
+            serde_json::from_str(&body).map_err(|error| QueryError::SerializationError { error })?;
+        value["karma"] = serde_json::Value::from(0);
+
+        Ok(Some(
+            serde_json::to_string(&value).map_err(|error| QueryError::SerializationError { error })?,
+        ))
+    }
+}
+
 pub trait SingleRequest<P, T>
 where
     T: DeserializeOwned,
@@ -23,17 +99,35 @@ where
 
     fn parse(&self, string: &str) -> Result<P, QueryError>;
     fn extract(&self, page: P) -> T;
+
+    fn retry_check(&self) -> Option<RetryCheck<'_>> {
+        None
+    }
+
+    fn conflict_check(&self) -> Option<ConflictCheck<'_>> {
+        None
+    }
+
+    fn karma_check(&self) -> Option<KarmaCheck<'_>> {
+        None
+    }
 }
 
 pub trait PaginatedRequest<P, T>
 where
     P: Pagination,
-    T: DeserializeOwned,
+    T: IntoIterator + DeserializeOwned,
 {
     fn page_request<'a>(&'a self, page: u32) -> Box<dyn SingleRequest<P, T> + 'a>;
     fn callback(&self, page: u32, pages: u32);
+
+    // sort the combined results of all pages (results are returned in the order of the server by
+    // default, and results of streamed requests are never sorted on the client side)
+    #[allow(unused_variables)]
+    fn sort(&self, items: &mut [T::Item]) {}
 }
 
 pub trait Pagination {
     fn pages(&self) -> u32;
+    fn total(&self) -> u32;
 }
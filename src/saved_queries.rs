@@ -0,0 +1,861 @@
+//! # saved queries from declarative definitions
+//!
+//! This module contains data types for defining queries in TOML files, and for running them
+//! against a bodhi instance. Every saved query has a label, an entity type (`builds`, `comments`,
+//! `composes`, `overrides`, `packages`, `releases`, `updates`, or `users`), and filters that
+//! correspond to the methods of the query type for that entity (for example, the filters for
+//! `updates` correspond to the methods of [`UpdateQuery`]). Unknown filters are rejected.
+//!
+//! ```toml
+//! [[query]]
+//! label = "pending updates for Fedora 40"
+//! entity = "updates"
+//! releases = ["F40"]
+//! status = "pending"
+//!
+//! [[query]]
+//! label = "active buildroot overrides for Fedora 40"
+//! entity = "overrides"
+//! releases = ["F40"]
+//! expired = false
+//! ```
+//!
+//! Date & time values are specified as strings in the format that is used by bodhi (for example,
+//! `"2024-05-01 12:00:00"`). This module is only available with the `saved-queries` feature.
+//!
+//! Filters can also be parsed from the URLs of filtered lists in the bodhi web UI or the REST API
+//! with [`QueryFilters::from_url`], which makes it possible to turn a link into a saved query.
+//!
+//! ```
+//! use bodhi::saved_queries::SavedQueries;
+//! # use bodhi::mock::MockServer;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! # let bodhi = MockServer::with_fixtures().client().await.unwrap();
+//! let queries = SavedQueries::from_toml(
+//!     r#"
+//! [[query]]
+//! label = "pending updates for Fedora 38"
+//! entity = "updates"
+//! releases = ["F38"]
+//! status = "pending"
+//! "#,
+//! )
+//! .unwrap();
+//!
+//! for result in queries.run(&bodhi).await.unwrap() {
+//!     println!("{}: {} results", result.label, result.results.len());
+//! }
+//! # }
+//! ```
+
+use serde::Deserialize;
+use url::Url;
+
+use crate::client::BodhiClient;
+use crate::data::*;
+use crate::error::{QueryError, QueryUrlError};
+use crate::query::*;
+
+// convert an optional list of strings into a list of string slices for query builders
+fn strs(values: &Option<Vec<String>>) -> Option<Vec<&str>> {
+    values
+        .as_ref()
+        .map(|values| values.iter().map(String::as_str).collect())
+}
+
+
+/// data type that represents a list of saved query definitions
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SavedQueries {
+    /// list of saved queries (from `[[query]]` tables)
+    #[serde(default, rename = "query")]
+    pub queries: Vec<SavedQuery>,
+}
+
+impl SavedQueries {
+    /// parse saved query definitions from the contents of a TOML file
+    pub fn from_toml(contents: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    /// async method for running all saved queries (in order of their definitions)
+    ///
+    /// If any of the queries fails, the error is returned, and the remaining queries are not run.
+    pub async fn run(&self, bodhi: &BodhiClient) -> Result<Vec<LabeledResults>, QueryError> {
+        let mut results = Vec::with_capacity(self.queries.len());
+
+        for query in &self.queries {
+            results.push(query.run(bodhi).await?);
+        }
+
+        Ok(results)
+    }
+}
+
+
+/// data type that represents a single saved query definition
+#[derive(Debug, Deserialize)]
+pub struct SavedQuery {
+    /// human-readable label of this query
+    pub label: String,
+    /// entity type and filters of this query
+    #[serde(flatten)]
+    pub filters: QueryFilters,
+}
+
+impl SavedQuery {
+    /// async method for running this query
+    pub async fn run(&self, bodhi: &BodhiClient) -> Result<LabeledResults, QueryError> {
+        Ok(LabeledResults {
+            label: self.label.clone(),
+            results: self.filters.run(bodhi).await?,
+        })
+    }
+}
+
+
+/// data type that represents the results of a saved query, with the label of the query
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct LabeledResults {
+    /// label of the saved query
+    pub label: String,
+    /// results of the saved query
+    pub results: ResultSet,
+}
+
+/// results of a saved query, depending on the entity type of the query
+#[derive(Debug)]
+#[allow(missing_docs)]
+pub enum ResultSet {
+    Builds(Vec<Build>),
+    Comments(Vec<Comment>),
+    Composes(Vec<Compose>),
+    Overrides(Vec<Override>),
+    Packages(Vec<Package>),
+    Releases(Vec<Release>),
+    Updates(Vec<Update>),
+    Users(Vec<User>),
+}
+
+impl ResultSet {
+    /// number of results
+    pub fn len(&self) -> usize {
+        match self {
+            ResultSet::Builds(results) => results.len(),
+            ResultSet::Comments(results) => results.len(),
+            ResultSet::Composes(results) => results.len(),
+            ResultSet::Overrides(results) => results.len(),
+            ResultSet::Packages(results) => results.len(),
+            ResultSet::Releases(results) => results.len(),
+            ResultSet::Updates(results) => results.len(),
+            ResultSet::Users(results) => results.len(),
+        }
+    }
+
+    /// flag to indicate whether there are no results
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+
+/// entity type and filters of a saved query (based on the value of the `entity` key)
+#[derive(Debug, Deserialize)]
+#[serde(tag = "entity", rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum QueryFilters {
+    Builds(BuildFilters),
+    Comments(CommentFilters),
+    Composes(ComposeFilters),
+    Overrides(OverrideFilters),
+    Packages(PackageFilters),
+    Releases(ReleaseFilters),
+    Updates(UpdateFilters),
+    Users(UserFilters),
+}
+
+impl QueryFilters {
+    /// parse filters from the URL of a filtered list in the bodhi web UI or the REST API
+    ///
+    /// The entity type is determined from the path of the URL (for example, `/updates/` for
+    /// updates), and the query parameters are converted into the corresponding filters. List
+    /// values can be specified with repeated parameters or as comma-separated values. Paths
+    /// without scheme and host (for example, `/updates/?status=testing`) are also accepted.
+    ///
+    /// Unsupported query parameters are ignored, and a warning is logged for each of them.
+    /// Pagination parameters (`page` and `rows_per_page`) are ignored silently, since running the
+    /// query always returns all results.
+    ///
+    /// ```
+    /// use bodhi::saved_queries::QueryFilters;
+    ///
+    /// let filters = QueryFilters::from_url(
+    ///     "https://bodhi.fedoraproject.org/updates/?releases=F40&status=testing&user=decathorpe",
+    /// )
+    /// .unwrap();
+    /// assert!(matches!(filters, QueryFilters::Updates(_)));
+    /// ```
+    pub fn from_url(url: &str) -> Result<Self, QueryUrlError> {
+        let url = match Url::parse(url) {
+            Ok(url) => url,
+            Err(url::ParseError::RelativeUrlWithoutBase) => Url::parse(FEDORA_BODHI_URL)?.join(url)?,
+            Err(error) => return Err(error.into()),
+        };
+
+        let mut segments = url.path().split('/').filter(|segment| !segment.is_empty());
+        let entity = match (segments.next(), segments.next()) {
+            (Some(entity), None) => entity,
+            _ => {
+                return Err(QueryUrlError::UnknownEndpoint {
+                    path: url.path().to_owned(),
+                })
+            },
+        };
+
+        Ok(match entity {
+            "builds" => QueryFilters::Builds(url_filters(&url, BUILD_PARAMS)?),
+            "comments" => QueryFilters::Comments(url_filters(&url, COMMENT_PARAMS)?),
+            "composes" => QueryFilters::Composes(url_filters(&url, &[])?),
+            "overrides" => QueryFilters::Overrides(url_filters(&url, OVERRIDE_PARAMS)?),
+            "packages" => QueryFilters::Packages(url_filters(&url, PACKAGE_PARAMS)?),
+            "releases" => QueryFilters::Releases(url_filters(&url, RELEASE_PARAMS)?),
+            "updates" => QueryFilters::Updates(url_filters(&url, UPDATE_PARAMS)?),
+            "users" => QueryFilters::Users(url_filters(&url, USER_PARAMS)?),
+            _ => {
+                return Err(QueryUrlError::UnknownEndpoint {
+                    path: url.path().to_owned(),
+                })
+            },
+        })
+    }
+
+    /// async method for running the query with these filters
+    pub async fn run(&self, bodhi: &BodhiClient) -> Result<ResultSet, QueryError> {
+        Ok(match self {
+            QueryFilters::Builds(filters) => ResultSet::Builds(filters.run(bodhi).await?),
+            QueryFilters::Comments(filters) => ResultSet::Comments(filters.run(bodhi).await?),
+            QueryFilters::Composes(filters) => ResultSet::Composes(filters.run(bodhi).await?),
+            QueryFilters::Overrides(filters) => ResultSet::Overrides(filters.run(bodhi).await?),
+            QueryFilters::Packages(filters) => ResultSet::Packages(filters.run(bodhi).await?),
+            QueryFilters::Releases(filters) => ResultSet::Releases(filters.run(bodhi).await?),
+            QueryFilters::Updates(filters) => ResultSet::Updates(filters.run(bodhi).await?),
+            QueryFilters::Users(filters) => ResultSet::Users(filters.run(bodhi).await?),
+        })
+    }
+}
+
+
+// kinds of values of URL query parameters, and how they are converted into filter values
+#[derive(Clone, Copy)]
+enum Param {
+    // single string value (also used for enums and dates)
+    Value,
+    // boolean value (`true` or `false`)
+    Flag,
+    // list of string values (repeated parameters or comma-separated values)
+    List,
+    // list of numbers (repeated parameters or comma-separated values)
+    Numbers,
+}
+
+// names of the supported URL query parameters, the corresponding filter names, and their kinds
+const BUILD_PARAMS: &[(&str, &str, Param)] = &[
+    ("nvr", "nvr", Param::Value),
+    ("packages", "packages", Param::List),
+    ("releases", "releases", Param::List),
+    ("updates", "updates", Param::List),
+];
+
+const COMMENT_PARAMS: &[(&str, &str, Param)] = &[
+    ("anonymous", "anonymous", Param::Flag),
+    ("ignore_user", "ignore_users", Param::List),
+    ("like", "like", Param::Value),
+    ("packages", "packages", Param::List),
+    ("search", "search", Param::Value),
+    ("since", "since", Param::Value),
+    ("update_owner", "update_owners", Param::List),
+    ("updates", "updates", Param::List),
+    ("user", "users", Param::List),
+];
+
+const OVERRIDE_PARAMS: &[(&str, &str, Param)] = &[
+    ("builds", "builds", Param::List),
+    ("expired", "expired", Param::Flag),
+    ("like", "like", Param::Value),
+    ("packages", "packages", Param::List),
+    ("releases", "releases", Param::List),
+    ("search", "search", Param::Value),
+    ("user", "users", Param::List),
+];
+
+const PACKAGE_PARAMS: &[(&str, &str, Param)] = &[
+    ("like", "like", Param::Value),
+    ("name", "name", Param::Value),
+    ("search", "search", Param::Value),
+];
+
+const RELEASE_PARAMS: &[(&str, &str, Param)] = &[
+    ("exclude_archived", "exclude_archived", Param::Flag),
+    ("ids", "ids", Param::List),
+    ("name", "name", Param::Value),
+    ("packages", "packages", Param::List),
+    ("updates", "updates", Param::List),
+];
+
+const UPDATE_PARAMS: &[(&str, &str, Param)] = &[
+    ("active_releases", "active_releases", Param::Flag),
+    ("alias", "aliases", Param::List),
+    ("bugs", "bugs", Param::Numbers),
+    ("builds", "builds", Param::List),
+    ("content_type", "content_type", Param::Value),
+    ("critpath", "critpath", Param::Flag),
+    ("cves", "cves", Param::List),
+    ("like", "like", Param::Value),
+    ("locked", "locked", Param::Flag),
+    ("modified_before", "modified_before", Param::Value),
+    ("modified_since", "modified_since", Param::Value),
+    ("packages", "packages", Param::List),
+    ("pushed", "pushed", Param::Flag),
+    ("pushed_before", "pushed_before", Param::Value),
+    ("pushed_since", "pushed_since", Param::Value),
+    ("releases", "releases", Param::List),
+    ("request", "request", Param::Value),
+    ("search", "search", Param::Value),
+    ("severity", "severity", Param::Value),
+    ("status", "status", Param::Value),
+    ("submitted_before", "submitted_before", Param::Value),
+    ("submitted_since", "submitted_since", Param::Value),
+    ("suggest", "suggest", Param::Value),
+    ("updateid", "update_ids", Param::List),
+    ("type", "update_type", Param::Value),
+    ("user", "users", Param::List),
+];
+
+const USER_PARAMS: &[(&str, &str, Param)] = &[
+    ("groups", "groups", Param::List),
+    ("like", "like", Param::Value),
+    ("name", "name", Param::Value),
+    ("search", "search", Param::Value),
+    ("updates", "updates", Param::List),
+];
+
+// convert the query parameters of a URL into filters with the given parameter definitions
+fn url_filters<T: serde::de::DeserializeOwned>(url: &Url, params: &[(&str, &str, Param)]) -> Result<T, QueryUrlError> {
+    use serde_json::{Map, Value};
+
+    let mut filters = Map::new();
+
+    for (name, value) in url.query_pairs() {
+        let (filter, kind) = match params.iter().find(|(param, _, _)| *param == name) {
+            Some((_, filter, kind)) => (filter, kind),
+            None => {
+                // pagination parameters are ignored, since all results are always returned
+                if name != "page" && name != "rows_per_page" {
+                    log::warn!("Ignoring unsupported query parameter: {}={}", name, value);
+                }
+                continue;
+            },
+        };
+
+        match kind {
+            Param::Value => {
+                filters.insert(filter.to_string(), Value::from(value.as_ref()));
+            },
+            Param::Flag => {
+                let flag = match value.as_ref() {
+                    "true" | "True" | "1" => Value::Bool(true),
+                    "false" | "False" | "0" => Value::Bool(false),
+                    other => Value::from(other),
+                };
+                filters.insert(filter.to_string(), flag);
+            },
+            Param::List | Param::Numbers => {
+                let list = filters
+                    .entry(filter.to_string())
+                    .or_insert_with(|| Value::Array(Vec::new()));
+                if let Value::Array(list) = list {
+                    let values = value.split(',').map(str::trim).filter(|value| !value.is_empty());
+                    list.extend(values.map(|value| match (kind, value.parse::<u32>()) {
+                        (Param::Numbers, Ok(number)) => Value::from(number),
+                        _ => Value::from(value),
+                    }));
+                }
+            },
+        }
+    }
+
+    Ok(serde_json::from_value(Value::Object(filters))?)
+}
+
+
+/// filters for saved queries for builds (see [`BuildQuery`])
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BuildFilters {
+    /// see [`BuildQuery::nvr`]
+    pub nvr: Option<String>,
+    /// see [`BuildQuery::packages`]
+    pub packages: Option<Vec<String>>,
+    /// see [`BuildQuery::releases`]
+    pub releases: Option<Vec<FedoraRelease>>,
+    /// see [`BuildQuery::updates`]
+    pub updates: Option<Vec<String>>,
+}
+
+impl BuildFilters {
+    async fn run(&self, bodhi: &BodhiClient) -> Result<Vec<Build>, QueryError> {
+        let packages = strs(&self.packages);
+        let updates = strs(&self.updates);
+
+        let mut query = BuildQuery::new();
+        if let Some(nvr) = &self.nvr {
+            query = query.nvr(nvr);
+        }
+        if let Some(packages) = &packages {
+            query = query.packages(packages);
+        }
+        if let Some(releases) = &self.releases {
+            query = query.releases(releases);
+        }
+        if let Some(updates) = &updates {
+            query = query.updates(updates);
+        }
+
+        bodhi.paginated_request(&query).await
+    }
+}
+
+
+/// filters for saved queries for comments (see [`CommentQuery`])
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CommentFilters {
+    /// see [`CommentQuery::anonymous`]
+    pub anonymous: Option<bool>,
+    /// see [`CommentQuery::ignore_users`]
+    pub ignore_users: Option<Vec<String>>,
+    /// see [`CommentQuery::like`]
+    pub like: Option<String>,
+    /// see [`CommentQuery::packages`]
+    pub packages: Option<Vec<String>>,
+    /// see [`CommentQuery::search`]
+    pub search: Option<String>,
+    /// see [`CommentQuery::since`]
+    #[serde(default, with = "crate::option_bodhi_date_format")]
+    pub since: Option<BodhiDate>,
+    /// see [`CommentQuery::update_owners`]
+    pub update_owners: Option<Vec<String>>,
+    /// see [`CommentQuery::updates`]
+    pub updates: Option<Vec<String>>,
+    /// see [`CommentQuery::users`]
+    pub users: Option<Vec<String>>,
+}
+
+impl CommentFilters {
+    async fn run(&self, bodhi: &BodhiClient) -> Result<Vec<Comment>, QueryError> {
+        let ignore_users = strs(&self.ignore_users);
+        let packages = strs(&self.packages);
+        let update_owners = strs(&self.update_owners);
+        let updates = strs(&self.updates);
+        let users = strs(&self.users);
+
+        let mut query = CommentQuery::new();
+        if let Some(anonymous) = self.anonymous {
+            query = query.anonymous(anonymous);
+        }
+        if let Some(ignore_users) = &ignore_users {
+            query = query.ignore_users(ignore_users);
+        }
+        if let Some(like) = &self.like {
+            query = query.like(like);
+        }
+        if let Some(packages) = &packages {
+            query = query.packages(packages);
+        }
+        if let Some(search) = &self.search {
+            query = query.search(search);
+        }
+        if let Some(since) = &self.since {
+            query = query.since(since);
+        }
+        if let Some(update_owners) = &update_owners {
+            query = query.update_owners(update_owners);
+        }
+        if let Some(updates) = &updates {
+            query = query.updates(updates);
+        }
+        if let Some(users) = &users {
+            query = query.users(users);
+        }
+
+        bodhi.paginated_request(&query).await
+    }
+}
+
+
+/// filters for saved queries for running composes (see [`ComposeQuery`], which does not support
+/// any filters)
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ComposeFilters {}
+
+impl ComposeFilters {
+    async fn run(&self, bodhi: &BodhiClient) -> Result<Vec<Compose>, QueryError> {
+        bodhi.request(&ComposeQuery::new()).await
+    }
+}
+
+
+/// filters for saved queries for buildroot overrides (see [`OverrideQuery`])
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OverrideFilters {
+    /// see [`OverrideQuery::builds`]
+    pub builds: Option<Vec<String>>,
+    /// see [`OverrideQuery::expired`]
+    pub expired: Option<bool>,
+    /// see [`OverrideQuery::like`]
+    pub like: Option<String>,
+    /// see [`OverrideQuery::packages`]
+    pub packages: Option<Vec<String>>,
+    /// see [`OverrideQuery::releases`]
+    pub releases: Option<Vec<FedoraRelease>>,
+    /// see [`OverrideQuery::search`]
+    pub search: Option<String>,
+    /// see [`OverrideQuery::users`]
+    pub users: Option<Vec<String>>,
+}
+
+impl OverrideFilters {
+    async fn run(&self, bodhi: &BodhiClient) -> Result<Vec<Override>, QueryError> {
+        let builds = strs(&self.builds);
+        let packages = strs(&self.packages);
+        let users = strs(&self.users);
+
+        let mut query = OverrideQuery::new();
+        if let Some(builds) = &builds {
+            query = query.builds(builds);
+        }
+        if let Some(expired) = self.expired {
+            query = query.expired(expired);
+        }
+        if let Some(like) = &self.like {
+            query = query.like(like);
+        }
+        if let Some(packages) = &packages {
+            query = query.packages(packages);
+        }
+        if let Some(releases) = &self.releases {
+            query = query.releases(releases);
+        }
+        if let Some(search) = &self.search {
+            query = query.search(search);
+        }
+        if let Some(users) = &users {
+            query = query.users(users);
+        }
+
+        bodhi.paginated_request(&query).await
+    }
+}
+
+
+/// filters for saved queries for packages (see [`PackageQuery`])
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackageFilters {
+    /// see [`PackageQuery::like`]
+    pub like: Option<String>,
+    /// see [`PackageQuery::name`]
+    pub name: Option<String>,
+    /// see [`PackageQuery::search`]
+    pub search: Option<String>,
+}
+
+impl PackageFilters {
+    async fn run(&self, bodhi: &BodhiClient) -> Result<Vec<Package>, QueryError> {
+        let mut query = PackageQuery::new();
+        if let Some(like) = &self.like {
+            query = query.like(like);
+        }
+        if let Some(name) = &self.name {
+            query = query.name(name);
+        }
+        if let Some(search) = &self.search {
+            query = query.search(search);
+        }
+
+        bodhi.paginated_request(&query).await
+    }
+}
+
+
+/// filters for saved queries for releases (see [`ReleaseQuery`])
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReleaseFilters {
+    /// see [`ReleaseQuery::composed_by_bodhi`]
+    pub composed_by_bodhi: Option<bool>,
+    /// see [`ReleaseQuery::create_automatic_updates`]
+    pub create_automatic_updates: Option<bool>,
+    /// see [`ReleaseQuery::exclude_archived`]
+    pub exclude_archived: Option<bool>,
+    /// see [`ReleaseQuery::ids`]
+    pub ids: Option<Vec<String>>,
+    /// see [`ReleaseQuery::name`]
+    pub name: Option<String>,
+    /// see [`ReleaseQuery::packages`]
+    pub packages: Option<Vec<String>>,
+    /// see [`ReleaseQuery::updates`]
+    pub updates: Option<Vec<String>>,
+}
+
+impl ReleaseFilters {
+    async fn run(&self, bodhi: &BodhiClient) -> Result<Vec<Release>, QueryError> {
+        let ids = strs(&self.ids);
+        let packages = strs(&self.packages);
+        let updates = strs(&self.updates);
+
+        let mut query = ReleaseQuery::new();
+        if let Some(composed_by_bodhi) = self.composed_by_bodhi {
+            query = query.composed_by_bodhi(composed_by_bodhi);
+        }
+        if let Some(create_automatic_updates) = self.create_automatic_updates {
+            query = query.create_automatic_updates(create_automatic_updates);
+        }
+        if let Some(exclude_archived) = self.exclude_archived {
+            query = query.exclude_archived(exclude_archived);
+        }
+        if let Some(ids) = &ids {
+            query = query.ids(ids);
+        }
+        if let Some(name) = &self.name {
+            query = query.name(name);
+        }
+        if let Some(packages) = &packages {
+            query = query.packages(packages);
+        }
+        if let Some(updates) = &updates {
+            query = query.updates(updates);
+        }
+
+        bodhi.paginated_request(&query).await
+    }
+}
+
+
+/// filters for saved queries for updates (see [`UpdateQuery`])
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateFilters {
+    /// see [`UpdateQuery::active_releases`]
+    pub active_releases: Option<bool>,
+    /// see [`UpdateQuery::aliases`]
+    pub aliases: Option<Vec<String>>,
+    /// see [`UpdateQuery::bugs`]
+    pub bugs: Option<Vec<u32>>,
+    /// see [`UpdateQuery::builds`]
+    pub builds: Option<Vec<String>>,
+    /// see [`UpdateQuery::content_type`]
+    pub content_type: Option<ContentType>,
+    /// see [`UpdateQuery::critpath`]
+    pub critpath: Option<bool>,
+    /// see [`UpdateQuery::cves`]
+    pub cves: Option<Vec<String>>,
+    /// see [`UpdateQuery::like`]
+    pub like: Option<String>,
+    /// see [`UpdateQuery::locked`]
+    pub locked: Option<bool>,
+    /// see [`UpdateQuery::modified_before`]
+    #[serde(default, with = "crate::option_bodhi_date_format")]
+    pub modified_before: Option<BodhiDate>,
+    /// see [`UpdateQuery::modified_since`]
+    #[serde(default, with = "crate::option_bodhi_date_format")]
+    pub modified_since: Option<BodhiDate>,
+    /// see [`UpdateQuery::packages`]
+    pub packages: Option<Vec<String>>,
+    /// see [`UpdateQuery::pushed`]
+    pub pushed: Option<bool>,
+    /// see [`UpdateQuery::pushed_before`]
+    #[serde(default, with = "crate::option_bodhi_date_format")]
+    pub pushed_before: Option<BodhiDate>,
+    /// see [`UpdateQuery::pushed_since`]
+    #[serde(default, with = "crate::option_bodhi_date_format")]
+    pub pushed_since: Option<BodhiDate>,
+    /// see [`UpdateQuery::releases`]
+    pub releases: Option<Vec<FedoraRelease>>,
+    /// see [`UpdateQuery::request`]
+    pub request: Option<UpdateRequest>,
+    /// see [`UpdateQuery::search`]
+    pub search: Option<String>,
+    /// see [`UpdateQuery::severity`]
+    pub severity: Option<UpdateSeverity>,
+    /// see [`UpdateQuery::stable_before`]
+    #[serde(default, with = "crate::option_bodhi_date_format")]
+    pub stable_before: Option<BodhiDate>,
+    /// see [`UpdateQuery::stable_since`]
+    #[serde(default, with = "crate::option_bodhi_date_format")]
+    pub stable_since: Option<BodhiDate>,
+    /// see [`UpdateQuery::status`]
+    pub status: Option<UpdateStatus>,
+    /// see [`UpdateQuery::submitted_before`]
+    #[serde(default, with = "crate::option_bodhi_date_format")]
+    pub submitted_before: Option<BodhiDate>,
+    /// see [`UpdateQuery::submitted_since`]
+    #[serde(default, with = "crate::option_bodhi_date_format")]
+    pub submitted_since: Option<BodhiDate>,
+    /// see [`UpdateQuery::suggest`]
+    pub suggest: Option<UpdateSuggestion>,
+    /// see [`UpdateQuery::update_ids`]
+    pub update_ids: Option<Vec<String>>,
+    /// see [`UpdateQuery::update_type`]
+    pub update_type: Option<UpdateType>,
+    /// see [`UpdateQuery::users`]
+    pub users: Option<Vec<String>>,
+}
+
+impl UpdateFilters {
+    async fn run(&self, bodhi: &BodhiClient) -> Result<Vec<Update>, QueryError> {
+        let aliases = strs(&self.aliases);
+        let builds = strs(&self.builds);
+        let cves = strs(&self.cves);
+        let packages = strs(&self.packages);
+        let update_ids = strs(&self.update_ids);
+        let users = strs(&self.users);
+
+        let mut query = UpdateQuery::new();
+        if let Some(active_releases) = self.active_releases {
+            query = query.active_releases(active_releases);
+        }
+        if let Some(aliases) = &aliases {
+            query = query.aliases(aliases);
+        }
+        if let Some(bugs) = &self.bugs {
+            query = query.bugs(bugs);
+        }
+        if let Some(builds) = &builds {
+            query = query.builds(builds);
+        }
+        if let Some(content_type) = self.content_type {
+            query = query.content_type(content_type);
+        }
+        if let Some(critpath) = self.critpath {
+            query = query.critpath(critpath);
+        }
+        if let Some(cves) = &cves {
+            query = query.cves(cves);
+        }
+        if let Some(like) = &self.like {
+            query = query.like(like);
+        }
+        if let Some(locked) = self.locked {
+            query = query.locked(locked);
+        }
+        if let Some(modified_before) = &self.modified_before {
+            query = query.modified_before(modified_before);
+        }
+        if let Some(modified_since) = &self.modified_since {
+            query = query.modified_since(modified_since);
+        }
+        if let Some(packages) = &packages {
+            query = query.packages(packages);
+        }
+        if let Some(pushed) = self.pushed {
+            query = query.pushed(pushed);
+        }
+        if let Some(pushed_before) = &self.pushed_before {
+            query = query.pushed_before(pushed_before);
+        }
+        if let Some(pushed_since) = &self.pushed_since {
+            query = query.pushed_since(pushed_since);
+        }
+        if let Some(releases) = &self.releases {
+            query = query.releases(releases);
+        }
+        if let Some(request) = self.request {
+            query = query.request(request);
+        }
+        if let Some(search) = &self.search {
+            query = query.search(search);
+        }
+        if let Some(severity) = self.severity {
+            query = query.severity(severity);
+        }
+        if let Some(stable_before) = &self.stable_before {
+            query = query.stable_before(stable_before);
+        }
+        if let Some(stable_since) = &self.stable_since {
+            query = query.stable_since(stable_since);
+        }
+        if let Some(status) = self.status {
+            query = query.status(status);
+        }
+        if let Some(submitted_before) = &self.submitted_before {
+            query = query.submitted_before(submitted_before);
+        }
+        if let Some(submitted_since) = &self.submitted_since {
+            query = query.submitted_since(submitted_since);
+        }
+        if let Some(suggest) = self.suggest {
+            query = query.suggest(suggest);
+        }
+        if let Some(update_ids) = &update_ids {
+            query = query.update_ids(update_ids);
+        }
+        if let Some(update_type) = self.update_type {
+            query = query.update_type(update_type);
+        }
+        if let Some(users) = &users {
+            query = query.users(users);
+        }
+
+        bodhi.paginated_request(&query).await
+    }
+}
+
+
+/// filters for saved queries for users (see [`UserQuery`])
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserFilters {
+    /// see [`UserQuery::groups`]
+    pub groups: Option<Vec<String>>,
+    /// see [`UserQuery::like`]
+    pub like: Option<String>,
+    /// see [`UserQuery::name`]
+    pub name: Option<String>,
+    /// see [`UserQuery::search`]
+    pub search: Option<String>,
+    /// see [`UserQuery::updates`]
+    pub updates: Option<Vec<String>>,
+}
+
+impl UserFilters {
+    async fn run(&self, bodhi: &BodhiClient) -> Result<Vec<User>, QueryError> {
+        let groups = strs(&self.groups);
+        let updates = strs(&self.updates);
+
+        let mut query = UserQuery::new();
+        if let Some(groups) = &groups {
+            query = query.groups(groups);
+        }
+        if let Some(like) = &self.like {
+            query = query.like(like);
+        }
+        if let Some(name) = &self.name {
+            query = query.name(name);
+        }
+        if let Some(search) = &self.search {
+            query = query.search(search);
+        }
+        if let Some(updates) = &updates {
+            query = query.updates(updates);
+        }
+
+        bodhi.paginated_request(&query).await
+    }
+}
@@ -0,0 +1,43 @@
+use chrono::Duration;
+
+use crate::analysis::*;
+use crate::{BodhiDate, Update};
+
+const UPDATE_JSON: &str = include_str!("data/update.json");
+
+#[test]
+fn latency_percentiles() {
+    let durations = (1..=10).map(Duration::hours).collect();
+    let stats = LatencyStats::from_durations(durations).unwrap();
+
+    assert_eq!(stats.count, 10);
+    assert_eq!(stats.min, Duration::hours(1));
+    assert_eq!(stats.median, Duration::hours(5));
+    assert_eq!(stats.p90, Duration::hours(9));
+    assert_eq!(stats.p95, Duration::hours(10));
+    assert_eq!(stats.max, Duration::hours(10));
+
+    assert_eq!(LatencyStats::from_durations(Vec::new()), None);
+}
+
+#[test]
+fn update_latencies() {
+    let testing: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    let mut stable: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    stable.date_stable = Some(BodhiDate::try_from("2024-05-09 01:00:00").unwrap());
+
+    // inconsistent dates are ignored
+    let mut broken: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    broken.date_stable = Some(BodhiDate::try_from("2024-05-01 00:00:00").unwrap());
+
+    let stats = update_latency_stats(&[testing, stable, broken]);
+
+    let to_testing = stats.submitted_to_testing.unwrap();
+    assert_eq!(to_testing.count, 3);
+    assert_eq!(to_testing.median, Duration::hours(13));
+
+    let to_stable = stats.testing_to_stable.unwrap();
+    assert_eq!(to_stable.count, 1);
+    assert_eq!(to_stable.median, Duration::days(7));
+}
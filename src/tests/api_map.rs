@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use serde::Deserialize;
+
+use crate::api_map::{endpoint, endpoint_for, EndpointMethod, ENDPOINTS};
+
+// vendored list of bodhi REST API endpoints and their parameters
+const API_JSON: &str = include_str!("data/bodhi-api.json");
+
+// endpoints that are not implemented yet
+const MISSING_ENDPOINTS: &[(&str, &str)] = &[
+    ("GET", "/api_version"),
+    ("GET", "/latest_builds"),
+    ("GET", "/latest_candidates"),
+    ("GET", "/markdown"),
+    ("POST", "/releases/"),
+    ("POST", "/updates/{id}/trigger-tests"),
+];
+
+// parameters of implemented endpoints that are not supported yet
+const MISSING_PARAMETERS: &[(&str, &str, &str)] = &[
+    ("POST", "/comments/", "karma_critpath"),
+    ("GET", "/releases/", "state"),
+    ("GET", "/updates/", "from_side_tag"),
+    ("GET", "/updates/", "gating"),
+];
+
+// parameters that are sent by this crate, but that are not known to bodhi servers
+const UNKNOWN_PARAMETERS: &[(&str, &str, &str)] = &[];
+
+#[derive(Deserialize)]
+struct ApiSpec {
+    endpoints: Vec<ApiEndpoint>,
+}
+
+#[derive(Deserialize)]
+struct ApiEndpoint {
+    method: String,
+    path: String,
+    parameters: Vec<String>,
+}
+
+fn spec() -> ApiSpec {
+    serde_json::from_str(API_JSON).unwrap()
+}
+
+#[test]
+fn implemented_endpoints_exist() {
+    let spec = spec();
+
+    for implemented in ENDPOINTS {
+        let method = implemented.method.to_string();
+
+        let known = spec
+            .endpoints
+            .iter()
+            .find(|e| e.method == method && e.path == implemented.path)
+            .unwrap_or_else(|| panic!("Unknown endpoint: {implemented}"));
+
+        for param in implemented.parameters {
+            if UNKNOWN_PARAMETERS.contains(&(method.as_str(), implemented.path, *param)) {
+                assert!(!known.parameters.iter().any(|p| p == param));
+                continue;
+            }
+
+            assert!(
+                known.parameters.iter().any(|p| p == param),
+                "Unknown parameter for {implemented}: {param}"
+            );
+        }
+    }
+}
+
+#[test]
+fn known_endpoints_implemented() {
+    let spec = spec();
+
+    for known in &spec.endpoints {
+        let implemented: Vec<_> = ENDPOINTS
+            .iter()
+            .filter(|e| e.method.to_string() == known.method && e.path == known.path)
+            .collect();
+
+        if MISSING_ENDPOINTS.contains(&(known.method.as_str(), known.path.as_str())) {
+            assert!(
+                implemented.is_empty(),
+                "Endpoint is implemented: {} {}",
+                known.method,
+                known.path
+            );
+            continue;
+        }
+
+        assert!(
+            !implemented.is_empty(),
+            "Endpoint is not implemented: {} {}",
+            known.method,
+            known.path
+        );
+
+        let supported: HashSet<&str> = implemented.iter().flat_map(|e| e.parameters.iter().copied()).collect();
+
+        for param in &known.parameters {
+            let missing = MISSING_PARAMETERS.contains(&(known.method.as_str(), known.path.as_str(), param.as_str()));
+
+            assert_eq!(
+                supported.contains(param.as_str()),
+                !missing,
+                "Parameter support does not match for {} {}: {}",
+                known.method,
+                known.path,
+                param
+            );
+        }
+    }
+}
+
+#[test]
+fn endpoint_lookup() {
+    let query = endpoint(EndpointMethod::GET, "/updates/{id}").unwrap();
+    assert_eq!(query.request_type, "UpdateIDQuery");
+    assert!(query.parameters.is_empty());
+
+    let editor = endpoint_for("UpdateEditor").unwrap();
+    assert_eq!(editor.method, EndpointMethod::POST);
+    assert_eq!(editor.path, "/updates/");
+    assert!(editor.parameters.contains(&"edited"));
+
+    assert!(endpoint(EndpointMethod::POST, "/csrf").is_none());
+    assert!(endpoint_for("FooQuery").is_none());
+}
@@ -0,0 +1,61 @@
+use crate::badge::UpdateBadge;
+use crate::{BodhiDate, TestGatingStatus, Update, UpdateStatus};
+
+const UPDATE_JSON: &str = include_str!("data/update.json");
+
+#[test]
+fn badge_from_update() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    let now = BodhiDate::try_from("2024-05-05 12:00:00").unwrap();
+
+    let badge = UpdateBadge::new(&update, &now);
+    assert_eq!(badge.alias, "FEDORA-2024-1a2b3c4d5e");
+    assert_eq!(badge.status, UpdateStatus::Testing);
+    assert_eq!(badge.karma, 0);
+    assert_eq!(badge.gating, Some(TestGatingStatus::Passed));
+    assert_eq!(badge.days_in_testing, Some(3));
+
+    update.status = UpdateStatus::Stable;
+    assert_eq!(UpdateBadge::new(&update, &now).days_in_testing, None);
+}
+
+#[test]
+fn badge_display() {
+    let update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    let now = BodhiDate::try_from("2024-05-05 12:00:00").unwrap();
+
+    let mut badge = UpdateBadge::new(&update, &now);
+    badge.karma = 2;
+    assert_eq!(
+        badge.to_string(),
+        "FEDORA-2024-1a2b3c4d5e: testing, karma +2, gating passed, 3 days in testing"
+    );
+    assert_eq!(
+        format!("{badge:#}"),
+        "[`FEDORA-2024-1a2b3c4d5e`](https://bodhi.fedoraproject.org/updates/FEDORA-2024-1a2b3c4d5e): \
+         **testing**, karma +2, gating passed, 3 days in testing"
+    );
+
+    badge.karma = -1;
+    badge.gating = None;
+    badge.days_in_testing = Some(1);
+    assert_eq!(
+        badge.to_string(),
+        "FEDORA-2024-1a2b3c4d5e: testing, karma -1, 1 day in testing"
+    );
+}
+
+#[test]
+fn badge_serde() {
+    let update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    let now = BodhiDate::try_from("2024-05-05 12:00:00").unwrap();
+
+    let badge = UpdateBadge::new(&update, &now);
+    let json = serde_json::to_value(&badge).unwrap();
+    assert_eq!(json["status"], "testing");
+    assert_eq!(json["gating"], "passed");
+    assert_eq!(json["days_in_testing"], 3);
+
+    let parsed: UpdateBadge = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, badge);
+}
@@ -84,5 +84,5 @@ async fn nvr_query_err() {
 
     let build = bodhi.request(&BuildNVRQuery::new("this-doesnt-exist-1-1.fc30")).await;
 
-    assert!(matches!(build, Err(QueryError::NotFound)));
+    assert!(matches!(build, Err(QueryError::NotFound { .. })));
 }
@@ -81,5 +81,5 @@ async fn id_query_err() {
 
     let comment = bodhi.request(&CommentIDQuery::new(999_999_999)).await;
 
-    assert!(matches!(comment, Err(QueryError::NotFound)));
+    assert!(matches!(comment, Err(QueryError::NotFound { .. })));
 }
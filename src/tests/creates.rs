@@ -0,0 +1,161 @@
+use crate::request::{KarmaCheck, RetryCheck, SingleRequest};
+use crate::{
+    BodhiDate,
+    BugFeedbackData,
+    CommentCreator,
+    DuplicateKarma,
+    Karma,
+    OverrideCreator,
+    QueryError,
+    TestCaseFeedbackData,
+    Update,
+    UpdateCreator,
+};
+
+const UPDATE_JSON: &str = include_str!("data/update.json");
+
+#[test]
+fn override_creator_retry_check() {
+    let expiration_date = BodhiDate::try_from("2030-01-01").unwrap();
+
+    let creator = OverrideCreator::new("rust-bodhi-2.2.0-1.fc40", "notes", &expiration_date);
+
+    assert!(matches!(
+        creator.retry_check(),
+        Some(RetryCheck::Override {
+            nvr: "rust-bodhi-2.2.0-1.fc40",
+            notes: "notes",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn override_creator_for_update() {
+    let expiration_date = BodhiDate::try_from("2030-01-01").unwrap();
+
+    let mut value: serde_json::Value = serde_json::from_str(UPDATE_JSON).unwrap();
+    let mut build = value["builds"][0].clone();
+    build["nvr"] = serde_json::json!("foo-libs-1.2.3-1.fc40");
+    value["builds"].as_array_mut().unwrap().push(build);
+    let update: Update = serde_json::from_value(value).unwrap();
+
+    let creators = OverrideCreator::for_update(&update, "notes", &expiration_date).unwrap();
+    assert_eq!(creators.len(), 2);
+
+    for (creator, build) in creators.iter().zip(&update.builds) {
+        let body = creator.body(Some(String::from("token"))).unwrap().unwrap();
+        let data: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(data["nvr"], build.nvr.as_str());
+        assert_eq!(data["notes"], "notes");
+        assert_eq!(data["expiration_date"], "2030-01-01 00:00:00");
+    }
+
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    update.builds.clear();
+    assert!(matches!(
+        OverrideCreator::for_update(&update, "notes", &expiration_date),
+        Err(QueryError::InvalidDataError { .. })
+    ));
+}
+
+#[test]
+fn update_creator_retry_check() {
+    let builds = ["rust-bodhi-2.2.0-1.fc40"];
+
+    let from_builds = UpdateCreator::from_builds(&builds, "notes");
+    let from_tag = UpdateCreator::from_tag("f40-build-side-12345", "notes");
+
+    assert!(matches!(
+        from_builds.retry_check(),
+        Some(RetryCheck::Update {
+            builds: ["rust-bodhi-2.2.0-1.fc40"]
+        })
+    ));
+    assert!(from_tag.retry_check().is_none());
+}
+
+#[test]
+fn comment_creator_feedback() {
+    let update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    let bugs: Vec<BugFeedbackData> = update.bugs.iter().map(|bug| bug.feedback(Karma::Positive)).collect();
+    assert_eq!(bugs[0], BugFeedbackData::new(update.bugs[0].bug_id, Karma::Positive));
+    assert_eq!(bugs[0].karma(), Karma::Positive);
+
+    let testcases = [TestCaseFeedbackData::new("QA:Testcase_base_startup", Karma::Negative)];
+    assert_eq!(testcases[0].testcase_name(), "QA:Testcase_base_startup");
+
+    let creator = update.comment().bug_feedback(&bugs).testcase_feedback(&testcases);
+    let body = creator.body(Some(String::from("token"))).unwrap().unwrap();
+    assert!(body.contains(r#""bug_feedback.0.karma":"1""#));
+    assert!(body.contains(r#""testcase_feedback.0.karma":"-1""#));
+}
+
+#[test]
+fn comment_creator_karma_check() {
+    let alias = "FEDORA-2024-1a2b3c4d5e";
+
+    // existing karma is not checked by default, or for comments without karma
+    assert!(CommentCreator::new(alias)
+        .karma(Karma::Positive)
+        .karma_check()
+        .is_none());
+    assert!(CommentCreator::new(alias)
+        .karma(Karma::Neutral)
+        .duplicate_karma(DuplicateKarma::Reject)
+        .karma_check()
+        .is_none());
+
+    let creator = CommentCreator::new(alias)
+        .karma(Karma::Negative)
+        .duplicate_karma(DuplicateKarma::Neutral);
+    assert!(matches!(
+        creator.karma_check(),
+        Some(KarmaCheck {
+            update: "FEDORA-2024-1a2b3c4d5e",
+            reject: false
+        })
+    ));
+
+    let creator = CommentCreator::new(alias)
+        .karma(Karma::Positive)
+        .duplicate_karma(DuplicateKarma::Reject);
+    assert!(matches!(creator.karma_check(), Some(KarmaCheck { reject: true, .. })));
+
+    // karma is replaced with neutral karma in the request body
+    let body = creator.body(Some(String::from("token"))).unwrap();
+    let body = KarmaCheck::neutralize(body).unwrap().unwrap();
+    let value: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(value["karma"], 0);
+    assert_eq!(value["update"], alias);
+}
+
+#[test]
+fn comment_creator_invalid_feedback() {
+    let token = || Some(String::from("token"));
+
+    let invalid = [BugFeedbackData::new(0, Karma::Positive)];
+    let creator = CommentCreator::new("FEDORA-2024-1a2b3c4d5e").bug_feedback(&invalid);
+    assert!(matches!(
+        creator.body(token()),
+        Err(QueryError::InvalidDataError { .. })
+    ));
+
+    let duplicate = [
+        BugFeedbackData::new(12345, Karma::Positive),
+        BugFeedbackData::new(12345, Karma::Negative),
+    ];
+    let creator = CommentCreator::new("FEDORA-2024-1a2b3c4d5e").bug_feedback(&duplicate);
+    assert!(matches!(
+        creator.body(token()),
+        Err(QueryError::InvalidDataError { .. })
+    ));
+
+    let empty = [TestCaseFeedbackData::new(" ", Karma::Positive)];
+    let creator = CommentCreator::new("FEDORA-2024-1a2b3c4d5e").testcase_feedback(&empty);
+    assert!(matches!(
+        creator.body(token()),
+        Err(QueryError::InvalidDataError { .. })
+    ));
+}
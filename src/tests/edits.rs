@@ -0,0 +1,336 @@
+use crate::error::QueryError;
+use crate::request::SingleRequest;
+use crate::{
+    BodhiDate,
+    Override,
+    OverrideEditor,
+    OverrideExpirer,
+    ReleaseState,
+    Update,
+    UpdateEditor,
+    UpdateRequest,
+    UpdateRequestCaveat,
+    UpdateStatus,
+    UpdateStatusRequester,
+    UpdateUntagger,
+};
+
+const UPDATE_JSON: &str = include_str!("data/update.json");
+
+#[test]
+fn override_editor_from_nvr() {
+    let expiration_date = BodhiDate::try_from("2030-01-01").unwrap();
+
+    let editor = OverrideEditor::from_nvr("rust-bodhi-2.2.0-1.fc40")
+        .notes("new notes")
+        .expiration_date(&expiration_date);
+
+    let body = editor.body(Some(String::from("token"))).unwrap().unwrap();
+    let value: serde_json::Value = serde_json::from_str(&body).unwrap();
+
+    assert_eq!(value["nvr"], "rust-bodhi-2.2.0-1.fc40");
+    assert_eq!(value["edited"], "rust-bodhi-2.2.0-1.fc40");
+    assert_eq!(value["notes"], "new notes");
+    assert_eq!(value["expiration_date"], "2030-01-01 00:00:00");
+}
+
+#[test]
+fn override_editor_unresolved() {
+    let expiration_date = BodhiDate::try_from("2030-01-01").unwrap();
+
+    let editor = OverrideEditor::from_nvr("rust-bodhi-2.2.0-1.fc40").expiration_date(&expiration_date);
+
+    assert!(matches!(
+        editor.body(Some(String::from("token"))),
+        Err(QueryError::InvalidDataError { .. })
+    ));
+}
+
+#[test]
+fn override_expirer() {
+    let over_ride: Override = serde_json::from_value(
+        serde_json::from_str::<serde_json::Value>(crate::mock::fixture("override.json").unwrap()).unwrap()["override"]
+            .clone(),
+    )
+    .unwrap();
+
+    let expirer = over_ride.expire();
+    let body = expirer.body(Some(String::from("token"))).unwrap().unwrap();
+    let value: serde_json::Value = serde_json::from_str(&body).unwrap();
+
+    assert_eq!(value["nvr"], "foo-1.2.3-1.fc38");
+    assert_eq!(value["edited"], "foo-1.2.3-1.fc38");
+    assert_eq!(value["notes"], over_ride.notes);
+    assert_eq!(value["expiration_date"], "2024-05-09 12:00:00");
+    assert_eq!(value["expired"], true);
+
+    // notes and expiration date need to be resolved before expiring the override
+    let expirer = OverrideExpirer::from_nvr("foo-1.2.3-1.fc38");
+    assert!(matches!(
+        expirer.body(Some(String::from("token"))),
+        Err(QueryError::InvalidDataError { .. })
+    ));
+}
+
+#[test]
+fn update_editor_conflict_check() {
+    let update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    assert!(UpdateEditor::from_update(&update).conflict_check().is_none());
+
+    let editor = UpdateEditor::from_update(&update).check_conflicts(true);
+    let check = editor.conflict_check().unwrap();
+
+    let unchanged: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    assert!(check.conflict(&unchanged).is_none());
+
+    let mut modified: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    modified.date_modified = Some(BodhiDate::try_from("2030-01-01").unwrap());
+
+    let conflict = check.conflict(&modified).unwrap();
+    assert_eq!(conflict.alias, update.alias);
+    assert_eq!(conflict.expected_date_modified, update.date_modified);
+    assert_eq!(conflict.actual_date_modified, modified.date_modified);
+
+    let mut modified: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    modified.version_hash = String::from("0123456789abcdef0123456789abcdef01234567");
+
+    let conflict = check.conflict(&modified).unwrap();
+    assert_eq!(conflict.expected_version_hash, update.version_hash);
+    assert_eq!(conflict.actual_version_hash, modified.version_hash);
+}
+
+#[test]
+fn update_editor_roundtrip() {
+    let update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    let editor = UpdateEditor::try_from(&update).unwrap();
+    let body = editor.body(Some(String::from("token"))).unwrap().unwrap();
+    let data: serde_json::Value = serde_json::from_str(&body).unwrap();
+
+    let builds: Vec<&str> = update.builds.iter().map(|build| build.nvr.as_str()).collect();
+    let bugs: Vec<String> = update.bugs.iter().map(|bug| bug.bug_id.to_string()).collect();
+
+    assert_eq!(data["builds"], serde_json::json!(builds));
+    assert_eq!(data["bugs"], serde_json::json!(bugs));
+    assert!(data.get("from_tag").is_none());
+    assert_eq!(data["edited"], update.alias.as_str());
+    assert_eq!(data["notes"], update.notes.as_str());
+    assert_eq!(data["display_name"], update.display_name.as_str());
+    assert_eq!(data["close_bugs"], update.close_bugs);
+    assert_eq!(data["type"], serde_json::to_value(update.update_type).unwrap());
+    assert_eq!(data["request"], serde_json::to_value(update.request).unwrap());
+    assert_eq!(data["severity"], serde_json::to_value(update.severity).unwrap());
+    assert_eq!(data["suggest"], serde_json::to_value(update.suggest).unwrap());
+    assert_eq!(data["autokarma"], update.autokarma);
+    assert_eq!(data["stable_karma"], serde_json::json!(update.stable_karma));
+    assert_eq!(data["unstable_karma"], serde_json::json!(update.unstable_karma));
+    assert_eq!(data["requirements"], serde_json::json!(update.requirements));
+    assert_eq!(data["require_bugs"], update.require_bugs);
+    assert_eq!(data["require_testcases"], update.require_testcases);
+    assert_eq!(data["autotime"], update.autotime);
+    assert_eq!(data["stable_days"], serde_json::json!(update.stable_days));
+
+    // only explicitly changed fields are modified
+    let editor = UpdateEditor::try_from(&update).unwrap().stable_karma(5);
+    let body = editor.body(Some(String::from("token"))).unwrap().unwrap();
+    let edited: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(edited["stable_karma"], 5);
+    assert_eq!(edited["notes"], data["notes"]);
+    assert_eq!(edited["severity"], data["severity"]);
+}
+
+#[test]
+fn update_editor_side_tag() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    update.from_tag = Some(String::from("f40-build-side-12345"));
+
+    let editor = UpdateEditor::try_from(&update).unwrap();
+    let body = editor.body(Some(String::from("token"))).unwrap().unwrap();
+    let data: serde_json::Value = serde_json::from_str(&body).unwrap();
+
+    assert_eq!(data["from_tag"], "f40-build-side-12345");
+    assert!(data.get("builds").is_none());
+}
+
+#[test]
+fn update_editor_builds() {
+    let update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    let editor = UpdateEditor::try_from(&update).unwrap();
+    assert!(!editor.resets_karma());
+
+    let editor = UpdateEditor::try_from(&update)
+        .unwrap()
+        .add_builds(&["bar-2.0-1.fc40", "foo-1.2.3-1.fc40", "bar-2.0-1.fc40", "baz-0.1-3.fc40"])
+        .remove_builds(&["baz-0.1-3.fc40"])
+        .replace_build("foo-1.2.3-1.fc40", "foo-1.2.4-1.fc40");
+    assert!(editor.resets_karma());
+
+    let body = editor.body(Some(String::from("token"))).unwrap().unwrap();
+    let data: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(
+        data["builds"],
+        serde_json::json!(["foo-1.2.4-1.fc40", "bar-2.0-1.fc40"])
+    );
+
+    // replacing a build with a build that is already present does not duplicate it
+    let editor = UpdateEditor::try_from(&update)
+        .unwrap()
+        .add_build("bar-2.0-1.fc40")
+        .add_build("bar-2.0-1.fc40")
+        .replace_build("foo-1.2.3-1.fc40", "bar-2.0-1.fc40");
+
+    let body = editor.body(Some(String::from("token"))).unwrap().unwrap();
+    let data: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(data["builds"], serde_json::json!(["bar-2.0-1.fc40"]));
+
+    // reordering builds does not reset karma
+    let editor = UpdateEditor::try_from(&update)
+        .unwrap()
+        .add_build("bar-2.0-1.fc40")
+        .remove_build("foo-1.2.3-1.fc40")
+        .add_build("foo-1.2.3-1.fc40")
+        .remove_build("bar-2.0-1.fc40");
+    assert!(!editor.resets_karma());
+}
+
+#[test]
+fn update_editor_invalid_builds() {
+    let update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    for invalid in [
+        "foo",
+        "foo-1.0",
+        "foo--1.fc40",
+        "-1.0-1.fc40",
+        "foo-1.0-",
+        "foo bar-1.0-1.fc40",
+    ] {
+        let editor = UpdateEditor::try_from(&update).unwrap().add_build(invalid);
+        assert!(
+            matches!(
+                editor.body(Some(String::from("token"))),
+                Err(QueryError::InvalidDataError { .. })
+            ),
+            "{invalid} was accepted"
+        );
+    }
+
+    let editor = UpdateEditor::try_from(&update)
+        .unwrap()
+        .remove_build("foo-1.2.3-1.fc40");
+    assert!(matches!(
+        editor.body(Some(String::from("token"))),
+        Err(QueryError::InvalidDataError { .. })
+    ));
+
+    let editor = UpdateEditor::try_from(&update)
+        .unwrap()
+        .add_build("python-foo-bar-1.0-0.1.rc1.fc40");
+    assert!(editor.body(Some(String::from("token"))).is_ok());
+}
+
+#[test]
+fn update_editor_locked() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    update.locked = true;
+
+    assert!(matches!(
+        UpdateEditor::try_from(&update),
+        Err(QueryError::InvalidDataError { .. })
+    ));
+}
+
+#[test]
+fn update_status_requester_transitions() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    assert_eq!(update.status, UpdateStatus::Testing);
+
+    let requester = UpdateStatusRequester::from_update(&update, UpdateRequest::Stable);
+    let body = requester.body(Some(String::from("token"))).unwrap().unwrap();
+    let value: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(value["request"], "stable");
+
+    // revoking is only valid for updates with a pending request
+    let requester = UpdateStatusRequester::from_update(&update, UpdateRequest::Revoke);
+    assert!(matches!(
+        requester.body(Some(String::from("token"))),
+        Err(QueryError::InvalidDataError { .. })
+    ));
+
+    update.request = Some(UpdateRequest::Stable);
+    let requester = UpdateStatusRequester::from_update(&update, UpdateRequest::Revoke);
+    assert!(requester.body(Some(String::from("token"))).is_ok());
+
+    update.status = UpdateStatus::Pending;
+    update.request = None;
+    let requester = UpdateStatusRequester::from_update(&update, UpdateRequest::Stable);
+    match requester.body(Some(String::from("token"))) {
+        Err(QueryError::InvalidDataError { error }) => {
+            assert!(error.contains("status 'pending'"));
+            assert!(error.contains("allowed requests: testing, obsolete"));
+        },
+        _ => panic!("Expected request to be rejected."),
+    }
+
+    update.status = UpdateStatus::Stable;
+    let requester = UpdateStatusRequester::from_update(&update, UpdateRequest::Revoke);
+    match requester.body(Some(String::from("token"))) {
+        Err(QueryError::InvalidDataError { error }) => assert!(error.contains("allowed requests: none")),
+        _ => panic!("Expected request to be rejected."),
+    }
+}
+
+#[test]
+fn update_untagger() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    assert_eq!(update.status, UpdateStatus::Testing);
+
+    let untagger = update.untag();
+    assert_eq!(untagger.path().unwrap(), "/updates/FEDORA-2024-1a2b3c4d5e/request");
+    let body = untagger.body(Some(String::from("token"))).unwrap().unwrap();
+    let value: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(value["request"], "unpush");
+    assert_eq!(value["csrf_token"], "token");
+
+    update.locked = true;
+    match UpdateUntagger::from_update(&update).body(Some(String::from("token"))) {
+        Err(QueryError::InvalidDataError { error }) => assert!(error.contains("locked")),
+        _ => panic!("Expected request to be rejected."),
+    }
+
+    update.locked = false;
+    for status in [UpdateStatus::Pending, UpdateStatus::Unpushed, UpdateStatus::Stable] {
+        update.status = status;
+        match UpdateUntagger::from_update(&update).body(Some(String::from("token"))) {
+            Err(QueryError::InvalidDataError { error }) => assert!(error.contains(&format!("status '{status}'"))),
+            _ => panic!("Expected request to be rejected."),
+        }
+    }
+}
+
+#[test]
+fn update_status_requester_frozen_release() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    assert!(!update.release.is_frozen());
+    assert!(UpdateStatusRequester::from_update(&update, UpdateRequest::Stable)
+        .caveats()
+        .is_empty());
+
+    update.release.state = ReleaseState::Frozen;
+    assert!(update.release.is_frozen());
+
+    // stable requests for frozen releases are still valid, but come with a caveat
+    let requester = UpdateStatusRequester::from_update(&update, UpdateRequest::Stable);
+    assert_eq!(
+        requester.caveats(),
+        vec![UpdateRequestCaveat::FrozenRelease {
+            release: update.release.name.clone()
+        }]
+    );
+    assert!(requester.body(Some(String::from("token"))).is_ok());
+
+    let requester = UpdateStatusRequester::from_update(&update, UpdateRequest::Obsolete);
+    assert!(requester.caveats().is_empty());
+}
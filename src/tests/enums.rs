@@ -1,5 +1,22 @@
 use crate::data::*;
 
+#[test]
+fn idem_comment_kind() {
+    use CommentKind::*;
+
+    let strings = vec!["bodhi-bot", "ci-system", "user"];
+
+    let values = vec![BodhiBot, CISystem, User];
+
+    for string in strings {
+        assert_eq!(string.parse::<CommentKind>().unwrap().to_string(), string);
+    }
+
+    for value in values {
+        assert_eq!(value.to_string().parse::<CommentKind>().unwrap(), value);
+    }
+}
+
 #[test]
 fn idem_compose_request() {
     use ComposeRequest::*;
@@ -75,6 +92,98 @@ fn idem_content_type() {
     }
 }
 
+#[test]
+fn idem_critpath_group() {
+    use CritpathGroup::*;
+
+    let strings = vec![
+        "critical-path-apps",
+        "critical-path-base",
+        "critical-path-build",
+        "critical-path-compose",
+        "critical-path-deployment",
+        "critical-path-gnome",
+        "critical-path-kde",
+        "critical-path-server",
+        "critical-path-standard",
+        "critical-path-unknown",
+    ];
+
+    let values = vec![
+        Apps,
+        Base,
+        Build,
+        Compose,
+        Deployment,
+        Gnome,
+        KDE,
+        Server,
+        Standard,
+        Other(String::from("critical-path-unknown")),
+    ];
+
+    for string in strings {
+        assert_eq!(CritpathGroup::from(string).to_string(), string);
+    }
+
+    for value in values {
+        assert_eq!(CritpathGroup::from(value.to_string().as_str()), value);
+    }
+}
+
+#[test]
+fn idem_id_prefix() {
+    use IdPrefix::*;
+
+    let strings = vec![
+        "FEDORA",
+        "FEDORA-CONTAINER",
+        "FEDORA-FLATPAK",
+        "FEDORA-MODULAR",
+        "FEDORA-EPEL",
+        "FEDORA-EPEL-MODULAR",
+        "FEDORA-EPEL-NEXT",
+        "FEDORA-ELN",
+    ];
+
+    let values = vec![
+        Fedora,
+        FedoraContainer,
+        FedoraFlatpak,
+        FedoraModular,
+        Epel,
+        EpelModular,
+        EpelNext,
+        Other(String::from("FEDORA-ELN")),
+    ];
+
+    for string in strings {
+        assert_eq!(IdPrefix::from(string).to_string(), string);
+    }
+
+    for value in values {
+        assert_eq!(IdPrefix::from(value.to_string().as_str()), value);
+    }
+}
+
+#[test]
+fn id_prefix_properties() {
+    assert_eq!(IdPrefix::Fedora.content_type(), Some(ContentType::RPM));
+    assert_eq!(IdPrefix::FedoraContainer.content_type(), Some(ContentType::Container));
+    assert_eq!(IdPrefix::EpelModular.content_type(), Some(ContentType::Module));
+    assert_eq!(IdPrefix::from("FEDORA-ELN").content_type(), None);
+
+    assert!(!IdPrefix::FedoraFlatpak.is_epel());
+    assert!(IdPrefix::EpelNext.is_epel());
+    assert!(IdPrefix::from("FEDORA-EPEL-FOO").is_epel());
+    assert!(!IdPrefix::from("FEDORA-ELN").is_epel());
+
+    // prefixes are (de)serialized as plain strings
+    let prefix: IdPrefix = serde_json::from_str("\"FEDORA-EPEL\"").unwrap();
+    assert_eq!(prefix, IdPrefix::Epel);
+    assert_eq!(serde_json::to_string(&prefix).unwrap(), "\"FEDORA-EPEL\"");
+}
+
 #[test]
 fn idem_karma() {
     use Karma::*;
@@ -96,9 +205,9 @@ fn idem_karma() {
 fn idem_package_manager() {
     use PackageManager::*;
 
-    let strings = vec!["dnf", "yum"];
+    let strings = vec!["dnf", "unspecified", "yum"];
 
-    let values = vec![DNF, YUM];
+    let values = vec![DNF, Unspecified, YUM];
 
     for string in strings {
         assert_eq!(string.parse::<PackageManager>().unwrap().to_string(), string);
@@ -126,6 +235,39 @@ fn idem_release_state() {
     }
 }
 
+#[test]
+fn idem_release_tag() {
+    use ReleaseTag::*;
+
+    let strings = vec![
+        "candidate",
+        "override",
+        "pending_signing",
+        "pending_stable",
+        "pending_testing",
+        "stable",
+        "testing",
+    ];
+
+    let values = vec![
+        Candidate,
+        Override,
+        PendingSigning,
+        PendingStable,
+        PendingTesting,
+        Stable,
+        Testing,
+    ];
+
+    for string in strings {
+        assert_eq!(string.parse::<ReleaseTag>().unwrap().to_string(), string);
+    }
+
+    for value in values {
+        assert_eq!(value.to_string().parse::<ReleaseTag>().unwrap(), value);
+    }
+}
+
 #[test]
 fn idem_test_gating_status() {
     use TestGatingStatus::*;
@@ -0,0 +1,46 @@
+use crate::errata::render;
+use crate::{PackageManager, Update, UpdateStatus, UpdateType};
+
+const UPDATE_JSON: &str = include_str!("data/update.json");
+
+#[test]
+fn errata_subject() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    assert_eq!(render(&update).subject, "Fedora 40 Test Update: foo-1.2.3-1.fc40");
+
+    update.status = UpdateStatus::Stable;
+    update.update_type = UpdateType::Security;
+    assert_eq!(render(&update).subject, "[SECURITY] Fedora 40 Update: foo-1.2.3-1.fc40");
+}
+
+#[test]
+fn errata_body() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    let errata = render(&update);
+    let lines: Vec<&str> = errata.body.lines().collect();
+    assert_eq!(lines[1], "Fedora Test Update Notification");
+    assert_eq!(lines[2], "FEDORA-2024-1a2b3c4d5e");
+
+    assert!(errata.body.contains("Name        : foo\n"));
+    assert!(errata.body.contains("Version     : 1.2.3\n"));
+    assert!(errata.body.contains("Release     : 1.fc40\n"));
+    assert!(errata
+        .body
+        .contains("Update Information:\n\nUpdate to version 1.2.3.\n"));
+    assert!(errata.body.contains(
+        "  [ 2 ] Bug #2270002 - foo crashes on startup\n        https://bugzilla.redhat.com/show_bug.cgi?id=2270002\n"
+    ));
+    assert!(errata
+        .body
+        .contains("su -c 'dnf upgrade --advisory FEDORA-2024-1a2b3c4d5e'"));
+    assert!(errata.to_string().starts_with("Subject: Fedora 40 Test Update"));
+
+    update.release.package_manager = PackageManager::YUM;
+    update.bugs.clear();
+    let errata = render(&update);
+    assert!(errata
+        .body
+        .contains("su -c 'yum update --advisory FEDORA-2024-1a2b3c4d5e'"));
+    assert!(!errata.body.contains("References:"));
+}
@@ -0,0 +1,313 @@
+use std::cell::Cell;
+use std::time::Duration;
+
+use futures_util::future::BoxFuture;
+
+use crate::mock::{fixture, MockServer};
+use crate::{
+    BodhiDate,
+    FedoraRelease,
+    OverrideCreator,
+    OverrideExpirer,
+    QueryError,
+    Release,
+    ReleaseQuery,
+    ReleaseTag,
+    ResultSink,
+    Update,
+    UpdateCreator,
+    UpdateStatus,
+    WaitError,
+    WaitOptions,
+};
+
+#[tokio::test]
+async fn override_context() {
+    let bodhi = MockServer::with_fixtures().client().await.unwrap();
+
+    let context = bodhi.override_context("foo-1.2.3-1.fc38").await.unwrap();
+    assert_eq!(context.over_ride.nvr, "foo-1.2.3-1.fc38");
+    assert_eq!(context.updates.len(), 1);
+
+    let update = context.pending_update().unwrap();
+    assert_eq!(update.status, UpdateStatus::Testing);
+
+    assert!(matches!(
+        bodhi.override_context("bar-0.1.0-1.fc38").await,
+        Err(QueryError::NotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn create_overrides() {
+    let mut created: serde_json::Value = serde_json::from_str(fixture("override.json").unwrap()).unwrap();
+    created = created["override"].clone();
+    created["caveats"] = serde_json::json!([]);
+
+    let bodhi = MockServer::with_fixtures()
+        .route("/overrides/", created.to_string())
+        .client()
+        .await
+        .unwrap();
+
+    let update = Update::fetch(&bodhi, "FEDORA-2024-1a2b3c4d5e").await.unwrap();
+    let expiration_date = BodhiDate::try_from("2024-05-09 12:00:00").unwrap();
+    let creators = OverrideCreator::for_update(&update, "for testing", &expiration_date).unwrap();
+
+    let results = bodhi.create_overrides(&creators).await;
+    assert_eq!(results.len(), update.builds.len());
+
+    for ((nvr, result), build) in results.iter().zip(&update.builds) {
+        assert_eq!(*nvr, build.nvr);
+        assert_eq!(result.as_ref().unwrap().over_ride.nvr, build.nvr);
+    }
+}
+
+#[tokio::test]
+async fn expire_override() {
+    let mut expired: serde_json::Value = serde_json::from_str(fixture("override.json").unwrap()).unwrap();
+    expired = expired["override"].clone();
+    expired["expired_date"] = serde_json::Value::from("2024-05-03 12:00:00");
+    expired["caveats"] = serde_json::json!([]);
+
+    let bodhi = MockServer::with_fixtures()
+        .route("/overrides/", expired.to_string())
+        .client()
+        .await
+        .unwrap();
+
+    let over_ride = bodhi
+        .expire_override(&OverrideExpirer::from_nvr("foo-1.2.3-1.fc38"))
+        .await
+        .unwrap();
+    assert_eq!(over_ride.nvr, "foo-1.2.3-1.fc38");
+    assert!(over_ride.expired_date.is_some());
+
+    assert!(matches!(
+        bodhi
+            .expire_override(&OverrideExpirer::from_nvr("bar-0.1.0-1.fc38"))
+            .await,
+        Err(QueryError::NotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn builds_in_tag() {
+    let bodhi = MockServer::with_fixtures().client().await.unwrap();
+
+    let release = Release::fetch(&bodhi, "F38").await.unwrap();
+    assert_eq!(release.tag(ReleaseTag::PendingSigning), "f38-signing-pending");
+    assert_eq!(release.tag(ReleaseTag::Testing), "f38-updates-testing");
+
+    let name = FedoraRelease::try_from("F38").unwrap();
+
+    let unsigned = bodhi.builds_in_tag(&name, ReleaseTag::PendingSigning).await.unwrap();
+    assert!(unsigned.is_empty());
+
+    for tag in [ReleaseTag::Candidate, ReleaseTag::Override, ReleaseTag::Testing] {
+        let builds = bodhi.builds_in_tag(&name, tag).await.unwrap();
+        assert_eq!(builds[0].nvr, "foo-1.2.3-1.fc38");
+    }
+}
+
+#[tokio::test]
+async fn update_changes_since() {
+    let since = BodhiDate::try_from("2024-05-01 00:00:00").unwrap();
+
+    // comments on modified updates are attached to them
+    let bodhi = MockServer::with_fixtures().client().await.unwrap();
+    let changes = bodhi.update_changes_since(&since).await.unwrap();
+    assert_eq!(changes.updates.len(), 1);
+    assert_eq!(changes.updates[0].comments.as_ref().unwrap().len(), 3);
+    assert!(changes.comments.is_empty());
+
+    // comments on other updates are grouped by update alias
+    let bodhi = MockServer::with_fixtures()
+        .route(
+            "/updates/",
+            r#"{"updates": [], "page": 1, "pages": 1, "rows_per_page": 50, "total": 0}"#,
+        )
+        .client()
+        .await
+        .unwrap();
+    let changes = bodhi.update_changes_since(&since).await.unwrap();
+    assert!(changes.updates.is_empty());
+    assert_eq!(changes.comments["FEDORA-2024-1a2b3c4d5e"].len(), 3);
+    assert!(!changes.is_empty());
+}
+
+#[tokio::test]
+async fn create_update_and_wait() {
+    let mut update: serde_json::Value = serde_json::from_str(fixture("update.json").unwrap()).unwrap();
+    let mut created = update["update"].clone();
+    created["caveats"] = serde_json::json!([]);
+
+    let bodhi = MockServer::with_fixtures()
+        .route("/updates/", created.to_string())
+        .client()
+        .await
+        .unwrap();
+
+    let options = WaitOptions {
+        interval: Duration::from_millis(10),
+        timeout: Duration::from_secs(10),
+    };
+    let calls = Cell::new(0);
+
+    let builds = ["foo-1.2.3-1.fc38"];
+    let creator = UpdateCreator::from_builds(&builds, "Update to version 1.2.3.");
+    let result = bodhi
+        .create_update_and_wait(&creator, &options, |_| calls.set(calls.get() + 1))
+        .await
+        .unwrap();
+    assert_eq!(result.status, UpdateStatus::Testing);
+    assert_eq!(calls.get(), 2);
+
+    // updates that were obsoleted while waiting
+    update["update"]["status"] = serde_json::json!("obsolete");
+    let bodhi = MockServer::with_fixtures()
+        .route("/updates/FEDORA-2024-1a2b3c4d5e", update.to_string())
+        .client()
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        bodhi.wait_for_testing("FEDORA-2024-1a2b3c4d5e", &options, |_| {}).await,
+        Err(WaitError::UnexpectedStatus {
+            status: UpdateStatus::Obsolete,
+            ..
+        })
+    ));
+
+    // updates that are still pending when the timeout is reached
+    update["update"]["status"] = serde_json::json!("pending");
+    update["update"]["request"] = serde_json::json!("testing");
+    let bodhi = MockServer::with_fixtures()
+        .route("/updates/FEDORA-2024-1a2b3c4d5e", update.to_string())
+        .client()
+        .await
+        .unwrap();
+
+    let options = WaitOptions {
+        interval: Duration::from_millis(10),
+        timeout: Duration::from_millis(50),
+    };
+    assert!(matches!(
+        bodhi.wait_for_testing("FEDORA-2024-1a2b3c4d5e", &options, |_| {}).await,
+        Err(WaitError::Timeout { .. })
+    ));
+}
+
+#[tokio::test]
+async fn override_and_wait_for_repo() {
+    let mut over_ride: serde_json::Value = serde_json::from_str(fixture("override.json").unwrap()).unwrap();
+    let mut created = over_ride["override"].clone();
+    created["caveats"] = serde_json::json!([]);
+
+    let bodhi = MockServer::with_fixtures()
+        .route("/overrides/", created.to_string())
+        .client()
+        .await
+        .unwrap();
+
+    let options = WaitOptions {
+        interval: Duration::from_millis(10),
+        timeout: Duration::from_secs(10),
+    };
+    let expiration_date = BodhiDate::try_from("2024-05-09 12:00:00").unwrap();
+    let creator = OverrideCreator::new("foo-1.2.3-1.fc38", "for testing", &expiration_date);
+
+    let calls = Cell::new(0);
+    let result = bodhi
+        .override_and_wait_for_repo(&creator, &options, |_| {
+            calls.set(calls.get() + 1);
+            let ready = calls.get() >= 3;
+            async move { ready }
+        })
+        .await
+        .unwrap();
+    assert_eq!(result.nvr, "foo-1.2.3-1.fc38");
+    assert_eq!(calls.get(), 3);
+
+    // builds that never become available
+    let options = WaitOptions {
+        interval: Duration::from_millis(10),
+        timeout: Duration::from_millis(50),
+    };
+    assert!(matches!(
+        bodhi
+            .override_and_wait_for_repo(&creator, &options, |_| async { false })
+            .await,
+        Err(WaitError::Timeout { .. })
+    ));
+
+    // overrides that expire while waiting
+    over_ride["override"]["expired_date"] = serde_json::json!("2024-05-03 12:00:00");
+    let bodhi = MockServer::with_fixtures()
+        .route("/overrides/", created.to_string())
+        .route("/overrides/foo-1.2.3-1.fc38", over_ride.to_string())
+        .client()
+        .await
+        .unwrap();
+
+    let options = WaitOptions {
+        interval: Duration::from_millis(10),
+        timeout: Duration::from_secs(10),
+    };
+    assert!(matches!(
+        bodhi
+            .override_and_wait_for_repo(&creator, &options, |_| async { false })
+            .await,
+        Err(WaitError::OverrideExpired { .. })
+    ));
+}
+
+#[tokio::test]
+async fn export_all() {
+    #[derive(Default)]
+    struct Batches {
+        batches: Vec<Vec<Release>>,
+        finished: bool,
+    }
+
+    impl ResultSink<Release> for Batches {
+        type Error = std::convert::Infallible;
+
+        fn insert_batch(&mut self, items: Vec<Release>) -> BoxFuture<'_, Result<(), Self::Error>> {
+            self.batches.push(items);
+            Box::pin(async { Ok(()) })
+        }
+
+        fn finish(&mut self) -> BoxFuture<'_, Result<(), Self::Error>> {
+            self.finished = true;
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    let bodhi = MockServer::with_fixtures().client().await.unwrap();
+
+    let mut sink = Batches::default();
+    let count = bodhi.export_all(&ReleaseQuery::new(), &mut sink).await.unwrap();
+
+    assert_eq!(count, 1);
+    assert_eq!(sink.batches.len(), 1);
+    assert_eq!(sink.batches[0][0].name, FedoraRelease::try_from("F38").unwrap());
+    assert!(sink.finished);
+}
+
+#[tokio::test]
+async fn update_full() {
+    let bodhi = MockServer::with_fixtures().client().await.unwrap();
+
+    let bundle = bodhi.update_full("FEDORA-2024-1a2b3c4d5e").await.unwrap();
+
+    assert_eq!(bundle.update.alias, "FEDORA-2024-1a2b3c4d5e");
+    assert_eq!(bundle.comments.len(), 3);
+    assert!(bundle.comments.iter().all(|comment| comment.update.is_none()));
+    assert_eq!(bundle.overrides.len(), 1);
+    assert_eq!(bundle.overrides[0].nvr, "foo-1.2.3-1.fc38");
+    assert_eq!(bundle.release.name, bundle.update.release.name);
+
+    let result = bodhi.update_full("FEDORA-2024-0000000000").await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,263 @@
+use crate::mock::{fixture, MockServer, MockTransport};
+use crate::query::*;
+use crate::{ComposeRequest, ContentType, FedoraRelease, QueryError};
+
+#[tokio::test]
+async fn mock_queries() {
+    let bodhi = MockServer::with_fixtures().client().await.unwrap();
+
+    let build = bodhi.request(&BuildNVRQuery::new("foo-1.2.3-1.fc38")).await.unwrap();
+    assert!(build.signed);
+    assert_eq!(bodhi.paginated_request(&BuildQuery::new()).await.unwrap().len(), 1);
+
+    let comment = bodhi.request(&CommentIDQuery::new(3000003)).await.unwrap();
+    assert_eq!(u32::from(comment.id), 3000003);
+    assert_eq!(bodhi.paginated_request(&CommentQuery::new()).await.unwrap().len(), 3);
+
+    let release = FedoraRelease::fedora(38, ContentType::RPM).unwrap();
+    let compose = bodhi
+        .request(&ComposeReleaseRequestQuery::new(&release, ComposeRequest::Testing))
+        .await
+        .unwrap();
+    assert_eq!(compose.update_summary.len(), 1);
+    assert_eq!(bodhi.request(&ComposeQuery::new()).await.unwrap().len(), 1);
+
+    assert!(!bodhi.request(&CSRFQuery::new()).await.unwrap().is_empty());
+
+    let over_ride = bodhi.request(&OverrideNVRQuery::new("foo-1.2.3-1.fc38")).await.unwrap();
+    assert_eq!(over_ride.submitter.name, "packager");
+    assert_eq!(bodhi.paginated_request(&OverrideQuery::new()).await.unwrap().len(), 1);
+
+    assert_eq!(bodhi.paginated_request(&PackageQuery::new()).await.unwrap().len(), 1);
+
+    let release = bodhi.request(&ReleaseNameQuery::new("F38")).await.unwrap();
+    assert_eq!(release.name.to_string(), "F38");
+    assert_eq!(bodhi.paginated_request(&ReleaseQuery::new()).await.unwrap().len(), 1);
+
+    let update = bodhi
+        .request(&UpdateIDQuery::new("FEDORA-2024-1a2b3c4d5e"))
+        .await
+        .unwrap();
+    assert!(update.verify_version_hash());
+    assert_eq!(bodhi.paginated_request(&UpdateQuery::new()).await.unwrap().len(), 1);
+
+    let results = bodhi
+        .request(&UpdateTestResultsQuery::new("FEDORA-2024-1a2b3c4d5e"))
+        .await
+        .unwrap();
+    assert!(!results.is_satisfied());
+    let missing: Vec<_> = results.unsatisfied_requirements().collect();
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].requirement_type, "test-result-missing");
+    assert_eq!(
+        missing[0].testcase.as_deref(),
+        Some("update.install_default_update_netinst")
+    );
+    assert!(results.decisions[0].waivers.is_empty());
+    assert!(missing[0].item.is_some());
+
+    let user = bodhi.request(&UserNameQuery::new("packager")).await.unwrap();
+    assert_eq!(user.name, "packager");
+    assert_eq!(bodhi.paginated_request(&UserQuery::new()).await.unwrap().len(), 1);
+
+    assert!(matches!(
+        bodhi.request(&UpdateIDQuery::new("FEDORA-2024-0000000000")).await,
+        Err(QueryError::NotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn mock_routes() {
+    let bodhi = MockServer::new()
+        .route("/releases/", fixture("releases.json").unwrap())
+        .client()
+        .await
+        .unwrap();
+
+    assert_eq!(bodhi.paginated_request(&ReleaseQuery::new()).await.unwrap().len(), 1);
+    assert!(bodhi.paginated_request(&UpdateQuery::new()).await.is_err());
+}
+
+#[tokio::test]
+async fn mock_requests() {
+    let server = MockServer::with_fixtures();
+    let requests = server.requests();
+    let bodhi = server.client().await.unwrap();
+
+    bodhi
+        .paginated_request(&UpdateQuery::new().users(&["packager"]))
+        .await
+        .unwrap();
+
+    let recorded = requests.all();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].method, "GET");
+    assert_eq!(recorded[0].path, "/updates/");
+    assert!(recorded[0].query.as_deref().unwrap().contains("user=packager"));
+    assert!(recorded[0].body.is_empty());
+
+    requests.clear();
+
+    let creator = crate::CommentCreator::new("FEDORA-2024-1a2b3c4d5e").text("comment");
+    let _ = bodhi.request(&creator).await;
+
+    assert!(requests.find("GET", "/csrf").is_some());
+    let posted = requests.find("POST", "/comments/").unwrap();
+    let body = posted.json().unwrap();
+    assert_eq!(body["update"], "FEDORA-2024-1a2b3c4d5e");
+    assert_eq!(body["text"], "comment");
+
+    assert!(requests.find("POST", "/updates/").is_none());
+}
+
+#[tokio::test]
+async fn mock_transport() {
+    let transport = MockTransport::with_fixtures();
+    let requests = transport.requests();
+    let bodhi = transport.client().await.unwrap();
+
+    let update = bodhi
+        .request(&UpdateIDQuery::new("FEDORA-2024-1a2b3c4d5e"))
+        .await
+        .unwrap();
+    assert!(update.verify_version_hash());
+    assert_eq!(bodhi.paginated_request(&CommentQuery::new()).await.unwrap().len(), 3);
+
+    let recorded = requests.find("GET", "/comments/").unwrap();
+    assert!(recorded.query.unwrap().contains("page=1"));
+
+    // requests for paths without registered responses are answered with 404 errors
+    match bodhi.request(&UpdateIDQuery::new("FEDORA-2024-0000000000")).await {
+        Err(QueryError::NotFound { response }) => {
+            assert_eq!(response.url().path(), "/updates/FEDORA-2024-0000000000");
+        },
+        other => panic!("Unexpected result: {other:?}"),
+    }
+
+    requests.clear();
+
+    let creator = crate::CommentCreator::new("FEDORA-2024-1a2b3c4d5e").text("comment");
+    let _ = bodhi.request(&creator).await;
+
+    assert!(requests.find("GET", "/csrf").is_some());
+    let body = requests.find("POST", "/comments/").unwrap().json().unwrap();
+    assert_eq!(body["text"], "comment");
+}
+
+#[tokio::test]
+async fn mock_transport_builder() {
+    let transport = MockTransport::new().route("/releases/", fixture("releases.json").unwrap());
+    let requests = transport.requests();
+    let bodhi = crate::BodhiClientBuilder::custom(String::from("https://bodhi.example.com/"), String::new())
+        .transport(transport)
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(bodhi.paginated_request(&ReleaseQuery::new()).await.unwrap().len(), 1);
+    assert!(bodhi.paginated_request(&UpdateQuery::new()).await.is_err());
+    assert_eq!(requests.all().len(), 2);
+}
+
+#[tokio::test]
+async fn mock_lite_queries() {
+    let bodhi = MockServer::with_fixtures().client().await.unwrap();
+
+    let updates = bodhi.paginated_request(&UpdateQuery::new()).await.unwrap();
+    let lite = bodhi.paginated_request(&UpdateQuery::new().lite()).await.unwrap();
+    assert_eq!(lite.len(), updates.len());
+
+    for (lite, update) in lite.iter().zip(&updates) {
+        assert_eq!(lite.alias, update.alias);
+        assert_eq!(lite.title, update.title);
+        assert_eq!(lite.status, update.status);
+        assert_eq!(lite.karma, update.karma);
+        assert_eq!(lite.release, update.release.name);
+    }
+
+    // client-side filters are applied to lightweight listings as well
+    let since = crate::BodhiDate::try_from("2100-01-01").unwrap();
+    let stable = bodhi
+        .paginated_request(&UpdateQuery::new().stable_since(&since).lite())
+        .await
+        .unwrap();
+    assert!(stable.is_empty());
+}
+
+#[tokio::test]
+async fn mock_compose_monitor() {
+    use futures_util::StreamExt;
+
+    let release = FedoraRelease::fedora(38, ContentType::RPM).unwrap();
+    let monitor =
+        || ComposeMonitor::new(&release, ComposeRequest::Testing).interval(std::time::Duration::from_millis(10));
+
+    // running composes are polled without emitting items until their state changes
+    let bodhi = MockServer::with_fixtures().client().await.unwrap();
+    let changes: Vec<_> = monitor().watch(&bodhi).take(1).collect().await;
+    assert_eq!(changes.len(), 1);
+    let change = changes.into_iter().next().unwrap().unwrap();
+    assert_eq!(change.previous, None);
+    assert_eq!(change.compose.state, crate::ComposeState::Requested);
+    assert!(!change.is_finished());
+
+    // the stream ends when the compose has finished
+    let mut compose: serde_json::Value = serde_json::from_str(fixture("compose.json").unwrap()).unwrap();
+    compose["compose"]["state"] = serde_json::json!("failed");
+    let bodhi = MockServer::with_fixtures()
+        .route("/composes/F38/testing", compose.to_string())
+        .client()
+        .await
+        .unwrap();
+    let changes: Vec<_> = monitor().watch(&bodhi).collect().await;
+    assert_eq!(changes.len(), 1);
+    assert!(changes[0].as_ref().unwrap().is_finished());
+
+    // composes that are not running result in an error
+    let bodhi = MockServer::new().client().await.unwrap();
+    let changes: Vec<_> = monitor().watch(&bodhi).collect().await;
+    assert_eq!(changes.len(), 1);
+    assert!(matches!(changes[0], Err(QueryError::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn mock_override_sort() {
+    let mut page: serde_json::Value = serde_json::from_str(fixture("overrides.json").unwrap()).unwrap();
+    let mut second = page["overrides"][0].clone();
+    second["nvr"] = serde_json::json!("bar-2.0.0-1.fc38");
+    second["expiration_date"] = serde_json::json!("2024-05-01 12:00:00");
+    page["overrides"].as_array_mut().unwrap().push(second);
+    page["total"] = serde_json::json!(2);
+
+    let server = MockServer::with_fixtures().route("/overrides/", page.to_string());
+    let requests = server.requests();
+    let bodhi = server.client().await.unwrap();
+
+    let builds = ["foo-1.2.3-1.fc38", "bar-2.0.0-1.fc38"];
+    let unsorted = bodhi
+        .paginated_request(&OverrideQuery::new().builds(&builds))
+        .await
+        .unwrap();
+    assert_eq!(unsorted[0].nvr, "foo-1.2.3-1.fc38");
+
+    let query = requests.find("GET", "/overrides/").unwrap().query.unwrap();
+    assert!(query.contains("builds=foo-1.2.3-1.fc38"));
+    assert!(query.contains("bar-2.0.0-1.fc38"));
+
+    let sorted = bodhi
+        .paginated_request(
+            &OverrideQuery::new()
+                .builds(&builds)
+                .sort(OverrideSort::ExpirationAscending),
+        )
+        .await
+        .unwrap();
+    let nvrs: Vec<&str> = sorted.iter().map(|o| o.nvr.as_str()).collect();
+    assert_eq!(nvrs, ["bar-2.0.0-1.fc38", "foo-1.2.3-1.fc38"]);
+
+    let sorted = bodhi
+        .paginated_request(&OverrideQuery::new().sort(OverrideSort::ExpirationDescending))
+        .await
+        .unwrap();
+    assert_eq!(sorted[0].nvr, "foo-1.2.3-1.fc38");
+}
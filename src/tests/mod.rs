@@ -26,11 +26,39 @@ async fn bodhi_init() -> BodhiClient {
 
 // offline tests
 #[cfg(feature = "offline-tests")]
+mod analysis;
+#[cfg(feature = "offline-tests")]
+mod api_map;
+#[cfg(feature = "offline-tests")]
+mod badge;
+#[cfg(feature = "offline-tests")]
+mod creates;
+#[cfg(feature = "offline-tests")]
 mod dates;
 #[cfg(feature = "offline-tests")]
+mod edits;
+#[cfg(feature = "offline-tests")]
 mod enums;
 #[cfg(feature = "offline-tests")]
+mod errata;
+#[cfg(feature = "offline-tests")]
+mod helpers;
+#[cfg(feature = "offline-tests")]
+mod mock;
+#[cfg(feature = "offline-tests")]
+mod policy;
+#[cfg(feature = "offline-tests")]
+mod properties;
+#[cfg(feature = "offline-tests")]
+mod queries;
+#[cfg(all(feature = "offline-tests", feature = "saved-queries"))]
+mod saved_queries;
+#[cfg(feature = "offline-tests")]
 mod types;
+#[cfg(feature = "offline-tests")]
+mod update_set;
+#[cfg(feature = "offline-tests")]
+mod validate;
 
 // tests requiring internet access
 #[cfg(feature = "online-tests")]
@@ -84,5 +84,16 @@ async fn nvr_query_err() {
 
     let over_ride = bodhi.request(&OverrideNVRQuery::new("syncthing-1.1.3-1.fc30")).await;
 
-    assert!(matches!(over_ride, Err(QueryError::NotFound)));
+    assert!(matches!(over_ride, Err(QueryError::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn nvr_query_none() {
+    let bodhi = bodhi_init().await;
+
+    let over_ride = bodhi
+        .request_opt(&OverrideNVRQuery::new("syncthing-1.1.3-1.fc30"))
+        .await;
+
+    assert!(matches!(over_ride, Ok(None)));
 }
@@ -0,0 +1,120 @@
+use crate::policy::*;
+use crate::{BodhiDate, IdPrefix, ReleaseState, TestGatingStatus, Update, UpdateStatus};
+
+const UPDATE_JSON: &str = include_str!("data/update.json");
+
+#[test]
+fn testing_policy() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    let policy = TestingPolicy::for_update(&update);
+    assert_eq!(policy.min_days_in_testing, FEDORA_MIN_DAYS_IN_TESTING);
+    assert_eq!(policy.min_karma, MIN_KARMA);
+
+    update.critpath = true;
+    update.stable_days = Some(21);
+    let policy = TestingPolicy::for_update(&update);
+    assert_eq!(policy.min_days_in_testing, 21);
+    assert_eq!(policy.min_karma, CRITPATH_MIN_KARMA);
+
+    update.stable_days = None;
+    update.release.state = ReleaseState::Pending;
+    let policy = TestingPolicy::for_update(&update);
+    assert_eq!(policy.min_days_in_testing, FEDORA_BRANCHED_MIN_DAYS_IN_TESTING);
+
+    update.release.id_prefix = IdPrefix::Epel;
+    let policy = TestingPolicy::for_update(&update);
+    assert_eq!(policy.min_days_in_testing, EPEL_MIN_DAYS_IN_TESTING);
+
+    update.release.composed_by_bodhi = false;
+    let policy = TestingPolicy::for_update(&update);
+    assert_eq!(policy.min_days_in_testing, 0);
+}
+
+#[test]
+fn testing_days() {
+    let update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    // only full days are counted
+    let now = BodhiDate::try_from("2024-05-09 00:59:59").unwrap();
+    assert_eq!(days_in_testing(&update, &now), Some(6));
+    let now = BodhiDate::try_from("2024-05-09 01:00:00").unwrap();
+    assert_eq!(days_in_testing(&update, &now), Some(7));
+
+    let now = BodhiDate::try_from("2024-05-01 00:00:00").unwrap();
+    assert_eq!(days_in_testing(&update, &now), Some(0));
+}
+
+#[test]
+fn stable_date() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    assert_eq!(
+        update.date_testing,
+        Some(BodhiDate::try_from("2024-05-02 01:00:00").unwrap())
+    );
+
+    assert_eq!(
+        earliest_stable_date(&update),
+        Some(BodhiDate::try_from("2024-05-09 01:00:00").unwrap())
+    );
+
+    // updates with enough karma can be pushed to stable immediately
+    update.karma = Some(1);
+    assert_eq!(earliest_stable_date(&update), update.date_testing);
+
+    update.critpath = true;
+    assert_eq!(
+        earliest_stable_date(&update),
+        Some(BodhiDate::try_from("2024-05-09 01:00:00").unwrap())
+    );
+
+    update.status = UpdateStatus::Stable;
+    assert!(earliest_stable_date(&update).is_none());
+}
+
+#[test]
+fn karma_remaining() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    assert_eq!(update.karma_remaining(), StableEligibility::NeedsKarma(3));
+
+    update.karma = Some(2);
+    assert_eq!(update.karma_remaining(), StableEligibility::NeedsKarma(1));
+
+    update.karma = Some(3);
+    assert_eq!(update.karma_remaining(), StableEligibility::Now);
+
+    // the threshold is never lower than the minimum karma of the policy
+    update.karma = Some(1);
+    update.stable_karma = Some(1);
+    update.critpath = true;
+    assert_eq!(update.karma_remaining(), StableEligibility::NeedsKarma(1));
+
+    update.locked = true;
+    assert_eq!(
+        update.karma_remaining(),
+        StableEligibility::Blocked(BlockedReason::Locked)
+    );
+}
+
+#[test]
+fn time_remaining_in_testing() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    let now = BodhiDate::try_from("2024-05-04 12:00:00").unwrap();
+    assert_eq!(update.time_remaining_in_testing(&now), StableEligibility::NeedsDays(5));
+
+    let now = BodhiDate::try_from("2024-05-09 01:00:00").unwrap();
+    assert_eq!(update.time_remaining_in_testing(&now), StableEligibility::Now);
+
+    update.test_gating_status = Some(TestGatingStatus::Failed);
+    assert_eq!(
+        update.time_remaining_in_testing(&now),
+        StableEligibility::Blocked(BlockedReason::GatingFailed(TestGatingStatus::Failed))
+    );
+
+    update.status = UpdateStatus::Stable;
+    assert_eq!(
+        update.time_remaining_in_testing(&now),
+        StableEligibility::Blocked(BlockedReason::NotInTesting(UpdateStatus::Stable))
+    );
+}
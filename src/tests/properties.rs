@@ -0,0 +1,162 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use quickcheck::QuickCheck;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use url::Url;
+
+use crate::data::*;
+use crate::query::*;
+use crate::request::{PaginatedRequest, SingleRequest};
+
+// helper function that returns a quickcheck value
+fn quickchecker() -> QuickCheck {
+    QuickCheck::new().tests(10_000)
+}
+
+// check that values survive round-trips through Display / FromStr and through serde
+fn roundtrip<T>(value: T) -> bool
+where
+    T: Copy + Display + FromStr + PartialEq + Serialize + DeserializeOwned,
+{
+    let parsed = value.to_string().parse::<T>().ok();
+
+    let serialized = serde_json::to_string(&value).unwrap();
+    let deserialized: T = serde_json::from_str(&serialized).unwrap();
+
+    parsed == Some(value) && deserialized == value
+}
+
+// check that a path with query string is valid and that all values are percent-encoded
+fn is_valid_path(path: &str) -> bool {
+    let bytes = path.as_bytes();
+
+    for (pos, byte) in bytes.iter().enumerate() {
+        let valid = match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => true,
+            b'-' | b'.' | b'_' | b'~' | b'*' | b'+' | b'/' | b'?' | b'&' | b'=' => true,
+            b'%' => {
+                bytes.get(pos + 1).is_some_and(u8::is_ascii_hexdigit)
+                    && bytes.get(pos + 2).is_some_and(u8::is_ascii_hexdigit)
+            },
+            _ => false,
+        };
+
+        if !valid {
+            return false;
+        }
+    }
+
+    // the URL must not be changed by parsing and normalization
+    let base = Url::parse("https://bodhi.fedoraproject.org").unwrap();
+    match base.join(path) {
+        Ok(url) => url.as_str().ends_with(path),
+        Err(_) => false,
+    }
+}
+
+#[test]
+fn roundtrip_enums() {
+    quickchecker().quickcheck(roundtrip::<CommentKind> as fn(CommentKind) -> bool);
+    quickchecker().quickcheck(roundtrip::<ComposeRequest> as fn(ComposeRequest) -> bool);
+    quickchecker().quickcheck(roundtrip::<ComposeState> as fn(ComposeState) -> bool);
+    quickchecker().quickcheck(roundtrip::<ContentType> as fn(ContentType) -> bool);
+    quickchecker().quickcheck(roundtrip::<Karma> as fn(Karma) -> bool);
+    quickchecker().quickcheck(roundtrip::<PackageManager> as fn(PackageManager) -> bool);
+    quickchecker().quickcheck(roundtrip::<ReleaseState> as fn(ReleaseState) -> bool);
+    quickchecker().quickcheck(roundtrip::<TestGatingStatus> as fn(TestGatingStatus) -> bool);
+    quickchecker().quickcheck(roundtrip::<UpdateRequest> as fn(UpdateRequest) -> bool);
+    quickchecker().quickcheck(roundtrip::<UpdateSeverity> as fn(UpdateSeverity) -> bool);
+    quickchecker().quickcheck(roundtrip::<UpdateStatus> as fn(UpdateStatus) -> bool);
+    quickchecker().quickcheck(roundtrip::<UpdateSuggestion> as fn(UpdateSuggestion) -> bool);
+    quickchecker().quickcheck(roundtrip::<UpdateType> as fn(UpdateType) -> bool);
+}
+
+#[test]
+fn roundtrip_dates() {
+    fn prop(days: u32, seconds: u32) -> bool {
+        // dates between 1970-01-01 and 2069-12-31
+        let date = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap() + chrono::Days::new((days % 36_524).into());
+        let time = chrono::NaiveTime::from_num_seconds_from_midnight_opt(seconds % 86_400, 0).unwrap();
+        let string = date.and_time(time).format("%Y-%m-%d %H:%M:%S").to_string();
+
+        match BodhiDate::try_from(string.as_str()) {
+            Ok(parsed) => parsed.to_string() == string,
+            Err(_) => false,
+        }
+    }
+
+    quickchecker().quickcheck(prop as fn(u32, u32) -> bool);
+}
+
+#[test]
+fn parse_dates() {
+    // parsing arbitrary strings must return an error instead of panicking
+    fn prop(string: String) -> bool {
+        let _ = BodhiDate::try_from(string.as_str());
+        true
+    }
+
+    quickchecker().quickcheck(prop as fn(String) -> bool);
+}
+
+#[test]
+fn fuzz_id_queries() {
+    fn prop(string: String) -> bool {
+        // empty strings and relative path segments are rejected
+        let invalid = ["", ".", ".."].contains(&string.as_str());
+
+        [
+            BuildNVRQuery::new(&string).path(),
+            OverrideNVRQuery::new(&string).path(),
+            ReleaseNameQuery::new(&string).path(),
+            UpdateIDQuery::new(&string).path(),
+            UserNameQuery::new(&string).path(),
+        ]
+        .iter()
+        .all(|path| match path {
+            Ok(path) => !invalid && is_valid_path(path),
+            Err(_) => invalid,
+        })
+    }
+
+    quickchecker().quickcheck(prop as fn(String) -> bool);
+}
+
+#[test]
+fn fuzz_update_query() {
+    fn prop(search: String, packages: Vec<String>, users: Vec<String>) -> bool {
+        let packages: Vec<&str> = packages.iter().map(String::as_str).collect();
+        let users: Vec<&str> = users.iter().map(String::as_str).collect();
+
+        let query = UpdateQuery::new()
+            .search(&search)
+            .like(&search)
+            .packages(&packages)
+            .users(&users);
+
+        let path = query.page_request(FIRST_PAGE).path().unwrap();
+        is_valid_path(&path)
+    }
+
+    quickchecker().quickcheck(prop as fn(String, Vec<String>, Vec<String>) -> bool);
+}
+
+#[test]
+fn fuzz_comment_query() {
+    fn prop(search: String, updates: Vec<String>, ignore_users: Vec<String>) -> bool {
+        let updates: Vec<&str> = updates.iter().map(String::as_str).collect();
+        let ignore_users: Vec<&str> = ignore_users.iter().map(String::as_str).collect();
+
+        let query = CommentQuery::new()
+            .search(&search)
+            .updates(&updates)
+            .ignore_users(&ignore_users);
+
+        let path = query.page_request(FIRST_PAGE).path().unwrap();
+        is_valid_path(&path)
+    }
+
+    quickchecker().quickcheck(prop as fn(String, Vec<String>, Vec<String>) -> bool);
+}
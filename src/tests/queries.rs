@@ -0,0 +1,238 @@
+use crate::error::QueryError;
+use crate::query::*;
+use crate::request::PaginatedRequest;
+
+#[test]
+fn rows_per_page_limits() {
+    let query = UpdateQuery::new().rows_per_page(MAX_ROWS_PER_PAGE);
+    assert!(query.page_request(FIRST_PAGE).path().is_ok());
+
+    let query = UpdateQuery::new().rows_per_page(MAX_ROWS_PER_PAGE + 1);
+    assert!(matches!(
+        query.page_request(FIRST_PAGE).path(),
+        Err(QueryError::InvalidParameterError {
+            name: "rows_per_page",
+            ..
+        })
+    ));
+
+    let query = BuildQuery::new().rows_per_page(0);
+    assert!(matches!(
+        query.page_request(FIRST_PAGE).path(),
+        Err(QueryError::InvalidParameterError {
+            name: "rows_per_page",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn page_limits() {
+    let query = CommentQuery::new();
+    assert!(matches!(
+        query.page_request(0).path(),
+        Err(QueryError::InvalidParameterError { name: "page", .. })
+    ));
+}
+
+#[test]
+fn update_query_locked() {
+    let path = UpdateQuery::new().locked(true).page_request(FIRST_PAGE).path().unwrap();
+    assert!(path.contains("locked=true"));
+
+    let path = UpdateQuery::new()
+        .locked(false)
+        .page_request(FIRST_PAGE)
+        .path()
+        .unwrap();
+    assert!(path.contains("locked=false"));
+
+    let path = UpdateQuery::new().page_request(FIRST_PAGE).path().unwrap();
+    assert!(!path.contains("locked"));
+}
+
+#[test]
+fn update_query_pushed() {
+    let path = UpdateQuery::new().pushed(true).page_request(FIRST_PAGE).path().unwrap();
+    assert!(path.contains("pushed=true"));
+
+    let path = UpdateQuery::new()
+        .pushed(false)
+        .page_request(FIRST_PAGE)
+        .path()
+        .unwrap();
+    assert!(path.contains("pushed=false"));
+
+    let path = UpdateQuery::new().page_request(FIRST_PAGE).path().unwrap();
+    assert!(!path.contains("pushed"));
+}
+
+#[test]
+fn comment_query_filters() {
+    let path = CommentQuery::new()
+        .anonymous(false)
+        .ignore_users(&["bodhi", "zuul"])
+        .update_owners(&["decathorpe"])
+        .page_request(FIRST_PAGE)
+        .path()
+        .unwrap();
+    assert!(path.contains("anonymous=false"));
+    assert!(path.contains("ignore_user=bodhi&ignore_user=zuul"));
+    assert!(path.contains("update_owner=decathorpe"));
+    assert!(!path.contains("ignore_users"));
+    assert!(!path.contains("update_owners"));
+
+    let path = CommentQuery::new().page_request(FIRST_PAGE).path().unwrap();
+    assert!(!path.contains("anonymous"));
+}
+
+#[test]
+fn raw_query() {
+    let params = [("releases", "F40"), ("releases", "F39"), ("search", "rust bodhi")];
+    let query = RawQuery::new("/updates/", &params);
+
+    let path = query.page_request(2).path().unwrap();
+    assert_eq!(
+        path,
+        "/updates/?releases=F40&releases=F39&search=rust+bodhi&page=2&rows_per_page=50"
+    );
+
+    let params = [("page", "3")];
+    assert!(matches!(
+        RawQuery::new("/updates/", &params).page_request(FIRST_PAGE).path(),
+        Err(QueryError::InvalidDataError { .. })
+    ));
+    assert!(matches!(
+        RawQuery::new("/updates/?page=3", &[]).page_request(FIRST_PAGE).path(),
+        Err(QueryError::InvalidDataError { .. })
+    ));
+}
+
+#[test]
+fn raw_query_parse() {
+    let query = RawQuery::new("/widgets/", &[]);
+    let request = query.page_request(FIRST_PAGE);
+
+    let page = request
+        .parse(r#"{"widgets": [{"name": "foo"}, {"name": "bar"}], "page": 1, "pages": 1, "rows_per_page": 50, "total": 2}"#)
+        .unwrap();
+    let items = request.extract(page);
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[1]["name"], "bar");
+
+    assert!(request
+        .parse(r#"{"widgets": [], "gadgets": [], "page": 1, "pages": 1, "rows_per_page": 50, "total": 0}"#)
+        .is_err());
+    assert!(request
+        .parse(r#"{"page": 1, "pages": 1, "rows_per_page": 50, "total": 0}"#)
+        .is_err());
+}
+
+#[test]
+fn queryable() {
+    use crate::{Queryable, Release, Update};
+
+    let path = Update::query().page_request(FIRST_PAGE).path().unwrap();
+    assert_eq!(path, UpdateQuery::new().page_request(FIRST_PAGE).path().unwrap());
+
+    let path = Release::query()
+        .exclude_archived(true)
+        .page_request(FIRST_PAGE)
+        .path()
+        .unwrap();
+    assert!(path.starts_with("/releases/?"));
+}
+
+#[test]
+fn release_query_client_side_filters() {
+    let query = ReleaseQuery::new().composed_by_bodhi(true);
+    let request = query.page_request(FIRST_PAGE);
+    assert!(!request.path().unwrap().contains("composed_by_bodhi"));
+
+    let page = request.parse(crate::mock::fixture("releases.json").unwrap()).unwrap();
+    assert_eq!(request.extract(page).len(), 1);
+
+    let query = ReleaseQuery::new().create_automatic_updates(true);
+    let request = query.page_request(FIRST_PAGE);
+    assert!(!request.path().unwrap().contains("create_automatic_updates"));
+
+    let page = request.parse(crate::mock::fixture("releases.json").unwrap()).unwrap();
+    assert!(request.extract(page).is_empty());
+}
+
+#[test]
+fn update_query_client_side_filters() {
+    let since: crate::BodhiDate = "2023-05-01 00:00:00".parse().unwrap();
+    let before: crate::BodhiDate = "2023-06-01 00:00:00".parse().unwrap();
+
+    let query = UpdateQuery::new().stable_since(&since).stable_before(&before);
+    let request = query.page_request(FIRST_PAGE);
+    assert!(!request.path().unwrap().contains("stable"));
+
+    // updates that have not been pushed to stable are excluded
+    let page = request
+        .parse(crate::mock::fixture("updates_f38.json").unwrap())
+        .unwrap();
+    assert!(request.extract(page).is_empty());
+
+    let stable = crate::mock::fixture("updates_f38.json")
+        .unwrap()
+        .replace(r#""date_stable": null"#, r#""date_stable": "2023-05-15 12:00:00""#);
+    let page = request.parse(&stable).unwrap();
+    assert_eq!(request.extract(page).len(), 1);
+
+    let query = UpdateQuery::new().stable_before(&since);
+    let request = query.page_request(FIRST_PAGE);
+    let page = request.parse(&stable).unwrap();
+    assert!(request.extract(page).is_empty());
+}
+
+#[test]
+fn comment_query_embedded_updates() {
+    let update: serde_json::Value = serde_json::from_str(crate::mock::fixture("update.json").unwrap()).unwrap();
+    let update = &update["update"];
+    let comments = crate::mock::fixture("comments.json").unwrap().replace(
+        r#""update_alias": "FEDORA-2024-1a2b3c4d5e","#,
+        &format!(r#""update": {update},"#),
+    );
+
+    let query = CommentQuery::new();
+    let request = query.page_request(FIRST_PAGE);
+    let page = request.parse(&comments).unwrap();
+    assert!(request.extract(page).iter().all(|comment| comment.update.is_some()));
+
+    let query = CommentQuery::new().embedded_updates(false);
+    let request = query.page_request(FIRST_PAGE);
+    assert!(!request.path().unwrap().contains("embedded"));
+
+    let page = request.parse(&comments).unwrap();
+    for comment in request.extract(page) {
+        assert!(comment.update.is_none());
+        assert_eq!(comment.update_alias(), Some("FEDORA-2024-1a2b3c4d5e"));
+    }
+}
+
+#[test]
+fn update_query_builds_like() {
+    let path = UpdateQuery::new()
+        .builds_like("openssl-3.0")
+        .page_request(FIRST_PAGE)
+        .path()
+        .unwrap();
+    assert_eq!(path, "/updates/?like=openssl-3.0&page=1&rows_per_page=50");
+
+    let path = UpdateQuery::new()
+        .builds(&["openssl-3.0.9-2.fc38"])
+        .page_request(FIRST_PAGE)
+        .path()
+        .unwrap();
+    assert_eq!(path, "/updates/?builds=openssl-3.0.9-2.fc38&page=1&rows_per_page=50");
+
+    // wildcards and separators are percent-encoded
+    let path = UpdateQuery::new()
+        .builds_like("rust-%bodhi 2")
+        .page_request(FIRST_PAGE)
+        .path()
+        .unwrap();
+    assert!(path.contains("like=rust-%25bodhi+2"), "{path}");
+}
@@ -12,6 +12,19 @@ async fn query() {
     let _releases: Vec<Release> = bodhi.paginated_request(&ReleaseQuery::new()).await.unwrap();
 }
 
+#[tokio::test]
+async fn download_all() {
+    let bodhi = bodhi_init().await;
+
+    let releases: Vec<Release> = bodhi.paginated_request(&ReleaseQuery::new()).await.unwrap();
+    let count = bodhi
+        .download_all(&ReleaseQuery::new().rows_per_page(10), futures_util::sink::drain())
+        .await
+        .unwrap();
+
+    assert_eq!(count, releases.len() as u64);
+}
+
 #[tokio::test]
 async fn name_query_ok() {
     let bodhi = bodhi_init().await;
@@ -27,5 +40,14 @@ async fn name_query_err() {
 
     let release = bodhi.request(&ReleaseNameQuery::new("X12")).await;
 
-    assert!(matches!(release, Err(QueryError::NotFound)));
+    assert!(matches!(release, Err(QueryError::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn name_query_none() {
+    let bodhi = bodhi_init().await;
+
+    let release = bodhi.request_opt(&ReleaseNameQuery::new("X12")).await;
+
+    assert!(matches!(release, Ok(None)));
 }
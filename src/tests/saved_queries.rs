@@ -0,0 +1,118 @@
+use crate::mock::MockServer;
+use crate::saved_queries::{QueryFilters, ResultSet, SavedQueries};
+use crate::{QueryUrlError, UpdateStatus, UpdateType};
+
+const SAVED_QUERIES: &str = r#"
+[[query]]
+label = "pending updates"
+entity = "updates"
+releases = ["F38"]
+status = "pending"
+modified_since = "2024-05-01 00:00:00"
+
+[[query]]
+label = "active overrides"
+entity = "overrides"
+expired = false
+
+[[query]]
+label = "running composes"
+entity = "composes"
+"#;
+
+#[test]
+fn parse_saved_queries() {
+    let queries = SavedQueries::from_toml(SAVED_QUERIES).unwrap();
+    assert_eq!(queries.queries.len(), 3);
+    assert_eq!(queries.queries[0].label, "pending updates");
+
+    match &queries.queries[0].filters {
+        QueryFilters::Updates(filters) => {
+            assert_eq!(filters.releases.as_ref().unwrap()[0].to_string(), "F38");
+            assert_eq!(filters.status, Some(UpdateStatus::Pending));
+            assert_eq!(
+                filters.modified_since.as_ref().unwrap().to_string(),
+                "2024-05-01 00:00:00"
+            );
+        },
+        _ => panic!("Unexpected entity type."),
+    }
+
+    assert!(matches!(queries.queries[1].filters, QueryFilters::Overrides(_)));
+
+    // unknown filters and entity types are rejected
+    let unknown_filter = "[[query]]\nlabel = \"invalid\"\nentity = \"packages\"\nreleases = [\"F38\"]\n";
+    assert!(SavedQueries::from_toml(unknown_filter).is_err());
+    let unknown_entity = "[[query]]\nlabel = \"invalid\"\nentity = \"stacks\"\n";
+    assert!(SavedQueries::from_toml(unknown_entity).is_err());
+}
+
+#[tokio::test]
+async fn run_saved_queries() {
+    let bodhi = MockServer::with_fixtures().client().await.unwrap();
+
+    let queries = SavedQueries::from_toml(SAVED_QUERIES).unwrap();
+    let results = queries.run(&bodhi).await.unwrap();
+    assert_eq!(results.len(), 3);
+
+    assert_eq!(results[0].label, "pending updates");
+    match &results[0].results {
+        ResultSet::Updates(updates) => assert_eq!(updates[0].alias, "FEDORA-2024-1a2b3c4d5e"),
+        _ => panic!("Unexpected result type."),
+    }
+
+    assert!(matches!(results[1].results, ResultSet::Overrides(_)));
+    assert!(!results[1].results.is_empty());
+    assert!(matches!(results[2].results, ResultSet::Composes(_)));
+}
+
+#[test]
+fn filters_from_url() {
+    let filters = QueryFilters::from_url(
+        "https://bodhi.fedoraproject.org/updates/?releases=F39,F40&releases=F41&status=testing&type=security&user=decathorpe&bugs=123,456&locked=false&page=2&chrome=0",
+    )
+    .unwrap();
+
+    match filters {
+        QueryFilters::Updates(filters) => {
+            let releases: Vec<String> = filters.releases.unwrap().iter().map(ToString::to_string).collect();
+            assert_eq!(releases, ["F39", "F40", "F41"]);
+            assert_eq!(filters.status, Some(UpdateStatus::Testing));
+            assert_eq!(filters.update_type, Some(UpdateType::Security));
+            assert_eq!(filters.users.unwrap(), ["decathorpe"]);
+            assert_eq!(filters.bugs.unwrap(), [123, 456]);
+            assert_eq!(filters.locked, Some(false));
+        },
+        _ => panic!("Unexpected entity type."),
+    }
+
+    // paths without scheme and host are accepted as well
+    let filters = QueryFilters::from_url("/overrides/?expired=true&user=packager").unwrap();
+    match filters {
+        QueryFilters::Overrides(filters) => {
+            assert_eq!(filters.expired, Some(true));
+            assert_eq!(filters.users.unwrap(), ["packager"]);
+        },
+        _ => panic!("Unexpected entity type."),
+    }
+
+    assert!(matches!(
+        QueryFilters::from_url("https://bodhi.fedoraproject.org/updates/FEDORA-2024-1a2b3c4d5e"),
+        Err(QueryUrlError::UnknownEndpoint { .. })
+    ));
+    assert!(matches!(
+        QueryFilters::from_url("https://bodhi.fedoraproject.org/updates/?status=foo"),
+        Err(QueryUrlError::InvalidValue { .. })
+    ));
+}
+
+#[tokio::test]
+async fn run_filters_from_url() {
+    let bodhi = MockServer::with_fixtures().client().await.unwrap();
+
+    let filters = QueryFilters::from_url("/releases/?exclude_archived=true").unwrap();
+    match filters.run(&bodhi).await.unwrap() {
+        ResultSet::Releases(releases) => assert!(!releases.is_empty()),
+        _ => panic!("Unexpected entity type."),
+    }
+}
@@ -1,4 +1,27 @@
-use crate::{Bug, TestCase};
+use crate::{
+    migrate_json,
+    BotList,
+    Bug,
+    CommentId,
+    CritpathGroup,
+    Entity,
+    ExtraExtension,
+    FedoraRelease,
+    Group,
+    Karma,
+    MigrationError,
+    PackageManager,
+    TestCase,
+    Update,
+    UpdateRequest,
+    UpdateStatus,
+    User,
+    UserId,
+    DATA_SCHEMA_VERSION,
+};
+
+const UPDATE_JSON: &str = include_str!("data/update.json");
+const UPDATE_BODHI5_JSON: &str = include_str!("data/update_bodhi5.json");
 
 #[test]
 fn bug_url() {
@@ -29,3 +52,385 @@ fn testcase_url() {
         "https://fedoraproject.org/wiki/QA:Foo_Bar_Baz"
     );
 }
+
+#[test]
+fn user_avatar_url_generated() {
+    let user = User {
+        avatar: None,
+        email: None,
+        groups: Vec::new(),
+        id: UserId::from(1),
+        name: String::from("decathorpe"),
+        openid: Some(String::from("decathorpe.id.fedoraproject.org")),
+        extra: Default::default(),
+    };
+
+    assert_eq!(
+        user.avatar_url(64).to_string(),
+        "https://seccdn.libravatar.org/avatar/e5045d506aa85d296de0918e4dd52b74cc33dec956be122a03bfdbe4be24e97f?s=64&d=retro"
+    );
+}
+
+#[test]
+fn user_avatar_url_resized() {
+    let user = User {
+        avatar: Some(String::from("https://seccdn.libravatar.org/avatar/abcdef?s=24&d=retro")),
+        email: None,
+        groups: Vec::new(),
+        id: UserId::from(1),
+        name: String::from("decathorpe"),
+        openid: None,
+        extra: Default::default(),
+    };
+
+    assert_eq!(
+        user.avatar_url(128).to_string(),
+        "https://seccdn.libravatar.org/avatar/abcdef?d=retro&s=128"
+    );
+}
+
+#[test]
+fn user_known_bots() {
+    let user = |name: &str, openid: Option<&str>| User {
+        avatar: None,
+        email: None,
+        groups: Vec::new(),
+        id: UserId::from(1),
+        name: String::from(name),
+        openid: openid.map(String::from),
+        extra: Default::default(),
+    };
+
+    assert!(user("zodbot", None).is_known_bot());
+    assert!(user("example-ci", None).is_known_bot());
+    assert!(user("service", Some("http://packit.id.fedoraproject.org/")).is_known_bot());
+    assert!(!user("decathorpe", Some("decathorpe.id.fedoraproject.org")).is_known_bot());
+
+    let bots = BotList::default().with("decathorpe").without("zodbot");
+    assert!(bots.is_bot(&user("decathorpe", None)));
+    assert!(!bots.is_bot(&user("zodbot", None)));
+    assert!(bots.is_bot(&user("service", Some("packit.id.fedoraproject.org"))));
+}
+
+#[test]
+fn extra_fields() {
+    let result = serde_json::from_str::<Group>(r#"{"name": "packager", "description": "Packagers"}"#);
+
+    if cfg!(feature = "strict-schema") {
+        assert!(result.is_err());
+    } else {
+        let group = result.unwrap();
+        assert_eq!(group.name, "packager");
+        assert!(group.extra().contains_key("description"));
+    }
+}
+
+#[test]
+fn extra_fields_typed() {
+    #[derive(serde::Deserialize)]
+    struct Tracking {
+        ticket: String,
+        priority: Option<u32>,
+    }
+
+    impl ExtraExtension for Tracking {
+        type Entity = Update;
+    }
+
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    assert!(update.extra_typed::<Tracking>().is_err());
+
+    update
+        .extra_mut()
+        .insert(String::from("ticket"), serde_json::json!("INFRA-1234"));
+    update
+        .extra_mut()
+        .insert(String::from("unrelated"), serde_json::json!(true));
+
+    let tracking = update.extra_typed::<Tracking>().unwrap();
+    assert_eq!(tracking.ticket, "INFRA-1234");
+    assert_eq!(tracking.priority, None);
+}
+
+#[test]
+fn typed_ids() {
+    let id: CommentId = serde_json::from_str("19999").unwrap();
+
+    assert_eq!(id, CommentId::from(19_999));
+    assert_eq!(id, 19_999);
+    assert_eq!(u32::from(id), 19_999);
+    assert_eq!(id.to_string(), "19999");
+    assert_eq!(serde_json::to_string(&id).unwrap(), "19999");
+}
+
+#[test]
+fn update_feedback_by_bug() {
+    let update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    let feedback = update.feedback_by_bug();
+
+    assert_eq!(feedback.len(), 3);
+    assert_eq!(feedback[&2270001].len(), 1);
+    assert_eq!(feedback[&2270003].len(), 0);
+
+    let karma: Vec<Karma> = feedback[&2270002].iter().map(|item| item.karma).collect();
+    assert_eq!(karma, vec![Karma::Positive, Karma::Negative]);
+}
+
+#[test]
+fn update_feedback_by_testcase() {
+    let update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    let feedback = update.feedback_by_testcase();
+
+    assert_eq!(feedback.len(), 3);
+    assert_eq!(feedback["QA:Testcase foo startup"].len(), 2);
+    assert_eq!(feedback["QA:Testcase foo cli"][0].karma, Karma::Neutral);
+    assert!(feedback["QA:Testcase foo gui"].is_empty());
+}
+
+#[test]
+fn update_version_hash() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    assert_eq!(
+        update.compute_version_hash(),
+        "2cdcd35dcf3bb147bdffa3f9bce0fc61a75612c4"
+    );
+    assert!(update.verify_version_hash());
+
+    // the hash does not depend on the order of builds
+    let mut other = serde_json::to_value(&update.builds[0]).unwrap();
+    other["nvr"] = serde_json::Value::from("bar-0.1.0-1.fc40");
+    update.builds.push(serde_json::from_value(other).unwrap());
+
+    assert_eq!(
+        update.compute_version_hash(),
+        "eda3010923927138f5d43aa82d35b5b45aa8efe0"
+    );
+    update.builds.reverse();
+    assert_eq!(
+        update.compute_version_hash(),
+        "eda3010923927138f5d43aa82d35b5b45aa8efe0"
+    );
+    assert!(!update.verify_version_hash());
+}
+
+#[test]
+fn update_schema_drift() {
+    let update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    // all fields of the fixture (which mirrors current bodhi server responses) are typed
+    assert!(update.extra().is_empty());
+    assert!(update.release.extra().is_empty());
+    assert!(update.user.extra().is_empty());
+    assert!(update.user.groups.iter().all(|group| group.extra().is_empty()));
+    assert!(update.bugs.iter().all(|bug| bug.extra().is_empty()));
+    assert!(update.builds.iter().all(|build| build.extra().is_empty()));
+    assert!(update
+        .test_cases
+        .iter()
+        .flatten()
+        .all(|testcase| testcase.extra().is_empty()));
+
+    for comment in update.comments.iter().flatten() {
+        assert!(comment.extra().is_empty());
+        assert!(comment.user.extra().is_empty());
+        assert!(comment.bug_feedback.iter().all(|feedback| feedback.extra().is_empty()));
+        assert!(comment
+            .testcase_feedback
+            .iter()
+            .all(|feedback| feedback.extra().is_empty()));
+    }
+
+    assert_eq!(update.release.released_on.as_deref(), Some("2024-04-23"));
+}
+
+#[test]
+fn update_older_server() {
+    // fixture without the fields that are not included in responses from older server versions
+    let update: Update = serde_json::from_str(UPDATE_BODHI5_JSON).unwrap();
+
+    assert!(update.extra().is_empty());
+    assert!(update.release.extra().is_empty());
+
+    assert!(update.critpath_groups.is_none());
+    assert!(update.from_tag.is_none());
+    assert!(update.test_gating_status.is_none());
+    assert!(update.version_hash.is_empty());
+    assert!(!update.verify_version_hash());
+
+    assert_eq!(update.release.package_manager, PackageManager::Unspecified);
+    assert!(update.release.testing_repository.is_none());
+    assert!(update.release.eol.is_none());
+    assert!(update.release.released_on.is_none());
+}
+
+// fields that are only provided for backwards compatibility, and that are not included in
+// responses from newer server versions
+fn without_compat_fields(json: &str) -> String {
+    let mut update: serde_json::Value = serde_json::from_str(json).unwrap();
+
+    let object = update.as_object_mut().unwrap();
+    object.remove("updateid");
+    object.remove("date_approved");
+
+    for comment in update["comments"].as_array_mut().unwrap() {
+        let comment = comment.as_object_mut().unwrap();
+        comment.remove("author");
+        comment.remove("karma_critpath");
+        comment.remove("update_alias");
+    }
+
+    update.to_string()
+}
+
+#[test]
+fn update_server_versions() {
+    // responses from all server versions that are still deployed need to be parsed
+    let fixtures = [
+        ("5.x", String::from(UPDATE_BODHI5_JSON)),
+        ("7.x", String::from(UPDATE_JSON)),
+        ("8.x", without_compat_fields(UPDATE_JSON)),
+    ];
+
+    for (version, json) in fixtures {
+        let update: Update = serde_json::from_str(&json).unwrap_or_else(|error| panic!("bodhi {version}: {error}"));
+
+        assert_eq!(update.alias, "FEDORA-2024-1a2b3c4d5e", "bodhi {version}");
+        assert!(update.extra().is_empty(), "bodhi {version}");
+
+        let comments = update.comments.as_ref().unwrap();
+        assert!(!comments.is_empty(), "bodhi {version}");
+        for comment in comments {
+            assert!(comment.extra().is_empty(), "bodhi {version}");
+        }
+
+        // values survive a roundtrip through the serialized representation of this crate
+        let value = serde_json::to_string(&update).unwrap();
+        let roundtrip: Update = serde_json::from_str(&value).unwrap();
+        assert_eq!(roundtrip.alias, update.alias, "bodhi {version}");
+    }
+}
+
+#[test]
+fn update_critpath_groups() {
+    let mut update: serde_json::Value = serde_json::from_str(UPDATE_JSON).unwrap();
+    update["critpath_groups"] = serde_json::Value::from("critical-path-base critical-path-foo");
+
+    let update: Update = serde_json::from_value(update).unwrap();
+    assert_eq!(
+        update.critpath_groups.as_deref(),
+        Some(
+            [
+                CritpathGroup::Base,
+                CritpathGroup::Other(String::from("critical-path-foo"))
+            ]
+            .as_slice()
+        )
+    );
+
+    let value = serde_json::to_value(&update).unwrap();
+    assert_eq!(value["critpath_groups"], "critical-path-base critical-path-foo");
+
+    // the field is not present in responses from older server versions
+    let mut update: serde_json::Value = serde_json::from_str(UPDATE_JSON).unwrap();
+    update.as_object_mut().unwrap().remove("critpath_groups");
+    let update: Update = serde_json::from_value(update).unwrap();
+    assert!(update.critpath_groups.is_none());
+}
+
+#[test]
+fn update_unsigned_builds() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    assert!(update.builds.iter().all(|build| build.signed));
+    assert!(update.unsigned_builds().is_empty());
+
+    let mut other = serde_json::to_value(&update.builds[0]).unwrap();
+    other["nvr"] = serde_json::Value::from("bar-0.1.0-1.fc40");
+    other["signed"] = serde_json::Value::from(false);
+    update.builds.push(serde_json::from_value(other).unwrap());
+
+    let unsigned: Vec<&str> = update
+        .unsigned_builds()
+        .iter()
+        .map(|build| build.nvr.as_str())
+        .collect();
+    assert_eq!(unsigned, vec!["bar-0.1.0-1.fc40"]);
+}
+
+#[test]
+fn update_json_roundtrip() {
+    let original: serde_json::Value = serde_json::from_str(UPDATE_JSON).unwrap();
+
+    let update = Update::from_json(UPDATE_JSON).unwrap();
+    let json = update.to_json().unwrap();
+
+    // serializing values results in the same JSON representation as the server response
+    let serialized: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(serialized, original);
+
+    // deserializing and serializing values again does not lose any information
+    let again = Update::from_json(&json).unwrap().to_json().unwrap();
+    assert_eq!(serde_json::from_str::<serde_json::Value>(&again).unwrap(), original);
+
+    // this also holds for values with unknown fields
+    if !cfg!(feature = "strict-schema") {
+        let mut original = original;
+        original["user"]["foo"] = serde_json::Value::from("bar");
+
+        let user = User::from_json(&original["user"].to_string()).unwrap();
+        let serialized: serde_json::Value = serde_json::from_str(&user.to_json().unwrap()).unwrap();
+        assert_eq!(serialized, original["user"]);
+    }
+}
+
+#[test]
+fn release_rawhide() {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    assert!(!update.release.is_rawhide());
+
+    update.release.branch = String::from("rawhide");
+    update.release.composed_by_bodhi = false;
+    update.release.create_automatic_updates = Some(true);
+    assert!(update.release.is_rawhide());
+
+    update.status = UpdateStatus::Pending;
+    assert_eq!(update.allowed_requests(), [UpdateRequest::Obsolete]);
+
+    update.release.name = FedoraRelease::ELN;
+    assert!(!update.release.is_rawhide());
+    assert_eq!(
+        update.allowed_requests(),
+        [UpdateRequest::Testing, UpdateRequest::Obsolete]
+    );
+}
+
+
+#[test]
+fn update_migrate_json() {
+    let current: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    let expected = current.to_json().unwrap();
+
+    // data written with the current schema version is not modified
+    let value: serde_json::Value = serde_json::from_str(UPDATE_JSON).unwrap();
+    let migrated = migrate_json(DATA_SCHEMA_VERSION, value).unwrap();
+    assert_eq!(migrated.to_json().unwrap(), expected);
+
+    // older versions of this crate serialized nested comments with an empty "update" field
+    let mut value: serde_json::Value = serde_json::from_str(UPDATE_JSON).unwrap();
+    for comment in value["comments"].as_array_mut().unwrap() {
+        comment["update"] = serde_json::Value::Null;
+    }
+    let migrated = migrate_json(0, value).unwrap();
+    assert_eq!(migrated.to_json().unwrap(), expected);
+
+    let value: serde_json::Value = serde_json::from_str(UPDATE_JSON).unwrap();
+    assert!(matches!(
+        migrate_json(DATA_SCHEMA_VERSION + 1, value),
+        Err(MigrationError::UnsupportedVersion { .. })
+    ));
+
+    assert!(matches!(
+        migrate_json(0, serde_json::json!({"alias": "FEDORA-2024-1a2b3c4d5e"})),
+        Err(MigrationError::DeserializationError { .. })
+    ));
+}
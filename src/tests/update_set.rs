@@ -0,0 +1,62 @@
+use crate::{FedoraRelease, TestGatingStatus, Update, UpdateSet, UpdateType};
+
+const UPDATE_JSON: &str = include_str!("data/update.json");
+
+fn update(alias: &str, release: &str, karma: i32) -> Update {
+    let mut update: Update = serde_json::from_str(UPDATE_JSON).unwrap();
+    update.alias = alias.to_owned();
+    update.release.name = FedoraRelease::try_from(release).unwrap();
+    update.karma = Some(karma);
+    update
+}
+
+fn updates() -> UpdateSet {
+    let mut first = update("FEDORA-2024-1", "F40", 1);
+    first.update_type = UpdateType::Security;
+    first.test_gating_status = Some(TestGatingStatus::Failed);
+
+    let mut second = update("FEDORA-2024-2", "F40", 3);
+    second.test_gating_status = Some(TestGatingStatus::GreenwaveFailed);
+
+    let mut third = update("FEDORA-2024-3", "F39", 1);
+    third.update_type = UpdateType::Security;
+
+    let mut fourth = update("FEDORA-2024-4", "F39", -1);
+    fourth.karma = None;
+
+    vec![first, second, third, fourth].into()
+}
+
+fn aliases(updates: &UpdateSet) -> Vec<&str> {
+    updates.iter().map(|update| update.alias.as_str()).collect()
+}
+
+#[test]
+fn update_set_filters() {
+    assert_eq!(aliases(&updates().security_only()), ["FEDORA-2024-1", "FEDORA-2024-3"]);
+    assert_eq!(
+        aliases(&updates().with_failed_gating()),
+        ["FEDORA-2024-1", "FEDORA-2024-2"]
+    );
+    assert_eq!(
+        aliases(&updates().security_only().with_failed_gating()),
+        ["FEDORA-2024-1"]
+    );
+    assert!(updates().filter(|update| update.karma == Some(5)).is_empty());
+}
+
+#[test]
+fn update_set_sort() {
+    assert_eq!(
+        aliases(&updates().sort_by_karma()),
+        ["FEDORA-2024-2", "FEDORA-2024-1", "FEDORA-2024-3", "FEDORA-2024-4"]
+    );
+}
+
+#[test]
+fn update_set_groups() {
+    let groups = updates().sort_by_karma().group_by_release();
+    assert_eq!(groups.keys().collect::<Vec<_>>(), ["F39", "F40"]);
+    assert_eq!(aliases(&groups["F39"]), ["FEDORA-2024-3", "FEDORA-2024-4"]);
+    assert_eq!(aliases(&groups["F40"]), ["FEDORA-2024-2", "FEDORA-2024-1"]);
+}
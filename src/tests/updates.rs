@@ -193,5 +193,27 @@ async fn id_query_err() {
 
     let update = bodhi.request(&UpdateIDQuery::new("NOPE")).await;
 
-    assert!(matches!(update, Err(QueryError::NotFound)));
+    assert!(matches!(update, Err(QueryError::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn id_query_none() {
+    let bodhi = bodhi_init().await;
+
+    let update = bodhi.request_opt(&UpdateIDQuery::new("NOPE")).await;
+
+    assert!(matches!(update, Ok(None)));
+}
+
+#[tokio::test]
+async fn summarize() {
+    let bodhi = bodhi_init().await;
+
+    let releases = [FedoraRelease::try_from("F36").unwrap()];
+    let summary = bodhi.summarize(&UpdateQuery::new().releases(&releases)).await.unwrap();
+
+    // all updates for an archived release are either stable or obsolete
+    assert_eq!(summary.pending, 0);
+    assert_eq!(summary.testing, 0);
+    assert!(summary.stable > 0);
 }
@@ -39,5 +39,14 @@ async fn name_query_err() {
 
     let user = bodhi.request(&UserNameQuery::new("nobody")).await;
 
-    assert!(matches!(user, Err(QueryError::NotFound)));
+    assert!(matches!(user, Err(QueryError::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn name_query_none() {
+    let bodhi = bodhi_init().await;
+
+    let user = bodhi.request_opt(&UserNameQuery::new("nobody")).await;
+
+    assert!(matches!(user, Ok(None)));
 }
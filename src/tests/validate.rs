@@ -0,0 +1,46 @@
+use crate::mock::fixture;
+use crate::validate::{check_consistency, Inconsistency};
+use crate::{Comment, CommentId, Override, Update};
+
+fn load<T: serde::de::DeserializeOwned>(name: &str, key: &str) -> T {
+    let value: serde_json::Value = serde_json::from_str(fixture(name).unwrap()).unwrap();
+    serde_json::from_value(value[key].clone()).unwrap()
+}
+
+#[test]
+fn consistent_data() {
+    let updates: Vec<Update> = load("updates_f38.json", "updates");
+    let overrides: Vec<Override> = load("overrides.json", "overrides");
+    let comments: Vec<Comment> = load("comments.json", "comments");
+
+    assert!(check_consistency(&updates, &overrides, &comments).is_empty());
+}
+
+#[test]
+fn inconsistent_data() {
+    let mut updates: Vec<Update> = load("updates_f38.json", "updates");
+    let overrides: Vec<Override> = load("overrides.json", "overrides");
+    let mut comments: Vec<Comment> = load("comments.json", "comments");
+
+    comments.push(load("comment.json", "comment"));
+    updates[0].builds.clear();
+
+    let inconsistencies = check_consistency(&updates, &overrides, &comments);
+    assert_eq!(
+        inconsistencies,
+        vec![
+            Inconsistency::DuplicateComment {
+                id: CommentId::from(3000003)
+            },
+            Inconsistency::OverrideWithoutUpdate {
+                nvr: String::from("foo-1.2.3-1.fc38")
+            },
+        ]
+    );
+
+    let inconsistencies = check_consistency(&[], &[], &comments[..1]);
+    assert_eq!(
+        inconsistencies[0].to_string(),
+        "Comment 3000001 on unknown update: FEDORA-2024-1a2b3c4d5e"
+    );
+}
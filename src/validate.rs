@@ -0,0 +1,131 @@
+//! # consistency checks for mirrored data
+//!
+//! This module contains functions for detecting inconsistencies between updates, buildroot
+//! overrides, and comments that were mirrored from a bodhi instance. Data that is synchronized
+//! incrementally (for example, with [`BodhiClient::update_changes_since`]) can get out of sync
+//! with the server, and these checks can be used for monitoring the quality of such mirrors.
+
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+use crate::data::{Comment, CommentId, Override, Update};
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::client::BodhiClient;
+
+/// inconsistencies that can be detected between mirrored updates, overrides, and comments
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Inconsistency {
+    /// multiple updates with the same alias
+    DuplicateUpdate {
+        /// alias of the duplicated update
+        alias: String,
+    },
+    /// multiple buildroot overrides for the same build
+    DuplicateOverride {
+        /// NVR of the build of the duplicated override
+        nvr: String,
+    },
+    /// multiple comments with the same ID
+    DuplicateComment {
+        /// ID of the duplicated comment
+        id: CommentId,
+    },
+    /// buildroot override for a build that is not included in any update
+    OverrideWithoutUpdate {
+        /// NVR of the build of the override
+        nvr: String,
+    },
+    /// comment on an update that is not included in the data set
+    CommentWithUnknownUpdate {
+        /// ID of the comment
+        id: CommentId,
+        /// alias of the unknown update
+        alias: String,
+    },
+}
+
+impl Display for Inconsistency {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Inconsistency::DuplicateUpdate { alias } => write!(f, "Duplicate update: {alias}"),
+            Inconsistency::DuplicateOverride { nvr } => write!(f, "Duplicate override: {nvr}"),
+            Inconsistency::DuplicateComment { id } => write!(f, "Duplicate comment: {id}"),
+            Inconsistency::OverrideWithoutUpdate { nvr } => {
+                write!(f, "Override for build that is not part of any update: {nvr}")
+            },
+            Inconsistency::CommentWithUnknownUpdate { id, alias } => {
+                write!(f, "Comment {id} on unknown update: {alias}")
+            },
+        }
+    }
+}
+
+
+/// check mirrored updates, buildroot overrides, and comments for inconsistencies
+///
+/// The following inconsistencies are detected:
+///
+/// - updates, overrides, or comments that occur more than once
+/// - overrides for builds that are not included in any of the updates
+/// - comments on updates that are not included in the updates
+///
+/// Comments are associated with updates by their update alias. Comments that do not include the
+/// alias of their update are not checked. Inconsistencies are returned in the order of the
+/// checks listed above.
+pub fn check_consistency(updates: &[Update], overrides: &[Override], comments: &[Comment]) -> Vec<Inconsistency> {
+    let mut inconsistencies = Vec::new();
+
+    let mut aliases = HashSet::new();
+    for update in updates {
+        if !aliases.insert(update.alias.as_str()) {
+            inconsistencies.push(Inconsistency::DuplicateUpdate {
+                alias: update.alias.clone(),
+            });
+        }
+    }
+
+    let mut override_nvrs = HashSet::new();
+    for over_ride in overrides {
+        if !override_nvrs.insert(over_ride.nvr.as_str()) {
+            inconsistencies.push(Inconsistency::DuplicateOverride {
+                nvr: over_ride.nvr.clone(),
+            });
+        }
+    }
+
+    let mut comment_ids = HashSet::new();
+    for comment in comments {
+        if !comment_ids.insert(comment.id) {
+            inconsistencies.push(Inconsistency::DuplicateComment { id: comment.id });
+        }
+    }
+
+    let update_nvrs: HashSet<&str> = updates
+        .iter()
+        .flat_map(|update| update.builds.iter().map(|build| build.nvr.as_str()))
+        .collect();
+
+    for over_ride in overrides {
+        if !update_nvrs.contains(over_ride.nvr.as_str()) {
+            inconsistencies.push(Inconsistency::OverrideWithoutUpdate {
+                nvr: over_ride.nvr.clone(),
+            });
+        }
+    }
+
+    for comment in comments {
+        if let Some(alias) = comment.update_alias() {
+            if !aliases.contains(alias) {
+                inconsistencies.push(Inconsistency::CommentWithUnknownUpdate {
+                    id: comment.id,
+                    alias: alias.to_owned(),
+                });
+            }
+        }
+    }
+
+    inconsistencies
+}
@@ -0,0 +1,432 @@
+//! # watching for server-side changes
+//!
+//! This module contains helpers for reacting to changes on the bodhi server. Since bodhi does not
+//! provide a way to subscribe to changes, the current state is polled periodically, and events
+//! are emitted for differences between consecutive results.
+//!
+//! Changes to updates can also be received from the message bus (fedora-messaging), but consuming
+//! messages is out of scope for this crate. The [`UpdatesFeed`] type merges updates received from
+//! any source with the results of an initial query.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use bodhi::watch::release_states;
+//! use bodhi::BodhiClientBuilder;
+//! use futures_util::StreamExt;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let bodhi = BodhiClientBuilder::default().build().await.unwrap();
+//!
+//! let mut changes = Box::pin(release_states(&bodhi, Duration::from_secs(3600)));
+//! while let Some(change) = changes.next().await {
+//!     let change = change.unwrap();
+//!     if change.is_archived() {
+//!         println!("{} has been archived", change.release);
+//!     }
+//! }
+//! # }
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
+
+use futures_util::{stream, Stream, StreamExt};
+
+use crate::client::BodhiClient;
+use crate::data::{BodhiDate, FedoraRelease, Release, ReleaseState, Update};
+use crate::error::QueryError;
+use crate::query::{ReleaseQuery, UpdateQuery};
+
+/// data type that represents a change of the state of a release
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ReleaseStateChange {
+    /// release whose state changed
+    pub release: FedoraRelease,
+    /// previous state of the release (`None` if the release is new)
+    pub previous: Option<ReleaseState>,
+    /// current state of the release
+    pub current: ReleaseState,
+}
+
+impl ReleaseStateChange {
+    /// flag to indicate whether this change archived the release
+    pub fn is_archived(&self) -> bool {
+        self.current == ReleaseState::Archived && self.previous != Some(ReleaseState::Archived)
+    }
+}
+
+impl Display for ReleaseStateChange {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self.previous {
+            Some(previous) => write!(f, "{}: {} -> {}", self.release, previous, self.current),
+            None => write!(f, "{}: (new) -> {}", self.release, self.current),
+        }
+    }
+}
+
+
+// compute the state changes between two consecutive results
+fn diff_states(
+    previous: &HashMap<FedoraRelease, ReleaseState>,
+    current: &HashMap<FedoraRelease, ReleaseState>,
+) -> Vec<ReleaseStateChange> {
+    let mut changes: Vec<ReleaseStateChange> = current
+        .iter()
+        .filter(|(release, state)| previous.get(*release) != Some(*state))
+        .map(|(release, state)| ReleaseStateChange {
+            release: release.clone(),
+            previous: previous.get(release).copied(),
+            current: *state,
+        })
+        .collect();
+
+    changes.sort_by(|a, b| a.release.cmp(&b.release));
+    changes
+}
+
+fn release_state_map(releases: Vec<Release>) -> HashMap<FedoraRelease, ReleaseState> {
+    releases
+        .into_iter()
+        .map(|release| (release.name, release.state))
+        .collect()
+}
+
+
+// internal state of the stream returned by `release_states`
+struct ReleaseWatcher<'a> {
+    client: &'a BodhiClient,
+    interval: Duration,
+    started: bool,
+    states: Option<HashMap<FedoraRelease, ReleaseState>>,
+    pending: VecDeque<ReleaseStateChange>,
+}
+
+/// stream of changes of release states, based on periodically querying all releases
+///
+/// The first query establishes the initial states of all releases, and does not result in any
+/// events. After that, all releases are queried again after every `interval`, and events are
+/// emitted for releases whose state has changed (for example, when a release was archived after
+/// its EOL), and for new releases.
+///
+/// Failed queries result in an error item, but do not end the stream, and the next query is
+/// made after the regular interval. The stream itself never ends.
+pub fn release_states(
+    client: &BodhiClient,
+    interval: Duration,
+) -> impl Stream<Item = Result<ReleaseStateChange, QueryError>> + '_ {
+    let watcher = ReleaseWatcher {
+        client,
+        interval,
+        started: false,
+        states: None,
+        pending: VecDeque::new(),
+    };
+
+    stream::unfold(watcher, |mut watcher| async move {
+        loop {
+            if let Some(change) = watcher.pending.pop_front() {
+                return Some((Ok(change), watcher));
+            }
+
+            if watcher.started {
+                tokio::time::sleep(watcher.interval).await;
+            }
+            watcher.started = true;
+
+            let current = match watcher.client.paginated_request(&ReleaseQuery::new()).await {
+                Ok(releases) => release_state_map(releases),
+                Err(error) => return Some((Err(error), watcher)),
+            };
+
+            if let Some(previous) = &watcher.states {
+                watcher.pending.extend(diff_states(previous, &current));
+            }
+            watcher.states = Some(current);
+        }
+    })
+}
+
+
+/// kind of an event that is emitted by an [`UpdatesFeed`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum UpdateEventKind {
+    /// update was included in the results of the initial query
+    Snapshot,
+    /// update was not known before
+    New,
+    /// newer state of an update that was already known
+    Changed,
+}
+
+/// data type that represents an event that is emitted by an [`UpdatesFeed`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct UpdateEvent {
+    /// kind of this event
+    pub kind: UpdateEventKind,
+    /// current state of the update
+    pub update: Update,
+}
+
+
+// date & time of the last change of an update (including changes that do not affect its
+// modification date, like status changes and new comments)
+fn last_change(update: &Update) -> Option<&BodhiDate> {
+    let comments = update.comments.iter().flatten().map(|comment| &comment.timestamp);
+
+    [
+        &update.date_modified,
+        &update.date_submitted,
+        &update.date_testing,
+        &update.date_pushed,
+        &update.date_stable,
+    ]
+    .into_iter()
+    .flatten()
+    .chain(comments)
+    .max()
+}
+
+// dates of the last changes of all updates that were emitted by a feed
+#[derive(Debug, Default)]
+struct SeenUpdates {
+    changes: HashMap<String, Option<BodhiDate>>,
+}
+
+impl SeenUpdates {
+    // determine whether an event needs to be emitted for the given state of an update
+    fn observe(&mut self, update: Update, snapshot: bool) -> Option<UpdateEvent> {
+        let change = last_change(&update).cloned();
+
+        let kind = match self.changes.get(&update.alias) {
+            None if snapshot => UpdateEventKind::Snapshot,
+            None => UpdateEventKind::New,
+            Some(previous) if change > *previous => UpdateEventKind::Changed,
+            // duplicate or outdated state of a known update
+            Some(_) => return None,
+        };
+
+        self.changes.insert(update.alias.clone(), change);
+        Some(UpdateEvent { kind, update })
+    }
+}
+
+/// feed of updates that merges the results of an initial query with a stream of live updates
+///
+/// The feed first emits [`UpdateEventKind::Snapshot`] events for all updates that are returned
+/// by the initial query. After that, it emits events for the updates from the `live` stream, for
+/// example, for updates that were fetched after receiving messages from the message bus (the
+/// messages themselves only contain parts of the update data).
+///
+/// Events are deduplicated by alias and by the date & time of the last change of the update
+/// (the latest of its modification date, status change dates, and comment timestamps). States
+/// of updates that are not newer than the last emitted state are dropped, so duplicate messages
+/// or messages that arrive out of order do not result in events. To not miss any changes, the
+/// live stream should already be subscribed to when the feed is started: changes that happened
+/// while the initial query was running are then emitted after the snapshot, unless they were
+/// already included in it.
+///
+/// ```no_run
+/// use bodhi::watch::UpdatesFeed;
+/// use bodhi::{BodhiClientBuilder, FedoraRelease, Update, UpdateQuery};
+/// use futures_util::{stream, StreamExt};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let bodhi = BodhiClientBuilder::default().build().await.unwrap();
+///
+/// // aliases of updates that were mentioned in messages received from the message bus
+/// # let aliases = stream::iter(vec![String::from("FEDORA-2024-1a2b3c4d5e")]);
+/// let live = aliases
+///     .then(|alias: String| {
+///         let bodhi = &bodhi;
+///         async move { Update::fetch(bodhi, &alias).await }
+///     })
+///     .filter_map(|update| async move { update.ok() });
+///
+/// let releases = [FedoraRelease::try_from("F40").unwrap()];
+/// let query = UpdateQuery::new().releases(&releases);
+///
+/// let mut events = Box::pin(UpdatesFeed::new(&bodhi, query, live).into_stream());
+/// while let Some(event) = events.next().await {
+///     let event = event.unwrap();
+///     println!("{:?}: {}", event.kind, event.update.alias);
+/// }
+/// # }
+/// ```
+pub struct UpdatesFeed<'a, S> {
+    client: &'a BodhiClient,
+    query: UpdateQuery<'a>,
+    live: S,
+}
+
+impl<'a, S> Debug for UpdatesFeed<'a, S> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_struct("UpdatesFeed")
+            .field("client", &self.client)
+            .field("query", &self.query)
+            .finish_non_exhaustive()
+    }
+}
+
+// internal state of the stream returned by `UpdatesFeed::into_stream`
+struct FeedState<'a, S> {
+    client: &'a BodhiClient,
+    query: Option<UpdateQuery<'a>>,
+    live: std::pin::Pin<Box<S>>,
+    seen: SeenUpdates,
+    snapshot: VecDeque<Update>,
+}
+
+impl<'a, S> UpdatesFeed<'a, S>
+where
+    S: Stream<Item = Update> + 'a,
+{
+    /// constructor for [`UpdatesFeed`] from an initial query and a stream of live updates
+    pub fn new(client: &'a BodhiClient, query: UpdateQuery<'a>, live: S) -> Self {
+        UpdatesFeed { client, query, live }
+    }
+
+    /// method for starting the feed
+    ///
+    /// If the initial query fails, an error item is emitted, and the feed continues with events
+    /// for live updates. The stream ends when the stream of live updates ends.
+    pub fn into_stream(self) -> impl Stream<Item = Result<UpdateEvent, QueryError>> + 'a {
+        let state = FeedState {
+            client: self.client,
+            query: Some(self.query),
+            live: Box::pin(self.live),
+            seen: SeenUpdates::default(),
+            snapshot: VecDeque::new(),
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(update) = state.snapshot.pop_front() {
+                    match state.seen.observe(update, true) {
+                        Some(event) => return Some((Ok(event), state)),
+                        None => continue,
+                    }
+                }
+
+                if let Some(query) = state.query.take() {
+                    match state.client.paginated_request(&query).await {
+                        Ok(updates) => {
+                            state.snapshot.extend(updates);
+                            continue;
+                        },
+                        Err(error) => return Some((Err(error), state)),
+                    }
+                }
+
+                let update = state.live.next().await?;
+                if let Some(event) = state.seen.observe(update, false) {
+                    return Some((Ok(event), state));
+                }
+            }
+        })
+    }
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+
+    fn release(name: &str) -> FedoraRelease {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn release_state_changes() {
+        let previous = HashMap::from([
+            (release("F38"), ReleaseState::Current),
+            (release("F39"), ReleaseState::Current),
+            (release("F40"), ReleaseState::Pending),
+        ]);
+        let current = HashMap::from([
+            (release("F38"), ReleaseState::Archived),
+            (release("F39"), ReleaseState::Current),
+            (release("F40"), ReleaseState::Current),
+            (release("F41"), ReleaseState::Pending),
+        ]);
+
+        let changes = diff_states(&previous, &current);
+        assert_eq!(changes.len(), 3);
+
+        assert_eq!(changes[0].release, release("F38"));
+        assert!(changes[0].is_archived());
+        assert_eq!(changes[0].to_string(), "F38: current -> archived");
+
+        assert_eq!(changes[1].release, release("F40"));
+        assert_eq!(changes[1].previous, Some(ReleaseState::Pending));
+        assert!(!changes[1].is_archived());
+
+        assert_eq!(changes[2].release, release("F41"));
+        assert_eq!(changes[2].previous, None);
+
+        assert!(diff_states(&current, &current).is_empty());
+    }
+
+    fn update_json() -> serde_json::Value {
+        let page: serde_json::Value = serde_json::from_str(crate::mock::fixture("update.json").unwrap()).unwrap();
+        page["update"].clone()
+    }
+
+    fn update_with(value: serde_json::Value) -> Update {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn duplicate_of(update: &Update) -> Update {
+        update_with(serde_json::to_value(update).unwrap())
+    }
+
+    #[tokio::test]
+    async fn updates_feed() {
+        let bodhi = MockServer::with_fixtures().client().await.unwrap();
+        let snapshot: Vec<Update> = bodhi.paginated_request(&UpdateQuery::new()).await.unwrap();
+        let known = snapshot[0].alias.clone();
+
+        // duplicate of an update from the snapshot
+        let duplicate = duplicate_of(&snapshot[0]);
+
+        // new comment on an update from the snapshot
+        let mut value = serde_json::to_value(&snapshot[0]).unwrap();
+        let mut comment = value["comments"][0].clone();
+        comment["timestamp"] = serde_json::json!("2030-01-01 00:00:00");
+        value["comments"].as_array_mut().unwrap().push(comment);
+        let commented = update_with(value);
+
+        // outdated state that arrived after the newer state
+        let outdated = duplicate_of(&snapshot[0]);
+
+        // new update
+        let mut value = update_json();
+        value["alias"] = serde_json::json!("FEDORA-2030-0000000000");
+        let new = update_with(value);
+
+        let live = stream::iter(vec![duplicate, commented, outdated, new]);
+        let events: Vec<UpdateEvent> = UpdatesFeed::new(&bodhi, UpdateQuery::new(), live)
+            .into_stream()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        let kinds: Vec<(UpdateEventKind, &str)> = events.iter().map(|e| (e.kind, e.update.alias.as_str())).collect();
+
+        let mut expected: Vec<(UpdateEventKind, &str)> = snapshot
+            .iter()
+            .map(|update| (UpdateEventKind::Snapshot, update.alias.as_str()))
+            .collect();
+        expected.push((UpdateEventKind::Changed, known.as_str()));
+        expected.push((UpdateEventKind::New, "FEDORA-2030-0000000000"));
+        assert_eq!(kinds, expected);
+    }
+}
@@ -78,11 +78,11 @@ fn builds_dejson_f40() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F40).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -97,11 +97,11 @@ fn builds_dejson_f40c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F40C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -116,11 +116,11 @@ fn builds_dejson_f39() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F39).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -135,11 +135,11 @@ fn builds_dejson_f39c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F39C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -154,11 +154,11 @@ fn builds_dejson_f39f() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F39F).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -173,11 +173,11 @@ fn builds_dejson_f38() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F38).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -192,11 +192,11 @@ fn builds_dejson_f38c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F38C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -211,11 +211,11 @@ fn builds_dejson_f38f() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F38F).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -230,11 +230,11 @@ fn builds_dejson_f38m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F38M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -249,11 +249,11 @@ fn builds_dejson_f37() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F37).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -268,11 +268,11 @@ fn builds_dejson_f37c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F37C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -287,11 +287,11 @@ fn builds_dejson_f37f() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F37F).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -306,11 +306,11 @@ fn builds_dejson_f37m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F37M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -325,11 +325,11 @@ fn builds_dejson_f36() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F36).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -344,11 +344,11 @@ fn builds_dejson_f36c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F36C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -363,11 +363,11 @@ fn builds_dejson_f36f() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F36F).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -382,11 +382,11 @@ fn builds_dejson_f36m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F36M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -401,11 +401,11 @@ fn builds_dejson_f35() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F35).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -420,11 +420,11 @@ fn builds_dejson_f35c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F35C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -439,11 +439,11 @@ fn builds_dejson_f35f() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F35F).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -458,11 +458,11 @@ fn builds_dejson_f35m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F35M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -477,11 +477,11 @@ fn builds_dejson_f34() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F34).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -496,11 +496,11 @@ fn builds_dejson_f34c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F34C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -515,11 +515,11 @@ fn builds_dejson_f34f() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F34F).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -534,11 +534,11 @@ fn builds_dejson_f34m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F34M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -553,11 +553,11 @@ fn builds_dejson_f33() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F33).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -572,11 +572,11 @@ fn builds_dejson_f33c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F33C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -591,11 +591,11 @@ fn builds_dejson_f33f() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F33F).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -610,11 +610,11 @@ fn builds_dejson_f33m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F33M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -629,11 +629,11 @@ fn builds_dejson_f32() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F32).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -648,11 +648,11 @@ fn builds_dejson_f32c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F32C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -667,11 +667,11 @@ fn builds_dejson_f32f() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F32F).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -686,11 +686,11 @@ fn builds_dejson_f32m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F32M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -705,11 +705,11 @@ fn builds_dejson_f31() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F31).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -724,11 +724,11 @@ fn builds_dejson_f31c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F31C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -743,11 +743,11 @@ fn builds_dejson_f31f() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F31F).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -762,11 +762,11 @@ fn builds_dejson_f31m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F31M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -781,11 +781,11 @@ fn builds_dejson_f30() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F30).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -800,11 +800,11 @@ fn builds_dejson_f30c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F30C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -819,11 +819,11 @@ fn builds_dejson_f30f() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F30F).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -838,11 +838,11 @@ fn builds_dejson_f30m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F30M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -857,11 +857,11 @@ fn builds_dejson_f29() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F29).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -876,11 +876,11 @@ fn builds_dejson_f29c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F29C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -895,11 +895,11 @@ fn builds_dejson_f29f() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F29F).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -914,11 +914,11 @@ fn builds_dejson_f29m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F29M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -933,11 +933,11 @@ fn builds_dejson_f28() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F28).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -952,11 +952,11 @@ fn builds_dejson_f28c() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F28C).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -971,11 +971,11 @@ fn builds_dejson_f28m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F28M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -990,11 +990,11 @@ fn builds_dejson_f27() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F27).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1009,11 +1009,11 @@ fn builds_dejson_f27m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F27M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1028,11 +1028,11 @@ fn builds_dejson_f26() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F26).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1047,11 +1047,11 @@ fn builds_dejson_f25() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F25).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1066,11 +1066,11 @@ fn builds_dejson_f24() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F24).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1085,11 +1085,11 @@ fn builds_dejson_f23() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F23).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1104,11 +1104,11 @@ fn builds_dejson_f22() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F22).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1123,11 +1123,11 @@ fn builds_dejson_f21() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_F21).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1142,11 +1142,11 @@ fn builds_dejson_epel9() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_EPEL9).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1161,11 +1161,11 @@ fn builds_dejson_epel9n() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_EPEL9N).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1180,11 +1180,11 @@ fn builds_dejson_epel8() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_EPEL8).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1199,11 +1199,11 @@ fn builds_dejson_epel8m() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_EPEL8M).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1218,11 +1218,11 @@ fn builds_dejson_epel8n() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_EPEL8N).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1237,11 +1237,11 @@ fn builds_dejson_epel7() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_EPEL7).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1256,11 +1256,11 @@ fn builds_dejson_el6() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_EL6).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1275,11 +1275,11 @@ fn builds_dejson_el5() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_EL5).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1294,11 +1294,11 @@ fn builds_dejson_eln() {
     let builds: Vec<Build> = serde_json::from_str(&read_to_string(JSON_ELN).unwrap()).unwrap();
 
     for build in &builds {
-        if !build.extra.is_empty() {
-            println!("{:#?}", build.extra);
+        if !build.extra().is_empty() {
+            println!("{:#?}", build.extra());
         }
 
-        assert!(build.extra.is_empty());
+        assert!(build.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -13,11 +13,11 @@ fn comments_dejson() {
     let comments: Vec<Comment> = serde_json::from_str(&read_to_string(JSON).unwrap()).unwrap();
 
     for comment in &comments {
-        if !comment.extra.is_empty() {
-            println!("{:#?}", comment.extra);
+        if !comment.extra().is_empty() {
+            println!("{:#?}", comment.extra());
         }
 
-        assert!(comment.extra.is_empty());
+        assert!(comment.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -13,11 +13,11 @@ fn composes_dejson() {
     let composes: Vec<Compose> = serde_json::from_str(&read_to_string(JSON).unwrap()).unwrap();
 
     for compose in &composes {
-        if !compose.extra.is_empty() {
-            println!("{:#?}", compose.extra);
+        if !compose.extra().is_empty() {
+            println!("{:#?}", compose.extra());
         }
 
-        assert!(compose.extra.is_empty());
+        assert!(compose.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -13,10 +13,10 @@ fn packages_dejson() {
     let packages: Vec<Package> = serde_json::from_str(&read_to_string(JSON).unwrap()).unwrap();
 
     for package in &packages {
-        if !package.extra.is_empty() {
-            println!("{:#?}", package.extra);
+        if !package.extra().is_empty() {
+            println!("{:#?}", package.extra());
         }
 
-        assert!(package.extra.is_empty());
+        assert!(package.extra().is_empty());
     }
 }
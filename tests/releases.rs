@@ -13,11 +13,11 @@ fn releases_dejson() {
     let releases: Vec<Release> = serde_json::from_str(&read_to_string(JSON).unwrap()).unwrap();
 
     for release in &releases {
-        if !release.extra.is_empty() {
-            println!("{:#?}", release.extra);
+        if !release.extra().is_empty() {
+            println!("{:#?}", release.extra());
         }
 
-        assert!(release.extra.is_empty()); // fails for EPEL-9
+        assert!(release.extra().is_empty()); // fails for EPEL-9
     }
 
     // check if an optional field is no longer present
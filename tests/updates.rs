@@ -78,11 +78,11 @@ fn updates_dejson_f40() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F40).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -109,11 +109,11 @@ fn updates_dejson_f40c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F40C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -140,11 +140,11 @@ fn updates_dejson_f39() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F39).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -171,11 +171,11 @@ fn updates_dejson_f39c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F39C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -202,11 +202,11 @@ fn updates_dejson_f39f() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F39F).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -233,11 +233,11 @@ fn updates_dejson_f38() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F38).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -264,11 +264,11 @@ fn updates_dejson_f38c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F38C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -295,11 +295,11 @@ fn updates_dejson_f38f() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F38F).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -326,11 +326,11 @@ fn updates_dejson_f38m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F38M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -357,11 +357,11 @@ fn updates_dejson_f37() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F37).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -388,11 +388,11 @@ fn updates_dejson_f37c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F37C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -419,11 +419,11 @@ fn updates_dejson_f37f() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F37F).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -450,11 +450,11 @@ fn updates_dejson_f37m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F37M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -481,11 +481,11 @@ fn updates_dejson_f36() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F36).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -512,11 +512,11 @@ fn updates_dejson_f36c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F36C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -543,11 +543,11 @@ fn updates_dejson_f36f() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F36F).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -574,11 +574,11 @@ fn updates_dejson_f36m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F36M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -605,11 +605,11 @@ fn updates_dejson_f35() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F35).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -636,11 +636,11 @@ fn updates_dejson_f35c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F35C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -667,11 +667,11 @@ fn updates_dejson_f35f() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F35F).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -698,11 +698,11 @@ fn updates_dejson_f35m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F35M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -729,11 +729,11 @@ fn updates_dejson_f34() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F34).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -760,11 +760,11 @@ fn updates_dejson_f34c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F34C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -791,11 +791,11 @@ fn updates_dejson_f34f() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F34F).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -822,11 +822,11 @@ fn updates_dejson_f34m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F34M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -853,11 +853,11 @@ fn updates_dejson_f33() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F33).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -884,11 +884,11 @@ fn updates_dejson_f33c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F33C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -915,11 +915,11 @@ fn updates_dejson_f33f() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F33F).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -946,11 +946,11 @@ fn updates_dejson_f33m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F33M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -977,11 +977,11 @@ fn updates_dejson_f32() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F32).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1008,11 +1008,11 @@ fn updates_dejson_f32c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F32C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1039,11 +1039,11 @@ fn updates_dejson_f32f() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F32F).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1070,11 +1070,11 @@ fn updates_dejson_f32m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F32M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1101,11 +1101,11 @@ fn updates_dejson_f31() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F31).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1132,11 +1132,11 @@ fn updates_dejson_f31c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F31C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1163,11 +1163,11 @@ fn updates_dejson_f31f() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F31F).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1194,11 +1194,11 @@ fn updates_dejson_f31m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F31M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1225,11 +1225,11 @@ fn updates_dejson_f30() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F30).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1256,11 +1256,11 @@ fn updates_dejson_f30c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F30C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1287,11 +1287,11 @@ fn updates_dejson_f30f() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F30F).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1318,11 +1318,11 @@ fn updates_dejson_f30m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F30M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1349,11 +1349,11 @@ fn updates_dejson_f29() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F29).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1380,11 +1380,11 @@ fn updates_dejson_f29c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F29C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1411,11 +1411,11 @@ fn updates_dejson_f29f() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F29F).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1442,11 +1442,11 @@ fn updates_dejson_f29m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F29M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1473,11 +1473,11 @@ fn updates_dejson_f28() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F28).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1504,11 +1504,11 @@ fn updates_dejson_f28c() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F28C).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1535,11 +1535,11 @@ fn updates_dejson_f28m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F28M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1566,11 +1566,11 @@ fn updates_dejson_f27() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F27).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1597,11 +1597,11 @@ fn updates_dejson_f27m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F27M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1628,11 +1628,11 @@ fn updates_dejson_f26() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F26).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1659,11 +1659,11 @@ fn updates_dejson_f25() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F25).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1690,11 +1690,11 @@ fn updates_dejson_f24() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F24).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1721,11 +1721,11 @@ fn updates_dejson_f23() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F23).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1752,11 +1752,11 @@ fn updates_dejson_f22() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F22).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1783,11 +1783,11 @@ fn updates_dejson_f21() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_F21).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1814,11 +1814,11 @@ fn updates_dejson_epel9() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_EPEL9).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1845,11 +1845,11 @@ fn updates_dejson_epel9n() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_EPEL9N).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1876,11 +1876,11 @@ fn updates_dejson_epel8() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_EPEL8).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1907,11 +1907,11 @@ fn updates_dejson_epel8m() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_EPEL8M).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1938,11 +1938,11 @@ fn updates_dejson_epel8n() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_EPEL8N).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -1969,11 +1969,11 @@ fn updates_dejson_epel7() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_EPEL7).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -2000,11 +2000,11 @@ fn updates_dejson_el6() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_EL6).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -2031,11 +2031,11 @@ fn updates_dejson_el5() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_EL5).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -2062,11 +2062,11 @@ fn updates_dejson_eln() {
     let updates: Vec<Update> = serde_json::from_str(&read_to_string(JSON_ELN).unwrap()).unwrap();
 
     for update in &updates {
-        if !update.extra.is_empty() {
-            println!("{:#?}", update.extra);
+        if !update.extra().is_empty() {
+            println!("{:#?}", update.extra());
         }
 
-        assert!(update.extra.is_empty());
+        assert!(update.extra().is_empty());
     }
 
     // check if an optional field is no longer present
@@ -13,11 +13,11 @@ fn users_dejson() {
     let users: Vec<User> = serde_json::from_str(&read_to_string(JSON).unwrap()).unwrap();
 
     for user in &users {
-        if !user.extra.is_empty() {
-            println!("{:#?}", user.extra);
+        if !user.extra().is_empty() {
+            println!("{:#?}", user.extra());
         }
 
-        assert!(user.extra.is_empty());
+        assert!(user.extra().is_empty());
     }
 
     // check if an optional field is no longer present